@@ -0,0 +1,162 @@
+//! Benchmarking setup for pallet-ark-bls
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as ArkBls;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+// Min number of aggregated signatures
+const AGGREGATE_LEN_MIN: u32 = 1;
+// Max number of aggregated signatures
+const AGGREGATE_LEN_MAX: u32 = 16;
+// Max length of the signed messages, the runtime `MaxMessageLen` must not be lower
+const MESSAGE_LEN_MAX: u32 = 1024;
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    // ---------------------------------------------
+    // Calls for min-pk
+    // ---------------------------------------------
+
+    #[benchmark]
+    fn ark_min_pk_verify(m: Linear<0, MESSAGE_LEN_MAX>) {
+        let (public_key, message, signature) = utils::make_min_pk_verify_args::<T>(m);
+
+        #[extrinsic_call]
+        min_pk_verify(RawOrigin::None, public_key, message, signature, false);
+    }
+
+    #[benchmark]
+    fn sub_min_pk_verify(m: Linear<0, MESSAGE_LEN_MAX>) {
+        let (public_key, message, signature) = utils::make_min_pk_verify_args::<T>(m);
+
+        #[extrinsic_call]
+        min_pk_verify(RawOrigin::None, public_key, message, signature, true);
+    }
+
+    #[benchmark]
+    fn ark_min_pk_fast_aggregate_verify(
+        x: Linear<AGGREGATE_LEN_MIN, AGGREGATE_LEN_MAX>,
+        m: Linear<0, MESSAGE_LEN_MAX>,
+    ) {
+        let (public_keys, message, signature) =
+            utils::make_min_pk_fast_aggregate_verify_args::<T>(x, m);
+
+        #[extrinsic_call]
+        min_pk_fast_aggregate_verify(RawOrigin::None, public_keys, message, signature, false);
+    }
+
+    #[benchmark]
+    fn sub_min_pk_fast_aggregate_verify(
+        x: Linear<AGGREGATE_LEN_MIN, AGGREGATE_LEN_MAX>,
+        m: Linear<0, MESSAGE_LEN_MAX>,
+    ) {
+        let (public_keys, message, signature) =
+            utils::make_min_pk_fast_aggregate_verify_args::<T>(x, m);
+
+        #[extrinsic_call]
+        min_pk_fast_aggregate_verify(RawOrigin::None, public_keys, message, signature, true);
+    }
+
+    #[benchmark]
+    fn ark_min_pk_aggregate_verify(
+        x: Linear<AGGREGATE_LEN_MIN, AGGREGATE_LEN_MAX>,
+        m: Linear<AGGREGATE_LEN_MAX, MESSAGE_LEN_MAX>,
+    ) {
+        // Messages of `m` bytes overall, at least one byte each to keep them distinct.
+        let (public_keys, messages, signature) =
+            utils::make_min_pk_aggregate_verify_args::<T>(x, m);
+
+        #[extrinsic_call]
+        min_pk_aggregate_verify(RawOrigin::None, public_keys, messages, signature, false);
+    }
+
+    #[benchmark]
+    fn sub_min_pk_aggregate_verify(
+        x: Linear<AGGREGATE_LEN_MIN, AGGREGATE_LEN_MAX>,
+        m: Linear<AGGREGATE_LEN_MAX, MESSAGE_LEN_MAX>,
+    ) {
+        // Messages of `m` bytes overall, at least one byte each to keep them distinct.
+        let (public_keys, messages, signature) =
+            utils::make_min_pk_aggregate_verify_args::<T>(x, m);
+
+        #[extrinsic_call]
+        min_pk_aggregate_verify(RawOrigin::None, public_keys, messages, signature, true);
+    }
+
+    // ---------------------------------------------
+    // Calls for min-sig
+    // ---------------------------------------------
+
+    #[benchmark]
+    fn ark_min_sig_verify(m: Linear<0, MESSAGE_LEN_MAX>) {
+        let (public_key, message, signature) = utils::make_min_sig_verify_args::<T>(m);
+
+        #[extrinsic_call]
+        min_sig_verify(RawOrigin::None, public_key, message, signature, false);
+    }
+
+    #[benchmark]
+    fn sub_min_sig_verify(m: Linear<0, MESSAGE_LEN_MAX>) {
+        let (public_key, message, signature) = utils::make_min_sig_verify_args::<T>(m);
+
+        #[extrinsic_call]
+        min_sig_verify(RawOrigin::None, public_key, message, signature, true);
+    }
+
+    #[benchmark]
+    fn ark_min_sig_fast_aggregate_verify(
+        x: Linear<AGGREGATE_LEN_MIN, AGGREGATE_LEN_MAX>,
+        m: Linear<0, MESSAGE_LEN_MAX>,
+    ) {
+        let (public_keys, message, signature) =
+            utils::make_min_sig_fast_aggregate_verify_args::<T>(x, m);
+
+        #[extrinsic_call]
+        min_sig_fast_aggregate_verify(RawOrigin::None, public_keys, message, signature, false);
+    }
+
+    #[benchmark]
+    fn sub_min_sig_fast_aggregate_verify(
+        x: Linear<AGGREGATE_LEN_MIN, AGGREGATE_LEN_MAX>,
+        m: Linear<0, MESSAGE_LEN_MAX>,
+    ) {
+        let (public_keys, message, signature) =
+            utils::make_min_sig_fast_aggregate_verify_args::<T>(x, m);
+
+        #[extrinsic_call]
+        min_sig_fast_aggregate_verify(RawOrigin::None, public_keys, message, signature, true);
+    }
+
+    #[benchmark]
+    fn ark_min_sig_aggregate_verify(
+        x: Linear<AGGREGATE_LEN_MIN, AGGREGATE_LEN_MAX>,
+        m: Linear<AGGREGATE_LEN_MAX, MESSAGE_LEN_MAX>,
+    ) {
+        // Messages of `m` bytes overall, at least one byte each to keep them distinct.
+        let (public_keys, messages, signature) =
+            utils::make_min_sig_aggregate_verify_args::<T>(x, m);
+
+        #[extrinsic_call]
+        min_sig_aggregate_verify(RawOrigin::None, public_keys, messages, signature, false);
+    }
+
+    #[benchmark]
+    fn sub_min_sig_aggregate_verify(
+        x: Linear<AGGREGATE_LEN_MIN, AGGREGATE_LEN_MAX>,
+        m: Linear<AGGREGATE_LEN_MAX, MESSAGE_LEN_MAX>,
+    ) {
+        // Messages of `m` bytes overall, at least one byte each to keep them distinct.
+        let (public_keys, messages, signature) =
+            utils::make_min_sig_aggregate_verify_args::<T>(x, m);
+
+        #[extrinsic_call]
+        min_sig_aggregate_verify(RawOrigin::None, public_keys, messages, signature, true);
+    }
+
+    impl_benchmark_test_suite!(ArkBls, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,529 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+mod utils;
+mod weights;
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+
+use ark_ec::{
+    hashing::{curve_maps::wb::WBConfig, HashToCurve},
+    pairing::Pairing,
+    short_weierstrass::{Affine as SWAffine, Projective as SWProjective, SWCurveConfig},
+    AffineRepr, CurveConfig, CurveGroup,
+};
+use ark_scale::{
+    ark_serialize::Validate,
+    scale::{Decode, Encode},
+};
+use ark_std::{collections::BTreeSet, iter, vec::Vec, Zero};
+use pallet_ark_common::{decode_vec, encoded_vec_len, hashing::HasherFor, is_optimized};
+
+pub use pallet_ark_common::{ArkBackend, Arkworks, BackendSwitch, ConfigBackend, Substrate};
+pub use sp_crypto_ec_utils::bls12_381 as sub_bls12_381;
+
+pub use pallet::*;
+pub use weights::*;
+
+type ArkScale<T> = ark_scale::ArkScale<T>;
+
+/// Signed message.
+pub type Message<T> = BoundedVec<u8, <T as Config>::MaxMessageLen>;
+
+/// Distinct signed messages of an aggregate signature.
+pub type Messages<T> = BoundedVec<Message<T>, <T as Config>::MaxMessages>;
+
+/// Number of public keys accounted for by the call weight.
+///
+/// Inputs with more than `Config::MaxPublicKeys` keys are rejected before any work is done.
+fn public_keys_len<T: Config>(public_keys: &[u8]) -> u32 {
+    encoded_vec_len(public_keys).min(T::MaxPublicKeys::get())
+}
+
+/// Total length in bytes of the messages, hashed to the curve.
+fn messages_len<T: Config>(messages: &Messages<T>) -> u32 {
+    messages.iter().map(|message| message.len() as u32).sum()
+}
+
+/// Domain separation tag for the min-pk basic scheme.
+pub const MIN_PK_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+/// Domain separation tag for the min-pk proof of possession scheme.
+pub const MIN_PK_POP_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+/// Domain separation tag for the min-sig basic scheme.
+pub const MIN_SIG_DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+/// Domain separation tag for the min-sig proof of possession scheme.
+pub const MIN_SIG_POP_DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_";
+
+/// BLS12-381 pairing engine together with its groups configs.
+pub trait Engine {
+    type Pairing: Pairing<G1Affine = SWAffine<Self::G1>, G2Affine = SWAffine<Self::G2>>;
+    type G1: SWCurveConfig<BaseField = ark_bls12_381::Fq>;
+    type G2: SWCurveConfig<BaseField = ark_bls12_381::Fq2>;
+}
+
+/// Pure arkworks engine.
+pub struct ArkEngine;
+
+impl Engine for ArkEngine {
+    type Pairing = ark_bls12_381::Bls12_381;
+    type G1 = ark_bls12_381::g1::Config;
+    type G2 = ark_bls12_381::g2::Config;
+}
+
+/// Engine backed by Substrate host calls.
+pub struct SubEngine;
+
+impl Engine for SubEngine {
+    type Pairing = sub_bls12_381::Bls12_381;
+    type G1 = sub_bls12_381::g1::Config;
+    type G2 = sub_bls12_381::g2::Config;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Maximum number of public keys of an aggregate signature of a single message.
+        #[pallet::constant]
+        type MaxPublicKeys: Get<u32>;
+
+        /// Maximum number of messages of an aggregate signature of distinct messages.
+        #[pallet::constant]
+        type MaxMessages: Get<u32>;
+
+        /// Maximum length in bytes of a signed message.
+        #[pallet::constant]
+        type MaxMessageLen: Get<u32>;
+
+        /// Backend of the curve operations.
+        ///
+        /// Ignored with the `comparison-mode` feature, where the backend is selected per call.
+        type Backend: ArkBackend;
+
+        /// Extrinsic weights
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Public key can't be decoded or is the identity.
+        InvalidPublicKey,
+        /// Signature can't be decoded.
+        InvalidSignature,
+        /// No public keys provided.
+        EmptyPublicKeys,
+        /// Number of public keys exceeds `Config::MaxPublicKeys`.
+        TooManyPublicKeys,
+        /// Public keys and messages count mismatch.
+        LengthMismatch,
+        /// Messages are not distinct.
+        DuplicateMessage,
+        /// Message can't be hashed to the curve.
+        HashToCurveFailed,
+        /// Signature verification failed.
+        VerificationFailed,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        // ---------------------------------------------
+        // Calls for min-pk (public keys in G1, signatures in G2)
+        // ---------------------------------------------
+
+        /// Verify a signature.
+        #[pallet::call_index(0)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_min_pk_verify(message.len() as u32)
+        } else {
+            T::WeightInfo::ark_min_pk_verify(message.len() as u32)
+        })]
+        pub fn min_pk_verify(
+            _: OriginFor<T>,
+            public_key: Vec<u8>,
+            message: Message<T>,
+            signature: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            if is_optimized::<T::Backend>(&optimized) {
+                Self::min_pk_verify_impl::<SubEngine>(&public_key, &message, &signature)
+            } else {
+                Self::min_pk_verify_impl::<ArkEngine>(&public_key, &message, &signature)
+            }
+        }
+
+        /// Verify an aggregate signature of a single message.
+        ///
+        /// Public keys are expected to come with a proof of possession.
+        #[pallet::call_index(1)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_min_pk_fast_aggregate_verify(
+                public_keys_len::<T>(public_keys),
+                message.len() as u32,
+            )
+        } else {
+            T::WeightInfo::ark_min_pk_fast_aggregate_verify(
+                public_keys_len::<T>(public_keys),
+                message.len() as u32,
+            )
+        })]
+        pub fn min_pk_fast_aggregate_verify(
+            _: OriginFor<T>,
+            public_keys: Vec<u8>,
+            message: Message<T>,
+            signature: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            ensure!(
+                encoded_vec_len(&public_keys) <= T::MaxPublicKeys::get(),
+                Error::<T>::TooManyPublicKeys
+            );
+            if is_optimized::<T::Backend>(&optimized) {
+                Self::min_pk_fast_aggregate_verify_impl::<SubEngine>(
+                    &public_keys,
+                    &message,
+                    &signature,
+                )
+            } else {
+                Self::min_pk_fast_aggregate_verify_impl::<ArkEngine>(
+                    &public_keys,
+                    &message,
+                    &signature,
+                )
+            }
+        }
+
+        /// Verify an aggregate signature of distinct messages.
+        #[pallet::call_index(2)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_min_pk_aggregate_verify(
+                messages.len() as u32,
+                messages_len::<T>(messages),
+            )
+        } else {
+            T::WeightInfo::ark_min_pk_aggregate_verify(
+                messages.len() as u32,
+                messages_len::<T>(messages),
+            )
+        })]
+        pub fn min_pk_aggregate_verify(
+            _: OriginFor<T>,
+            public_keys: Vec<u8>,
+            messages: Messages<T>,
+            signature: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            if is_optimized::<T::Backend>(&optimized) {
+                Self::min_pk_aggregate_verify_impl::<SubEngine>(&public_keys, &messages, &signature)
+            } else {
+                Self::min_pk_aggregate_verify_impl::<ArkEngine>(&public_keys, &messages, &signature)
+            }
+        }
+
+        // ---------------------------------------------
+        // Calls for min-sig (public keys in G2, signatures in G1)
+        // ---------------------------------------------
+
+        /// Verify a signature.
+        #[pallet::call_index(10)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_min_sig_verify(message.len() as u32)
+        } else {
+            T::WeightInfo::ark_min_sig_verify(message.len() as u32)
+        })]
+        pub fn min_sig_verify(
+            _: OriginFor<T>,
+            public_key: Vec<u8>,
+            message: Message<T>,
+            signature: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            if is_optimized::<T::Backend>(&optimized) {
+                Self::min_sig_verify_impl::<SubEngine>(&public_key, &message, &signature)
+            } else {
+                Self::min_sig_verify_impl::<ArkEngine>(&public_key, &message, &signature)
+            }
+        }
+
+        /// Verify an aggregate signature of a single message.
+        ///
+        /// Public keys are expected to come with a proof of possession.
+        #[pallet::call_index(11)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_min_sig_fast_aggregate_verify(
+                public_keys_len::<T>(public_keys),
+                message.len() as u32,
+            )
+        } else {
+            T::WeightInfo::ark_min_sig_fast_aggregate_verify(
+                public_keys_len::<T>(public_keys),
+                message.len() as u32,
+            )
+        })]
+        pub fn min_sig_fast_aggregate_verify(
+            _: OriginFor<T>,
+            public_keys: Vec<u8>,
+            message: Message<T>,
+            signature: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            ensure!(
+                encoded_vec_len(&public_keys) <= T::MaxPublicKeys::get(),
+                Error::<T>::TooManyPublicKeys
+            );
+            if is_optimized::<T::Backend>(&optimized) {
+                Self::min_sig_fast_aggregate_verify_impl::<SubEngine>(
+                    &public_keys,
+                    &message,
+                    &signature,
+                )
+            } else {
+                Self::min_sig_fast_aggregate_verify_impl::<ArkEngine>(
+                    &public_keys,
+                    &message,
+                    &signature,
+                )
+            }
+        }
+
+        /// Verify an aggregate signature of distinct messages.
+        #[pallet::call_index(12)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_min_sig_aggregate_verify(
+                messages.len() as u32,
+                messages_len::<T>(messages),
+            )
+        } else {
+            T::WeightInfo::ark_min_sig_aggregate_verify(
+                messages.len() as u32,
+                messages_len::<T>(messages),
+            )
+        })]
+        pub fn min_sig_aggregate_verify(
+            _: OriginFor<T>,
+            public_keys: Vec<u8>,
+            messages: Messages<T>,
+            signature: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            if is_optimized::<T::Backend>(&optimized) {
+                Self::min_sig_aggregate_verify_impl::<SubEngine>(
+                    &public_keys,
+                    &messages,
+                    &signature,
+                )
+            } else {
+                Self::min_sig_aggregate_verify_impl::<ArkEngine>(
+                    &public_keys,
+                    &messages,
+                    &signature,
+                )
+            }
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        // ---------------------------------------------
+        // min-pk
+        // ---------------------------------------------
+
+        fn min_pk_verify_impl<E: Engine>(
+            public_key: &[u8],
+            message: &[u8],
+            signature: &[u8],
+        ) -> DispatchResult {
+            let public_key = Self::decode_public_key::<E::G1>(public_key)?;
+            let signature = Self::decode_signature::<E::G2>(signature)?;
+            let hash =
+                Self::hash_to_curve::<ark_bls12_381::g2::Config, E::G2>(MIN_PK_DST, message)?;
+            Self::min_pk_check::<E>(signature, [public_key], [hash])
+        }
+
+        fn min_pk_fast_aggregate_verify_impl<E: Engine>(
+            public_keys: &[u8],
+            message: &[u8],
+            signature: &[u8],
+        ) -> DispatchResult {
+            let public_key = Self::aggregate_public_keys::<E::G1>(public_keys)?;
+            let signature = Self::decode_signature::<E::G2>(signature)?;
+            let hash =
+                Self::hash_to_curve::<ark_bls12_381::g2::Config, E::G2>(MIN_PK_POP_DST, message)?;
+            Self::min_pk_check::<E>(signature, [public_key], [hash])
+        }
+
+        fn min_pk_aggregate_verify_impl<E: Engine>(
+            public_keys: &[u8],
+            messages: &[Message<T>],
+            signature: &[u8],
+        ) -> DispatchResult {
+            let public_keys = Self::decode_public_keys::<E::G1>(public_keys, messages)?;
+            let signature = Self::decode_signature::<E::G2>(signature)?;
+            let hashes = messages
+                .iter()
+                .map(|message| {
+                    Self::hash_to_curve::<ark_bls12_381::g2::Config, E::G2>(MIN_PK_DST, message)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Self::min_pk_check::<E>(signature, public_keys, hashes)
+        }
+
+        /// Checks `e(g1, signature) == Π e(public_key_i, hash_i)`.
+        fn min_pk_check<E: Engine>(
+            signature: SWAffine<E::G2>,
+            public_keys: impl IntoIterator<Item = SWAffine<E::G1>>,
+            hashes: impl IntoIterator<Item = SWAffine<E::G2>>,
+        ) -> DispatchResult {
+            let g1 = iter::once(-SWAffine::<E::G1>::generator()).chain(public_keys);
+            let g2 = iter::once(signature).chain(hashes);
+            let res = E::Pairing::multi_pairing(g1, g2);
+            ensure!(res.is_zero(), Error::<T>::VerificationFailed);
+            Ok(())
+        }
+
+        // ---------------------------------------------
+        // min-sig
+        // ---------------------------------------------
+
+        fn min_sig_verify_impl<E: Engine>(
+            public_key: &[u8],
+            message: &[u8],
+            signature: &[u8],
+        ) -> DispatchResult {
+            let public_key = Self::decode_public_key::<E::G2>(public_key)?;
+            let signature = Self::decode_signature::<E::G1>(signature)?;
+            let hash =
+                Self::hash_to_curve::<ark_bls12_381::g1::Config, E::G1>(MIN_SIG_DST, message)?;
+            Self::min_sig_check::<E>(signature, [public_key], [hash])
+        }
+
+        fn min_sig_fast_aggregate_verify_impl<E: Engine>(
+            public_keys: &[u8],
+            message: &[u8],
+            signature: &[u8],
+        ) -> DispatchResult {
+            let public_key = Self::aggregate_public_keys::<E::G2>(public_keys)?;
+            let signature = Self::decode_signature::<E::G1>(signature)?;
+            let hash =
+                Self::hash_to_curve::<ark_bls12_381::g1::Config, E::G1>(MIN_SIG_POP_DST, message)?;
+            Self::min_sig_check::<E>(signature, [public_key], [hash])
+        }
+
+        fn min_sig_aggregate_verify_impl<E: Engine>(
+            public_keys: &[u8],
+            messages: &[Message<T>],
+            signature: &[u8],
+        ) -> DispatchResult {
+            let public_keys = Self::decode_public_keys::<E::G2>(public_keys, messages)?;
+            let signature = Self::decode_signature::<E::G1>(signature)?;
+            let hashes = messages
+                .iter()
+                .map(|message| {
+                    Self::hash_to_curve::<ark_bls12_381::g1::Config, E::G1>(MIN_SIG_DST, message)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Self::min_sig_check::<E>(signature, public_keys, hashes)
+        }
+
+        /// Checks `e(signature, g2) == Π e(hash_i, public_key_i)`.
+        fn min_sig_check<E: Engine>(
+            signature: SWAffine<E::G1>,
+            public_keys: impl IntoIterator<Item = SWAffine<E::G2>>,
+            hashes: impl IntoIterator<Item = SWAffine<E::G1>>,
+        ) -> DispatchResult {
+            let g1 = iter::once(signature).chain(hashes);
+            let g2 = iter::once(-SWAffine::<E::G2>::generator()).chain(public_keys);
+            let res = E::Pairing::multi_pairing(g1, g2);
+            ensure!(res.is_zero(), Error::<T>::VerificationFailed);
+            Ok(())
+        }
+
+        // ---------------------------------------------
+        // Common
+        // ---------------------------------------------
+
+        /// Public keys are checked to be in the prime order subgroup and not the identity.
+        fn decode_public_key<C: SWCurveConfig>(data: &[u8]) -> Result<SWAffine<C>, Error<T>> {
+            let public_key = ArkScale::<SWAffine<C>>::decode(&mut &data[..])
+                .map_err(|_| Error::<T>::InvalidPublicKey)?
+                .0;
+            ensure!(!public_key.is_zero(), Error::<T>::InvalidPublicKey);
+            Ok(public_key)
+        }
+
+        /// Public keys of an aggregate verification of distinct messages.
+        ///
+        /// Keys count is checked against the messages count before decoding.
+        fn decode_public_keys<C: SWCurveConfig>(
+            data: &[u8],
+            messages: &[Message<T>],
+        ) -> Result<Vec<SWAffine<C>>, Error<T>> {
+            ensure!(
+                encoded_vec_len(data) as usize == messages.len(),
+                Error::<T>::LengthMismatch
+            );
+            let public_keys = decode_vec::<SWAffine<C>>(data, Validate::Yes)
+                .ok_or(Error::<T>::InvalidPublicKey)?;
+            ensure!(!public_keys.is_empty(), Error::<T>::EmptyPublicKeys);
+            ensure!(
+                public_keys.len() == messages.len(),
+                Error::<T>::LengthMismatch
+            );
+            ensure!(
+                public_keys.iter().all(|public_key| !public_key.is_zero()),
+                Error::<T>::InvalidPublicKey
+            );
+            let distinct = messages
+                .iter()
+                .map(|message| message.as_slice())
+                .collect::<BTreeSet<_>>();
+            ensure!(
+                distinct.len() == messages.len(),
+                Error::<T>::DuplicateMessage
+            );
+            Ok(public_keys)
+        }
+
+        fn aggregate_public_keys<C: SWCurveConfig>(data: &[u8]) -> Result<SWAffine<C>, Error<T>> {
+            let public_keys = decode_vec::<SWAffine<C>>(data, Validate::Yes)
+                .ok_or(Error::<T>::InvalidPublicKey)?;
+            ensure!(!public_keys.is_empty(), Error::<T>::EmptyPublicKeys);
+            ensure!(
+                public_keys.iter().all(|public_key| !public_key.is_zero()),
+                Error::<T>::InvalidPublicKey
+            );
+            let public_key: SWProjective<C> = public_keys.iter().sum();
+            Ok(public_key.into_affine())
+        }
+
+        /// Signatures are checked to be in the prime order subgroup.
+        fn decode_signature<C: SWCurveConfig>(data: &[u8]) -> Result<SWAffine<C>, Error<T>> {
+            ArkScale::<SWAffine<C>>::decode(&mut &data[..])
+                .map(|signature| signature.0)
+                .map_err(|_| Error::<T>::InvalidSignature)
+        }
+
+        fn hash_to_curve<M, C>(dst: &[u8], message: &[u8]) -> Result<SWAffine<C>, Error<T>>
+        where
+            M: WBConfig,
+            C: SWCurveConfig<BaseField = <M as CurveConfig>::BaseField>,
+        {
+            let hasher = HasherFor::<M, C>::new(dst).map_err(|_| Error::<T>::HashToCurveFailed)?;
+            hasher
+                .hash(message)
+                .map_err(|_| Error::<T>::HashToCurveFailed)
+        }
+    }
+}
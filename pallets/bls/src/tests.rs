@@ -0,0 +1,325 @@
+use crate::{
+    mock::{backend, new_test_ext, ArkBls, RuntimeOrigin, Test},
+    utils::*,
+    ArkScale, Error,
+};
+use ark_ec::AffineRepr;
+use ark_scale::scale::Encode;
+use frame_support::{assert_noop, assert_ok};
+
+const AGGREGATE_ITEMS: u32 = 4;
+const MESSAGE_LEN: u32 = 32;
+
+// ---------------------------------------------
+// Tests for min-pk
+// ---------------------------------------------
+
+fn min_pk_verify(optimized: bool) {
+    let (public_key, message, signature) = make_min_pk_verify_args::<Test>(MESSAGE_LEN);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkBls::min_pk_verify(
+            RuntimeOrigin::none(),
+            public_key,
+            message,
+            signature,
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_min_pk_verify() {
+    min_pk_verify(false);
+}
+
+#[test]
+fn sub_min_pk_verify() {
+    min_pk_verify(true);
+}
+
+fn min_pk_fast_aggregate_verify(optimized: bool) {
+    let (public_keys, message, signature) =
+        make_min_pk_fast_aggregate_verify_args::<Test>(AGGREGATE_ITEMS, MESSAGE_LEN);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkBls::min_pk_fast_aggregate_verify(
+            RuntimeOrigin::none(),
+            public_keys,
+            message,
+            signature,
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_min_pk_fast_aggregate_verify() {
+    min_pk_fast_aggregate_verify(false);
+}
+
+#[test]
+fn sub_min_pk_fast_aggregate_verify() {
+    min_pk_fast_aggregate_verify(true);
+}
+
+fn min_pk_aggregate_verify(optimized: bool) {
+    let (public_keys, messages, signature) =
+        make_min_pk_aggregate_verify_args::<Test>(AGGREGATE_ITEMS, AGGREGATE_ITEMS * MESSAGE_LEN);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkBls::min_pk_aggregate_verify(
+            RuntimeOrigin::none(),
+            public_keys,
+            messages,
+            signature,
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_min_pk_aggregate_verify() {
+    min_pk_aggregate_verify(false);
+}
+
+#[test]
+fn sub_min_pk_aggregate_verify() {
+    min_pk_aggregate_verify(true);
+}
+
+// ---------------------------------------------
+// Tests for min-sig
+// ---------------------------------------------
+
+fn min_sig_verify(optimized: bool) {
+    let (public_key, message, signature) = make_min_sig_verify_args::<Test>(MESSAGE_LEN);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkBls::min_sig_verify(
+            RuntimeOrigin::none(),
+            public_key,
+            message,
+            signature,
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_min_sig_verify() {
+    min_sig_verify(false);
+}
+
+#[test]
+fn sub_min_sig_verify() {
+    min_sig_verify(true);
+}
+
+fn min_sig_fast_aggregate_verify(optimized: bool) {
+    let (public_keys, message, signature) =
+        make_min_sig_fast_aggregate_verify_args::<Test>(AGGREGATE_ITEMS, MESSAGE_LEN);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkBls::min_sig_fast_aggregate_verify(
+            RuntimeOrigin::none(),
+            public_keys,
+            message,
+            signature,
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_min_sig_fast_aggregate_verify() {
+    min_sig_fast_aggregate_verify(false);
+}
+
+#[test]
+fn sub_min_sig_fast_aggregate_verify() {
+    min_sig_fast_aggregate_verify(true);
+}
+
+fn min_sig_aggregate_verify(optimized: bool) {
+    let (public_keys, messages, signature) =
+        make_min_sig_aggregate_verify_args::<Test>(AGGREGATE_ITEMS, AGGREGATE_ITEMS * MESSAGE_LEN);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkBls::min_sig_aggregate_verify(
+            RuntimeOrigin::none(),
+            public_keys,
+            messages,
+            signature,
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_min_sig_aggregate_verify() {
+    min_sig_aggregate_verify(false);
+}
+
+#[test]
+fn sub_min_sig_aggregate_verify() {
+    min_sig_aggregate_verify(true);
+}
+
+// ---------------------------------------------
+// Tests for invalid inputs
+// ---------------------------------------------
+
+#[test]
+fn verify_with_wrong_message_fails() {
+    let (public_key, _, signature) = make_min_pk_verify_args::<Test>(MESSAGE_LEN);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkBls::min_pk_verify(
+                RuntimeOrigin::none(),
+                public_key,
+                b"another message".to_vec().try_into().unwrap(),
+                signature,
+                backend(true)
+            ),
+            Error::<Test>::VerificationFailed
+        );
+    });
+}
+
+#[test]
+fn verify_with_signature_of_other_scheme_fails() {
+    // Same groups, but message hashed with the proof of possession DST.
+    let (public_keys, message, signature) =
+        make_min_pk_fast_aggregate_verify_args::<Test>(1, MESSAGE_LEN);
+    let public_key = public_keys[8..].to_vec();
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkBls::min_pk_verify(
+                RuntimeOrigin::none(),
+                public_key,
+                message,
+                signature,
+                backend(true)
+            ),
+            Error::<Test>::VerificationFailed
+        );
+    });
+}
+
+#[test]
+fn verify_with_identity_public_key_fails() {
+    let (_, message, signature) = make_min_sig_verify_args::<Test>(MESSAGE_LEN);
+    let public_key = ArkScale::from(ark_bls12_381::G2Affine::zero()).encode();
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkBls::min_sig_verify(
+                RuntimeOrigin::none(),
+                public_key,
+                message,
+                signature,
+                backend(true)
+            ),
+            Error::<Test>::InvalidPublicKey
+        );
+    });
+}
+
+#[test]
+fn verify_with_invalid_signature_fails() {
+    let (public_key, message, _) = make_min_pk_verify_args::<Test>(MESSAGE_LEN);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkBls::min_pk_verify(
+                RuntimeOrigin::none(),
+                public_key,
+                message,
+                vec![0xff; 96],
+                backend(true)
+            ),
+            Error::<Test>::InvalidSignature
+        );
+    });
+}
+
+#[test]
+fn fast_aggregate_verify_without_public_keys_fails() {
+    let (_, message, signature) = make_min_pk_fast_aggregate_verify_args::<Test>(1, MESSAGE_LEN);
+    let public_keys = ArkScale::from(Vec::<ark_bls12_381::G1Affine>::new()).encode();
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkBls::min_pk_fast_aggregate_verify(
+                RuntimeOrigin::none(),
+                public_keys,
+                message,
+                signature,
+                backend(true)
+            ),
+            Error::<Test>::EmptyPublicKeys
+        );
+    });
+}
+
+#[test]
+fn fast_aggregate_verify_with_too_many_public_keys_fails() {
+    let (public_keys, message, signature) =
+        make_min_pk_fast_aggregate_verify_args::<Test>(17, MESSAGE_LEN);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkBls::min_pk_fast_aggregate_verify(
+                RuntimeOrigin::none(),
+                public_keys,
+                message,
+                signature,
+                backend(true)
+            ),
+            Error::<Test>::TooManyPublicKeys
+        );
+    });
+}
+
+#[test]
+fn aggregate_verify_with_duplicate_messages_fails() {
+    let (public_keys, mut messages, signature) =
+        make_min_sig_aggregate_verify_args::<Test>(2, 2 * MESSAGE_LEN);
+    messages[1] = messages[0].clone();
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkBls::min_sig_aggregate_verify(
+                RuntimeOrigin::none(),
+                public_keys,
+                messages,
+                signature,
+                backend(true)
+            ),
+            Error::<Test>::DuplicateMessage
+        );
+    });
+}
+
+#[test]
+fn aggregate_verify_with_length_mismatch_fails() {
+    let (public_keys, mut messages, signature) =
+        make_min_pk_aggregate_verify_args::<Test>(2, 2 * MESSAGE_LEN);
+    messages.pop();
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkBls::min_pk_aggregate_verify(
+                RuntimeOrigin::none(),
+                public_keys,
+                messages,
+                signature,
+                backend(true)
+            ),
+            Error::<Test>::LengthMismatch
+        );
+    });
+}
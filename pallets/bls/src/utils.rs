@@ -0,0 +1,155 @@
+#![allow(dead_code)]
+
+use crate::{
+    ArkScale, Config, Message, Messages, MIN_PK_DST, MIN_PK_POP_DST, MIN_SIG_DST, MIN_SIG_POP_DST,
+};
+use ark_bls12_381::{g1, g2, Fr};
+use ark_ec::{
+    hashing::{curve_maps::wb::WBConfig, HashToCurve},
+    short_weierstrass::{Affine as SWAffine, Projective as SWProjective, SWCurveConfig},
+    AffineRepr, CurveGroup,
+};
+use ark_scale::scale::Encode;
+use ark_std::{test_rng, vec, vec::Vec, UniformRand};
+use pallet_ark_common::hashing::HasherFor;
+
+/// Encoded `(public_key, message, signature)`.
+pub type VerifyArgs<T> = (Vec<u8>, Message<T>, Vec<u8>);
+
+/// Encoded `(public_keys, messages, signature)`.
+pub type AggregateVerifyArgs<T> = (Vec<u8>, Messages<T>, Vec<u8>);
+
+fn make_secrets(count: u32) -> Vec<Fr> {
+    let rng = &mut test_rng();
+    (0..count).map(|_| Fr::rand(rng)).collect()
+}
+
+/// Message of `len` bytes, ending with `index` to keep the messages of an aggregate distinct.
+fn make_message<T: Config>(index: u32, len: u32) -> Message<T> {
+    let mut message = vec![0xab; len as usize];
+    if let Some(last) = message.last_mut() {
+        *last = index as u8;
+    }
+    message.try_into().unwrap()
+}
+
+/// `count` distinct messages of `len` bytes overall.
+fn make_messages<T: Config>(count: u32, len: u32) -> Messages<T> {
+    (0..count)
+        .map(|index| make_message::<T>(index, len / count + u32::from(index < len % count)))
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap()
+}
+
+fn public_key<K: SWCurveConfig<ScalarField = Fr>>(secret: Fr) -> SWAffine<K> {
+    (SWAffine::<K>::generator() * secret).into_affine()
+}
+
+fn sign<S: WBConfig<ScalarField = Fr>>(secret: Fr, dst: &[u8], message: &[u8]) -> SWAffine<S> {
+    let hash = HasherFor::<S, S>::new(dst).unwrap().hash(message).unwrap();
+    (hash * secret).into_affine()
+}
+
+fn make_verify_args<T, K, S>(dst: &[u8], message_len: u32) -> VerifyArgs<T>
+where
+    T: Config,
+    K: SWCurveConfig<ScalarField = Fr>,
+    S: WBConfig<ScalarField = Fr>,
+{
+    let secret = make_secrets(1)[0];
+    let message = make_message::<T>(0, message_len);
+    let signature = sign::<S>(secret, dst, &message);
+    (
+        ArkScale::from(public_key::<K>(secret)).encode(),
+        message,
+        ArkScale::from(signature).encode(),
+    )
+}
+
+fn make_fast_aggregate_verify_args<T, K, S>(
+    dst: &[u8],
+    count: u32,
+    message_len: u32,
+) -> VerifyArgs<T>
+where
+    T: Config,
+    K: SWCurveConfig<ScalarField = Fr>,
+    S: WBConfig<ScalarField = Fr>,
+{
+    let secrets = make_secrets(count);
+    let message = make_message::<T>(0, message_len);
+    let public_keys: Vec<_> = secrets.iter().map(|s| public_key::<K>(*s)).collect();
+    // Aggregate signature of a single message is the signature of the secrets sum.
+    let signature = sign::<S>(secrets.iter().sum(), dst, &message);
+    (
+        ArkScale::from(public_keys).encode(),
+        message,
+        ArkScale::from(signature).encode(),
+    )
+}
+
+fn make_aggregate_verify_args<T, K, S>(
+    dst: &[u8],
+    count: u32,
+    messages_len: u32,
+) -> AggregateVerifyArgs<T>
+where
+    T: Config,
+    K: SWCurveConfig<ScalarField = Fr>,
+    S: WBConfig<ScalarField = Fr>,
+{
+    let secrets = make_secrets(count);
+    let messages = make_messages::<T>(count, messages_len);
+    let public_keys: Vec<_> = secrets.iter().map(|s| public_key::<K>(*s)).collect();
+    let signature: SWProjective<S> = secrets
+        .iter()
+        .zip(messages.iter())
+        .map(|(s, m)| sign::<S>(*s, dst, m))
+        .sum();
+    (
+        ArkScale::from(public_keys).encode(),
+        messages,
+        ArkScale::from(signature.into_affine()).encode(),
+    )
+}
+
+pub fn make_min_pk_verify_args<T: Config>(message_len: u32) -> VerifyArgs<T> {
+    make_verify_args::<T, g1::Config, g2::Config>(MIN_PK_DST, message_len)
+}
+
+pub fn make_min_pk_fast_aggregate_verify_args<T: Config>(
+    count: u32,
+    message_len: u32,
+) -> VerifyArgs<T> {
+    make_fast_aggregate_verify_args::<T, g1::Config, g2::Config>(MIN_PK_POP_DST, count, message_len)
+}
+
+pub fn make_min_pk_aggregate_verify_args<T: Config>(
+    count: u32,
+    messages_len: u32,
+) -> AggregateVerifyArgs<T> {
+    make_aggregate_verify_args::<T, g1::Config, g2::Config>(MIN_PK_DST, count, messages_len)
+}
+
+pub fn make_min_sig_verify_args<T: Config>(message_len: u32) -> VerifyArgs<T> {
+    make_verify_args::<T, g2::Config, g1::Config>(MIN_SIG_DST, message_len)
+}
+
+pub fn make_min_sig_fast_aggregate_verify_args<T: Config>(
+    count: u32,
+    message_len: u32,
+) -> VerifyArgs<T> {
+    make_fast_aggregate_verify_args::<T, g2::Config, g1::Config>(
+        MIN_SIG_POP_DST,
+        count,
+        message_len,
+    )
+}
+
+pub fn make_min_sig_aggregate_verify_args<T: Config>(
+    count: u32,
+    messages_len: u32,
+) -> AggregateVerifyArgs<T> {
+    make_aggregate_verify_args::<T, g2::Config, g1::Config>(MIN_SIG_DST, count, messages_len)
+}
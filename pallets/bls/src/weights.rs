@@ -0,0 +1,227 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Placeholder weights for `pallet_ark_bls`
+//!
+//! NOT GENERATED BY THE BENCHMARK CLI. The figures below are estimates and must be
+//! replaced by running the pallet benchmarks with the command below.
+
+// Command to regenerate:
+// ./target/release/ark-node
+// benchmark
+// pallet
+// --runtime=target/release/wbuild/ark-runtime/ark_runtime.compact.wasm
+// --template=pallets/frame-weight-template.hbs
+// --pallet
+// pallet_ark_bls
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat=20
+// --no-storage-info
+// --disable-proof-recording
+// --no-median-slopes
+// --json-file=./pallets/bls/src/results.json
+// --output=./pallets/bls/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_ark_bls`.
+pub trait WeightInfo {
+	fn ark_min_pk_verify(m: u32, ) -> Weight;
+	fn sub_min_pk_verify(m: u32, ) -> Weight;
+	fn ark_min_pk_fast_aggregate_verify(x: u32, m: u32, ) -> Weight;
+	fn sub_min_pk_fast_aggregate_verify(x: u32, m: u32, ) -> Weight;
+	fn ark_min_pk_aggregate_verify(x: u32, m: u32, ) -> Weight;
+	fn sub_min_pk_aggregate_verify(x: u32, m: u32, ) -> Weight;
+	fn ark_min_sig_verify(m: u32, ) -> Weight;
+	fn sub_min_sig_verify(m: u32, ) -> Weight;
+	fn ark_min_sig_fast_aggregate_verify(x: u32, m: u32, ) -> Weight;
+	fn sub_min_sig_fast_aggregate_verify(x: u32, m: u32, ) -> Weight;
+	fn ark_min_sig_aggregate_verify(x: u32, m: u32, ) -> Weight;
+	fn sub_min_sig_aggregate_verify(x: u32, m: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_ark_bls` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// The range of component `m` is `[0, 1024]`.
+	fn ark_min_pk_verify(m: u32, ) -> Weight {
+		Weight::from_parts(22_237_000_000, 0)
+			.saturating_add(Weight::from_parts(15_310, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `m` is `[0, 1024]`.
+	fn sub_min_pk_verify(m: u32, ) -> Weight {
+		Weight::from_parts(7_661_000_000, 0)
+			.saturating_add(Weight::from_parts(15_270, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `m` is `[0, 1024]`.
+	fn ark_min_pk_fast_aggregate_verify(x: u32, m: u32, ) -> Weight {
+		Weight::from_parts(22_237_000_000, 0)
+			.saturating_add(Weight::from_parts(612_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(15_310, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `m` is `[0, 1024]`.
+	fn sub_min_pk_fast_aggregate_verify(x: u32, m: u32, ) -> Weight {
+		Weight::from_parts(7_661_000_000, 0)
+			.saturating_add(Weight::from_parts(254_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(15_270, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `m` is `[16, 1024]`.
+	fn ark_min_pk_aggregate_verify(x: u32, m: u32, ) -> Weight {
+		Weight::from_parts(10_098_000_000, 0)
+			.saturating_add(Weight::from_parts(12_651_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(15_310, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `m` is `[16, 1024]`.
+	fn sub_min_pk_aggregate_verify(x: u32, m: u32, ) -> Weight {
+		Weight::from_parts(2_505_000_000, 0)
+			.saturating_add(Weight::from_parts(5_412_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(15_270, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `m` is `[0, 1024]`.
+	fn ark_min_sig_verify(m: u32, ) -> Weight {
+		Weight::from_parts(17_051_000_000, 0)
+			.saturating_add(Weight::from_parts(15_240, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `m` is `[0, 1024]`.
+	fn sub_min_sig_verify(m: u32, ) -> Weight {
+		Weight::from_parts(5_523_000_000, 0)
+			.saturating_add(Weight::from_parts(15_180, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `m` is `[0, 1024]`.
+	fn ark_min_sig_fast_aggregate_verify(x: u32, m: u32, ) -> Weight {
+		Weight::from_parts(17_051_000_000, 0)
+			.saturating_add(Weight::from_parts(2_013_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(15_240, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `m` is `[0, 1024]`.
+	fn sub_min_sig_fast_aggregate_verify(x: u32, m: u32, ) -> Weight {
+		Weight::from_parts(5_523_000_000, 0)
+			.saturating_add(Weight::from_parts(817_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(15_180, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `m` is `[16, 1024]`.
+	fn ark_min_sig_aggregate_verify(x: u32, m: u32, ) -> Weight {
+		Weight::from_parts(10_098_000_000, 0)
+			.saturating_add(Weight::from_parts(8_953_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(15_240, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `m` is `[16, 1024]`.
+	fn sub_min_sig_aggregate_verify(x: u32, m: u32, ) -> Weight {
+		Weight::from_parts(2_505_000_000, 0)
+			.saturating_add(Weight::from_parts(3_824_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(15_180, 0).saturating_mul(m.into()))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// The range of component `m` is `[0, 1024]`.
+	fn ark_min_pk_verify(m: u32, ) -> Weight {
+		Weight::from_parts(22_237_000_000, 0)
+			.saturating_add(Weight::from_parts(15_310, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `m` is `[0, 1024]`.
+	fn sub_min_pk_verify(m: u32, ) -> Weight {
+		Weight::from_parts(7_661_000_000, 0)
+			.saturating_add(Weight::from_parts(15_270, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `m` is `[0, 1024]`.
+	fn ark_min_pk_fast_aggregate_verify(x: u32, m: u32, ) -> Weight {
+		Weight::from_parts(22_237_000_000, 0)
+			.saturating_add(Weight::from_parts(612_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(15_310, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `m` is `[0, 1024]`.
+	fn sub_min_pk_fast_aggregate_verify(x: u32, m: u32, ) -> Weight {
+		Weight::from_parts(7_661_000_000, 0)
+			.saturating_add(Weight::from_parts(254_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(15_270, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `m` is `[16, 1024]`.
+	fn ark_min_pk_aggregate_verify(x: u32, m: u32, ) -> Weight {
+		Weight::from_parts(10_098_000_000, 0)
+			.saturating_add(Weight::from_parts(12_651_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(15_310, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `m` is `[16, 1024]`.
+	fn sub_min_pk_aggregate_verify(x: u32, m: u32, ) -> Weight {
+		Weight::from_parts(2_505_000_000, 0)
+			.saturating_add(Weight::from_parts(5_412_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(15_270, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `m` is `[0, 1024]`.
+	fn ark_min_sig_verify(m: u32, ) -> Weight {
+		Weight::from_parts(17_051_000_000, 0)
+			.saturating_add(Weight::from_parts(15_240, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `m` is `[0, 1024]`.
+	fn sub_min_sig_verify(m: u32, ) -> Weight {
+		Weight::from_parts(5_523_000_000, 0)
+			.saturating_add(Weight::from_parts(15_180, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `m` is `[0, 1024]`.
+	fn ark_min_sig_fast_aggregate_verify(x: u32, m: u32, ) -> Weight {
+		Weight::from_parts(17_051_000_000, 0)
+			.saturating_add(Weight::from_parts(2_013_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(15_240, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `m` is `[0, 1024]`.
+	fn sub_min_sig_fast_aggregate_verify(x: u32, m: u32, ) -> Weight {
+		Weight::from_parts(5_523_000_000, 0)
+			.saturating_add(Weight::from_parts(817_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(15_180, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `m` is `[16, 1024]`.
+	fn ark_min_sig_aggregate_verify(x: u32, m: u32, ) -> Weight {
+		Weight::from_parts(10_098_000_000, 0)
+			.saturating_add(Weight::from_parts(8_953_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(15_240, 0).saturating_mul(m.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `m` is `[16, 1024]`.
+	fn sub_min_sig_aggregate_verify(x: u32, m: u32, ) -> Weight {
+		Weight::from_parts(2_505_000_000, 0)
+			.saturating_add(Weight::from_parts(3_824_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(15_180, 0).saturating_mul(m.into()))
+	}
+}
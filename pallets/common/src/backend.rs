@@ -0,0 +1,68 @@
+//! Selection of the implementation backing the curve operations.
+
+use ark_scale::ark_serialize::Validate;
+use codec::{Decode, DecodeWithMemTracking, Encode};
+use scale_info::TypeInfo;
+
+/// Implementation backing the curve operations.
+pub trait ArkBackend {
+    /// Use the curve configs backed by Substrate host calls (`sp-crypto-ec-utils`).
+    fn optimized() -> bool;
+}
+
+/// Arkworks curve configs, the whole computation is performed within the runtime.
+pub struct Arkworks;
+
+impl ArkBackend for Arkworks {
+    fn optimized() -> bool {
+        false
+    }
+}
+
+/// Substrate curve configs, the expensive operations are offloaded to host calls.
+pub struct Substrate;
+
+impl ArkBackend for Substrate {
+    fn optimized() -> bool {
+        true
+    }
+}
+
+/// Placeholder of the per call backend switch, the backend is set by `Config::Backend`.
+///
+/// Carries no information and has an empty encoding.
+#[derive(
+    Copy, Clone, Default, PartialEq, Eq, Debug, Encode, Decode, DecodeWithMemTracking, TypeInfo,
+)]
+pub struct ConfigBackend;
+
+/// Per call backend switch.
+///
+/// With the `comparison-mode` feature calls take a flag selecting either the Substrate
+/// (`true`) or the Arkworks (`false`) backend, to compare the two. Otherwise calls take
+/// a `ConfigBackend` placeholder and the backend set by `Config::Backend` is used.
+#[cfg(feature = "comparison-mode")]
+pub type BackendSwitch = bool;
+#[cfg(not(feature = "comparison-mode"))]
+pub type BackendSwitch = ConfigBackend;
+
+/// Whether a call is dispatched to the Substrate backend.
+#[cfg(feature = "comparison-mode")]
+pub fn is_optimized<B: ArkBackend>(optimized: &BackendSwitch) -> bool {
+    *optimized
+}
+
+/// Whether a call is dispatched to the Substrate backend.
+#[cfg(not(feature = "comparison-mode"))]
+pub fn is_optimized<B: ArkBackend>(_: &BackendSwitch) -> bool {
+    B::optimized()
+}
+
+/// Point validation mode matching the `Config::ValidatePoints` flag of a pallet.
+pub fn validation(validate_points: bool) -> Validate {
+    if validate_points {
+        Validate::Yes
+    } else {
+        Validate::No
+    }
+}
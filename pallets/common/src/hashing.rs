@@ -0,0 +1,43 @@
+//! Hash to curve support.
+
+use ark_ec::{
+    hashing::{
+        curve_maps::wb::{WBConfig, WBMap},
+        map_to_curve_hasher::{MapToCurve, MapToCurveBasedHasher},
+        HashToCurveError,
+    },
+    short_weierstrass::{Affine as SWAffine, Projective as SWProjective, SWCurveConfig},
+    AffineRepr, CurveConfig,
+};
+use ark_ff::field_hashers::DefaultFieldHasher;
+use ark_std::marker::PhantomData;
+use sha2::Sha256;
+
+/// WB map to the curve defined by `M`, with the output lifted to the curve config `C`.
+///
+/// `M` and `C` are expected to describe the same curve. This allows the map to be
+/// evaluated using the `M` arithmetic while the cofactor clearing done by
+/// `MapToCurveBasedHasher` uses the `C` backend (e.g. host calls).
+pub struct WBMapFor<M, C>(PhantomData<(M, C)>);
+
+impl<M, C> MapToCurve<SWProjective<C>> for WBMapFor<M, C>
+where
+    M: WBConfig,
+    C: SWCurveConfig<BaseField = <M as CurveConfig>::BaseField>,
+{
+    fn check_parameters() -> Result<(), HashToCurveError> {
+        <WBMap<M> as MapToCurve<SWProjective<M>>>::check_parameters()
+    }
+
+    fn map_to_curve(point: C::BaseField) -> Result<SWAffine<C>, HashToCurveError> {
+        let point = <WBMap<M> as MapToCurve<SWProjective<M>>>::map_to_curve(point)?;
+        Ok(match point.xy() {
+            Some((x, y)) => SWAffine::new_unchecked(x, y),
+            None => SWAffine::identity(),
+        })
+    }
+}
+
+/// RFC 9380 `XMD:SHA-256_SSWU_RO_` hasher to the curve `C`, using the WB map of `M`.
+pub type HasherFor<M, C> =
+    MapToCurveBasedHasher<SWProjective<C>, DefaultFieldHasher<Sha256, 128>, WBMapFor<M, C>>;
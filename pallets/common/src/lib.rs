@@ -0,0 +1,33 @@
+//! Helpers shared by the Arkworks pallets.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod backend;
+pub mod hashing;
+
+pub use backend::*;
+
+use ark_scale::ark_serialize::{CanonicalDeserialize, Compress, Validate};
+use ark_std::vec::Vec;
+
+/// Number of items of an ArkScale encoded vector, as declared by its length prefix.
+pub fn encoded_vec_len(data: &[u8]) -> u32 {
+    data.first_chunk::<8>()
+        .map(|len| u64::from_le_bytes(*len).try_into().unwrap_or(u32::MAX))
+        .unwrap_or_default()
+}
+
+/// Decode an ArkScale encoded vector.
+///
+/// Points are expected in compressed form. With `Validate::Yes` points are checked
+/// to be on the curve and in the prime order subgroup.
+///
+/// Arkworks preallocates the vector using the encoded length prefix, thus we reject
+/// prefixes which can't possibly be backed by the input data before decoding.
+pub fn decode_vec<U: CanonicalDeserialize>(data: &[u8], validate: Validate) -> Option<Vec<U>> {
+    let (len, items) = data.split_first_chunk::<8>()?;
+    if u64::from_le_bytes(*len) > items.len() as u64 {
+        return None;
+    }
+    Vec::<U>::deserialize_with_mode(data, Compress::Yes, validate).ok()
+}
@@ -5,6 +5,40 @@ use crate::Pallet as ArkGroth16;
 use frame_benchmarking::v2::*;
 use frame_system::RawOrigin;
 
+// Min number of public inputs
+const INPUTS_LEN_MIN: u32 = 1;
+// Max number of public inputs
+const INPUTS_LEN_MAX: u32 = 16;
+
+// Min number of proofs of a batch
+const BATCH_LEN_MIN: u32 = 1;
+// Max number of proofs of a batch
+const BATCH_LEN_MAX: u32 = 16;
+
+// Identifier of the registered verifying keys
+const CIRCUIT_ID: CircuitId = 0;
+
+/// Register origin, funded for the deposit of the largest verifying key.
+fn funded_register_origin<T: Config>() -> Result<T::RuntimeOrigin, BenchmarkError> {
+    let origin =
+        T::RegisterOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    let owner =
+        T::RegisterOrigin::ensure_origin(origin.clone()).map_err(|_| BenchmarkError::Weightless)?;
+    let deposit = Pallet::<T>::key_deposit(T::MaxVerifyingKeyLen::get());
+    T::Currency::set_balance(
+        &owner,
+        T::Currency::minimum_balance().saturating_add(deposit),
+    );
+    Ok(origin)
+}
+
+/// Register `vk` as `CIRCUIT_ID`.
+fn register<T: Config>(curve: Curve, vk: Vec<u8>) -> Result<(), BenchmarkError> {
+    let origin = funded_register_origin::<T>()?;
+    Pallet::<T>::register_verifying_key(origin, CIRCUIT_ID, curve, vk)?;
+    Ok(())
+}
+
 #[benchmarks]
 mod benchmarks {
     use super::*;
@@ -14,22 +48,22 @@ mod benchmarks {
     // ---------------------------------------------
 
     #[benchmark]
-    fn ark_bls12_381_groth16_verify() {
-        let (vk, public_input, proof) =
-            utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>();
+    fn ark_bls12_381_groth16_verify(x: Linear<INPUTS_LEN_MIN, INPUTS_LEN_MAX>) {
+        let (vk, public_inputs, proof) =
+            utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(x);
         // utils::bls12_381_groth16_verify_params_get_pregen()
 
         #[extrinsic_call]
-        bls12_381_groth16_verify(RawOrigin::None, vk.0, public_input.0, proof.0, false);
+        bls12_381_groth16_verify(RawOrigin::None, vk.0, public_inputs.0, proof.0, false);
     }
 
     #[benchmark]
-    fn sub_bls12_381_groth16_verify() {
-        let (vk, public_input, proof) =
-            utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>();
+    fn sub_bls12_381_groth16_verify(x: Linear<INPUTS_LEN_MIN, INPUTS_LEN_MAX>) {
+        let (vk, public_inputs, proof) =
+            utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(x);
 
         #[extrinsic_call]
-        bls12_381_groth16_verify(RawOrigin::None, vk.0, public_input.0, proof.0, true);
+        bls12_381_groth16_verify(RawOrigin::None, vk.0, public_inputs.0, proof.0, true);
     }
 
     // ---------------------------------------------
@@ -37,41 +71,269 @@ mod benchmarks {
     // ---------------------------------------------
 
     #[benchmark]
-    fn ark_bls12_377_groth16_verify() {
-        let (vk, public_input, proof) =
-            utils::groth16_verify_params_gen::<ark_bls12_377::Bls12_377>();
+    fn ark_bls12_377_groth16_verify(x: Linear<INPUTS_LEN_MIN, INPUTS_LEN_MAX>) {
+        let (vk, public_inputs, proof) =
+            utils::groth16_verify_params_gen::<ark_bls12_377::Bls12_377>(x);
 
         #[extrinsic_call]
-        bls12_377_groth16_verify(RawOrigin::None, vk.0, public_input.0, proof.0, false);
+        bls12_377_groth16_verify(RawOrigin::None, vk.0, public_inputs.0, proof.0, false);
     }
 
     #[benchmark]
-    fn sub_bls12_377_groth16_verify() {
-        let (vk, public_input, proof) =
-            utils::groth16_verify_params_gen::<ark_bls12_377::Bls12_377>();
+    fn sub_bls12_377_groth16_verify(x: Linear<INPUTS_LEN_MIN, INPUTS_LEN_MAX>) {
+        let (vk, public_inputs, proof) =
+            utils::groth16_verify_params_gen::<ark_bls12_377::Bls12_377>(x);
 
         #[extrinsic_call]
-        bls12_377_groth16_verify(RawOrigin::None, vk.0, public_input.0, proof.0, true);
+        bls12_377_groth16_verify(RawOrigin::None, vk.0, public_inputs.0, proof.0, true);
     }
 
     // ---------------------------------------------
-    // Calls for bls12-377
+    // Calls for bw6-761
+    // ---------------------------------------------
+
+    #[benchmark]
+    fn ark_bw6_761_groth16_verify(x: Linear<INPUTS_LEN_MIN, INPUTS_LEN_MAX>) {
+        let (vk, public_inputs, proof) =
+            utils::groth16_verify_params_gen::<ark_bw6_761::BW6_761>(x);
+
+        #[extrinsic_call]
+        bw6_761_groth16_verify(RawOrigin::None, vk.0, public_inputs.0, proof.0, false);
+    }
+
+    #[benchmark]
+    fn sub_bw6_761_groth16_verify(x: Linear<INPUTS_LEN_MIN, INPUTS_LEN_MAX>) {
+        let (vk, public_inputs, proof) =
+            utils::groth16_verify_params_gen::<ark_bw6_761::BW6_761>(x);
+
+        #[extrinsic_call]
+        bw6_761_groth16_verify(RawOrigin::None, vk.0, public_inputs.0, proof.0, true);
+    }
+
+    // ---------------------------------------------
+    // Verifying key registry
+    // ---------------------------------------------
+
+    #[benchmark]
+    fn bls12_381_register_verifying_key(
+        x: Linear<INPUTS_LEN_MIN, INPUTS_LEN_MAX>,
+    ) -> Result<(), BenchmarkError> {
+        let origin = funded_register_origin::<T>()?;
+        let (vk, _, _) = utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(x);
+
+        #[extrinsic_call]
+        register_verifying_key(
+            origin as T::RuntimeOrigin,
+            CIRCUIT_ID,
+            Curve::Bls12_381,
+            vk.0,
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn bls12_377_register_verifying_key(
+        x: Linear<INPUTS_LEN_MIN, INPUTS_LEN_MAX>,
+    ) -> Result<(), BenchmarkError> {
+        let origin = funded_register_origin::<T>()?;
+        let (vk, _, _) = utils::groth16_verify_params_gen::<ark_bls12_377::Bls12_377>(x);
+
+        #[extrinsic_call]
+        register_verifying_key(
+            origin as T::RuntimeOrigin,
+            CIRCUIT_ID,
+            Curve::Bls12_377,
+            vk.0,
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn bw6_761_register_verifying_key(
+        x: Linear<INPUTS_LEN_MIN, INPUTS_LEN_MAX>,
+    ) -> Result<(), BenchmarkError> {
+        let origin = funded_register_origin::<T>()?;
+        let (vk, _, _) = utils::groth16_verify_params_gen::<ark_bw6_761::BW6_761>(x);
+
+        #[extrinsic_call]
+        register_verifying_key(origin as T::RuntimeOrigin, CIRCUIT_ID, Curve::Bw6_761, vk.0);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn deregister_verifying_key() -> Result<(), BenchmarkError> {
+        let (vk, _, _) = utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(1);
+        register::<T>(Curve::Bls12_381, vk.0)?;
+        let origin =
+            T::RegisterOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        deregister_verifying_key(origin as T::RuntimeOrigin, CIRCUIT_ID);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn ark_bls12_381_groth16_verify_with_registered(
+        x: Linear<INPUTS_LEN_MIN, INPUTS_LEN_MAX>,
+    ) -> Result<(), BenchmarkError> {
+        let (vk, public_inputs, proof) =
+            utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(x);
+        register::<T>(Curve::Bls12_381, vk.0)?;
+
+        #[extrinsic_call]
+        verify_with_registered(RawOrigin::None, CIRCUIT_ID, public_inputs.0, proof.0, false);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn sub_bls12_381_groth16_verify_with_registered(
+        x: Linear<INPUTS_LEN_MIN, INPUTS_LEN_MAX>,
+    ) -> Result<(), BenchmarkError> {
+        let (vk, public_inputs, proof) =
+            utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(x);
+        register::<T>(Curve::Bls12_381, vk.0)?;
+
+        #[extrinsic_call]
+        verify_with_registered(RawOrigin::None, CIRCUIT_ID, public_inputs.0, proof.0, true);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn ark_bls12_377_groth16_verify_with_registered(
+        x: Linear<INPUTS_LEN_MIN, INPUTS_LEN_MAX>,
+    ) -> Result<(), BenchmarkError> {
+        let (vk, public_inputs, proof) =
+            utils::groth16_verify_params_gen::<ark_bls12_377::Bls12_377>(x);
+        register::<T>(Curve::Bls12_377, vk.0)?;
+
+        #[extrinsic_call]
+        verify_with_registered(RawOrigin::None, CIRCUIT_ID, public_inputs.0, proof.0, false);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn sub_bls12_377_groth16_verify_with_registered(
+        x: Linear<INPUTS_LEN_MIN, INPUTS_LEN_MAX>,
+    ) -> Result<(), BenchmarkError> {
+        let (vk, public_inputs, proof) =
+            utils::groth16_verify_params_gen::<ark_bls12_377::Bls12_377>(x);
+        register::<T>(Curve::Bls12_377, vk.0)?;
+
+        #[extrinsic_call]
+        verify_with_registered(RawOrigin::None, CIRCUIT_ID, public_inputs.0, proof.0, true);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn ark_bw6_761_groth16_verify_with_registered(
+        x: Linear<INPUTS_LEN_MIN, INPUTS_LEN_MAX>,
+    ) -> Result<(), BenchmarkError> {
+        let (vk, public_inputs, proof) =
+            utils::groth16_verify_params_gen::<ark_bw6_761::BW6_761>(x);
+        register::<T>(Curve::Bw6_761, vk.0)?;
+
+        #[extrinsic_call]
+        verify_with_registered(RawOrigin::None, CIRCUIT_ID, public_inputs.0, proof.0, false);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn sub_bw6_761_groth16_verify_with_registered(
+        x: Linear<INPUTS_LEN_MIN, INPUTS_LEN_MAX>,
+    ) -> Result<(), BenchmarkError> {
+        let (vk, public_inputs, proof) =
+            utils::groth16_verify_params_gen::<ark_bw6_761::BW6_761>(x);
+        register::<T>(Curve::Bw6_761, vk.0)?;
+
+        #[extrinsic_call]
+        verify_with_registered(RawOrigin::None, CIRCUIT_ID, public_inputs.0, proof.0, true);
+
+        Ok(())
+    }
+
+    // ---------------------------------------------
+    // Batch verification
     // ---------------------------------------------
 
     #[benchmark]
-    fn ark_bw6_761_groth16_verify() {
-        let (vk, public_input, proof) = utils::groth16_verify_params_gen::<ark_bw6_761::BW6_761>();
+    fn ark_bls12_381_groth16_verify_batch(
+        x: Linear<BATCH_LEN_MIN, BATCH_LEN_MAX>,
+        y: Linear<INPUTS_LEN_MIN, INPUTS_LEN_MAX>,
+    ) {
+        let (vk, batch) = utils::groth16_verify_batch_params_gen::<ark_bls12_381::Bls12_381>(x, y);
+        let batch = batch.try_into().unwrap();
+
+        #[extrinsic_call]
+        bls12_381_groth16_verify_batch(RawOrigin::None, vk.0, batch, false);
+    }
+
+    #[benchmark]
+    fn sub_bls12_381_groth16_verify_batch(
+        x: Linear<BATCH_LEN_MIN, BATCH_LEN_MAX>,
+        y: Linear<INPUTS_LEN_MIN, INPUTS_LEN_MAX>,
+    ) {
+        let (vk, batch) = utils::groth16_verify_batch_params_gen::<ark_bls12_381::Bls12_381>(x, y);
+        let batch = batch.try_into().unwrap();
+
+        #[extrinsic_call]
+        bls12_381_groth16_verify_batch(RawOrigin::None, vk.0, batch, true);
+    }
+
+    #[benchmark]
+    fn ark_bls12_377_groth16_verify_batch(
+        x: Linear<BATCH_LEN_MIN, BATCH_LEN_MAX>,
+        y: Linear<INPUTS_LEN_MIN, INPUTS_LEN_MAX>,
+    ) {
+        let (vk, batch) = utils::groth16_verify_batch_params_gen::<ark_bls12_377::Bls12_377>(x, y);
+        let batch = batch.try_into().unwrap();
+
+        #[extrinsic_call]
+        bls12_377_groth16_verify_batch(RawOrigin::None, vk.0, batch, false);
+    }
+
+    #[benchmark]
+    fn sub_bls12_377_groth16_verify_batch(
+        x: Linear<BATCH_LEN_MIN, BATCH_LEN_MAX>,
+        y: Linear<INPUTS_LEN_MIN, INPUTS_LEN_MAX>,
+    ) {
+        let (vk, batch) = utils::groth16_verify_batch_params_gen::<ark_bls12_377::Bls12_377>(x, y);
+        let batch = batch.try_into().unwrap();
+
+        #[extrinsic_call]
+        bls12_377_groth16_verify_batch(RawOrigin::None, vk.0, batch, true);
+    }
+
+    #[benchmark]
+    fn ark_bw6_761_groth16_verify_batch(
+        x: Linear<BATCH_LEN_MIN, BATCH_LEN_MAX>,
+        y: Linear<INPUTS_LEN_MIN, INPUTS_LEN_MAX>,
+    ) {
+        let (vk, batch) = utils::groth16_verify_batch_params_gen::<ark_bw6_761::BW6_761>(x, y);
+        let batch = batch.try_into().unwrap();
 
         #[extrinsic_call]
-        bw6_761_groth16_verify(RawOrigin::None, vk.0, public_input.0, proof.0, false);
+        bw6_761_groth16_verify_batch(RawOrigin::None, vk.0, batch, false);
     }
 
     #[benchmark]
-    fn sub_bw6_761_groth16_verify() {
-        let (vk, public_input, proof) = utils::groth16_verify_params_gen::<ark_bw6_761::BW6_761>();
+    fn sub_bw6_761_groth16_verify_batch(
+        x: Linear<BATCH_LEN_MIN, BATCH_LEN_MAX>,
+        y: Linear<INPUTS_LEN_MIN, INPUTS_LEN_MAX>,
+    ) {
+        let (vk, batch) = utils::groth16_verify_batch_params_gen::<ark_bw6_761::BW6_761>(x, y);
+        let batch = batch.try_into().unwrap();
 
         #[extrinsic_call]
-        bw6_761_groth16_verify(RawOrigin::None, vk.0, public_input.0, proof.0, true);
+        bw6_761_groth16_verify_batch(RawOrigin::None, vk.0, batch, true);
     }
 
     impl_benchmark_test_suite!(ArkGroth16, crate::mock::new_test_ext(), crate::mock::Test);
@@ -11,14 +11,26 @@ mod benchmarking;
 mod utils;
 mod weights;
 
-use frame_support::pallet_prelude::*;
+use frame_support::{
+    pallet_prelude::*,
+    sp_runtime::traits::{Hash, Saturating},
+    traits::{
+        fungible::{Inspect, Mutate, MutateHold},
+        tokens::Precision,
+    },
+    CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
 use frame_system::pallet_prelude::*;
 
-use ark_ec::pairing::Pairing;
-use ark_groth16::Groth16;
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup, VariableBaseMSM};
+use ark_ff::{PrimeField, Zero};
+use ark_groth16::{Groth16, PreparedVerifyingKey};
+use ark_scale::ark_serialize::{CanonicalSerialize, Validate};
 use ark_snark::SNARK;
 use ark_std::vec::Vec;
+use pallet_ark_common::{encoded_vec_len, is_optimized, validation};
 
+pub use pallet_ark_common::{ArkBackend, Arkworks, BackendSwitch, ConfigBackend, Substrate};
 pub use sp_crypto_ec_utils::{
     bls12_377 as sub_bls12_377, bls12_381 as sub_bls12_381, bw6_761 as sub_bw6_761,
 };
@@ -29,9 +41,7 @@ pub type ArkScaleHost<T> = ark_scale::ArkScale<T, { ark_scale::HOST_CALL }>;
 pub use pallet::*;
 pub use weights::*;
 
-use crate::utils::deserialize_uncompressed_host;
-
-const DEFAULT_WEIGHT: u64 = 10_000;
+use crate::utils::{deserialize_uncompressed, serialize_uncompressed_host};
 
 pub type ScalarFieldFor<PairingT> = <PairingT as Pairing>::ScalarField;
 
@@ -44,12 +54,114 @@ pub type ProverKeyFor<PairingT> =
 pub type ProofFor<PairingT> =
     <Groth16<PairingT> as SNARK<<PairingT as Pairing>::ScalarField>>::Proof;
 
-pub fn groth16_verify<P: Pairing>(vk: Vec<u8>, c: Vec<u8>, proof: Vec<u8>) {
-    let vk = deserialize_uncompressed_host::<VerifierKeyFor<P>>(vk);
-    let c = deserialize_uncompressed_host::<ScalarFieldFor<P>>(c);
-    let proof = deserialize_uncompressed_host::<ProofFor<P>>(proof);
-    let result = Groth16::<P>::verify(&vk, &[c], &proof).unwrap();
-    assert!(result);
+/// Pairing friendly curve of a registered verifying key.
+#[derive(
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum Curve {
+    Bls12_381,
+    Bls12_377,
+    Bw6_761,
+}
+
+/// Identifier of a registered verifying key.
+pub type CircuitId = u32;
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Registered verifying key.
+#[derive(
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    RuntimeDebugNoBound,
+    Encode,
+    Decode,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct RegisteredKey<T: Config> {
+    /// Account which registered the key and holds the deposit.
+    pub owner: T::AccountId,
+    /// Deposit held for the key storage.
+    pub deposit: BalanceOf<T>,
+    /// Curve of the key.
+    pub curve: Curve,
+    /// Hash of the verifying key encoding, as passed on registration.
+    pub vk_hash: T::Hash,
+    /// Uncompressed encoding of the verifying key and of its `alpha_g1_beta_g2` pairing.
+    pub key: BoundedVec<u8, T::MaxVerifyingKeyLen>,
+}
+
+/// Proof verified within a batch.
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, DecodeWithMemTracking, TypeInfo)]
+pub struct ProofBatchItem {
+    /// Encoding of the vector of public inputs.
+    pub inputs: Vec<u8>,
+    /// Encoding of the proof.
+    pub proof: Vec<u8>,
+}
+
+/// Proofs verified together against the same verifying key.
+pub type ProofBatch<MaxSize> = BoundedVec<ProofBatchItem, MaxSize>;
+
+/// Domain separator of the batch verification challenges.
+const BATCH_DOMAIN: &[u8] = b"pallet-ark-groth16/batch";
+
+/// Length prefix of the `gamma_abc_g1` vector of an uncompressed verifying key, along
+/// with the data following it.
+fn split_gamma_abc_g1<P: Pairing>(vk: &[u8]) -> Option<(u64, &[u8])> {
+    let g1_size = P::G1Affine::generator().uncompressed_size();
+    let g2_size = P::G2Affine::generator().uncompressed_size();
+    // `gamma_abc_g1` follows `alpha_g1`, `beta_g2`, `gamma_g2` and `delta_g2`.
+    let (len, points) = vk.get(g1_size + 3 * g2_size..)?.split_first_chunk::<8>()?;
+    Some((u64::from_le_bytes(*len), points))
+}
+
+/// Number of public inputs accounted for by the call weight.
+///
+/// Inputs longer than `Config::MaxPublicInputs` are rejected before any work is done.
+fn inputs_len<T: Config>(inputs: &[u8]) -> u32 {
+    encoded_vec_len(inputs).min(T::MaxPublicInputs::get())
+}
+
+/// Number of public inputs of a verifying key accounted for by the call weight.
+///
+/// Keys with more than `Config::MaxPublicInputs` inputs are rejected before decoding.
+fn vk_inputs_len<T: Config, P: Pairing>(vk: &[u8]) -> u32 {
+    split_gamma_abc_g1::<P>(vk)
+        .map(|(len, _)| len.saturating_sub(1).min(T::MaxPublicInputs::get() as u64) as u32)
+        .unwrap_or_default()
+}
+
+/// Number of public inputs of a verification accounted for by the call weight.
+///
+/// The key is decoded and its inputs prepared whatever the number of inputs supplied,
+/// so the larger of the key and of the supplied inputs counts is used.
+fn verify_inputs_len<T: Config, P: Pairing>(vk: &[u8], inputs: &[u8]) -> u32 {
+    inputs_len::<T>(inputs).max(vk_inputs_len::<T, P>(vk))
+}
+
+/// Number of public inputs of a batch accounted for by the call weight.
+///
+/// Proofs of a batch share the verifying key, so the largest number of inputs is used.
+fn batch_inputs_len<T: Config, P: Pairing>(vk: &[u8], batch: &[ProofBatchItem]) -> u32 {
+    batch
+        .iter()
+        .map(|item| inputs_len::<T>(&item.inputs))
+        .fold(vk_inputs_len::<T, P>(vk), u32::max)
 }
 
 #[frame_support::pallet]
@@ -61,65 +173,650 @@ pub mod pallet {
     pub struct Pallet<T>(_);
 
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> {
+        /// Check that verifying key and proof points are on the curve and in the prime
+        /// order subgroup.
+        #[pallet::constant]
+        type ValidatePoints: Get<bool>;
+
+        /// Max number of public inputs of a proof.
+        #[pallet::constant]
+        type MaxPublicInputs: Get<u32>;
+
+        /// Max number of proofs of a batch verification.
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+
+        /// Max length of the encoding of a registered verifying key.
+        #[pallet::constant]
+        type MaxVerifyingKeyLen: Get<u32>;
+
+        /// Origin allowed to register verifying keys, returning the account holding the
+        /// deposit.
+        type RegisterOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+        /// Currency of the verifying key deposits.
+        type Currency: Mutate<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+        /// Overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Base deposit for registering a verifying key.
+        #[pallet::constant]
+        type KeyDepositBase: Get<BalanceOf<Self>>;
+
+        /// Deposit per byte of a registered verifying key.
+        #[pallet::constant]
+        type KeyDepositPerByte: Get<BalanceOf<Self>>;
+
+        /// Backend of the curve operations.
+        ///
+        /// Ignored with the `comparison-mode` feature, where the backend is selected per call.
+        type Backend: ArkBackend;
+
         /// Extrinsic weights
         type WeightInfo: WeightInfo;
     }
 
+    /// Reasons of the funds held by the pallet.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Deposit of a registered verifying key.
+        VerifyingKeyDeposit,
+    }
+
+    /// Registered verifying keys.
+    ///
+    /// Keys are validated on registration and stored prepared, that is along with the
+    /// `alpha_g1_beta_g2` pairing, thus later verifications skip validation and a pairing.
+    #[pallet::storage]
+    pub type VerifyingKeys<T: Config> = StorageMap<_, Twox64Concat, CircuitId, RegisteredKey<T>>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A verifying key has been registered.
+        VerifyingKeyRegistered {
+            circuit_id: CircuitId,
+            curve: Curve,
+            owner: T::AccountId,
+        },
+        /// A verifying key has been deregistered.
+        VerifyingKeyDeregistered { circuit_id: CircuitId },
+        /// A proof has been verified.
+        ///
+        /// `vk_hash` and `inputs_hash` are the hashes of the verifying key and public inputs
+        /// encodings, the key being the one passed on registration for registered keys.
+        ProofVerified {
+            curve: Curve,
+            vk_hash: T::Hash,
+            inputs_hash: T::Hash,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Verifying key encoding is invalid or its points are not valid.
+        MalformedVerifyingKey,
+        /// Proof encoding is invalid or its points are not valid.
+        MalformedProof,
+        /// Public inputs encoding is invalid.
+        MalformedInput,
+        /// Proof verification failed.
+        InvalidProof,
+        /// Number of public inputs exceeds `Config::MaxPublicInputs`.
+        TooManyInputs,
+        /// Number of public inputs doesn't match the verifying key.
+        InputCountMismatch,
+        /// Circuit identifier is already registered.
+        CircuitExists,
+        /// Circuit identifier is not registered.
+        UnknownCircuit,
+        /// Verifying key encoding exceeds `Config::MaxVerifyingKeyLen`.
+        VerifyingKeyTooLarge,
+        /// Origin is not the owner of the verifying key.
+        NotKeyOwner,
+        /// Batch verification of no proofs.
+        EmptyBatch,
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// BLS12-381 Groth16 proof verification
+        ///
+        /// `inputs` is the encoding of the vector of public inputs, one per element of
+        /// `vk.gamma_abc_g1` but the first.
         #[pallet::call_index(1)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_381_groth16_verify(
+                verify_inputs_len::<T, ark_bls12_381::Bls12_381>(vk, inputs)
+            )
+        } else {
+            T::WeightInfo::ark_bls12_381_groth16_verify(
+                verify_inputs_len::<T, ark_bls12_381::Bls12_381>(vk, inputs)
+            )
+        })]
         pub fn bls12_381_groth16_verify(
             _: OriginFor<T>,
             vk: Vec<u8>,
-            c: Vec<u8>,
+            inputs: Vec<u8>,
             proof: Vec<u8>,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
-                groth16_verify::<sub_bls12_381::Bls12_381>(vk, c, proof);
+            let curve = Curve::Bls12_381;
+            if is_optimized::<T::Backend>(&optimized) {
+                Self::groth16_verify::<sub_bls12_381::Bls12_381>(curve, vk, inputs, proof)
             } else {
-                groth16_verify::<ark_bls12_381::Bls12_381>(vk, c, proof);
+                Self::groth16_verify::<ark_bls12_381::Bls12_381>(curve, vk, inputs, proof)
             }
-            Ok(())
         }
 
         /// BLS12-377 Groth16 proof verification
+        ///
+        /// `inputs` is the encoding of the vector of public inputs, one per element of
+        /// `vk.gamma_abc_g1` but the first.
         #[pallet::call_index(2)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_377_groth16_verify(
+                verify_inputs_len::<T, ark_bls12_377::Bls12_377>(vk, inputs)
+            )
+        } else {
+            T::WeightInfo::ark_bls12_377_groth16_verify(
+                verify_inputs_len::<T, ark_bls12_377::Bls12_377>(vk, inputs)
+            )
+        })]
         pub fn bls12_377_groth16_verify(
             _: OriginFor<T>,
             vk: Vec<u8>,
-            c: Vec<u8>,
+            inputs: Vec<u8>,
             proof: Vec<u8>,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
-                groth16_verify::<sub_bls12_377::Bls12_377>(vk, c, proof);
+            let curve = Curve::Bls12_377;
+            if is_optimized::<T::Backend>(&optimized) {
+                Self::groth16_verify::<sub_bls12_377::Bls12_377>(curve, vk, inputs, proof)
             } else {
-                groth16_verify::<ark_bls12_377::Bls12_377>(vk, c, proof);
+                Self::groth16_verify::<ark_bls12_377::Bls12_377>(curve, vk, inputs, proof)
             }
-            Ok(())
         }
 
         /// BW6-761 Groth16 proof verification
+        ///
+        /// `inputs` is the encoding of the vector of public inputs, one per element of
+        /// `vk.gamma_abc_g1` but the first.
         #[pallet::call_index(3)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bw6_761_groth16_verify(
+                verify_inputs_len::<T, ark_bw6_761::BW6_761>(vk, inputs)
+            )
+        } else {
+            T::WeightInfo::ark_bw6_761_groth16_verify(
+                verify_inputs_len::<T, ark_bw6_761::BW6_761>(vk, inputs)
+            )
+        })]
         pub fn bw6_761_groth16_verify(
             _: OriginFor<T>,
             vk: Vec<u8>,
-            c: Vec<u8>,
+            inputs: Vec<u8>,
+            proof: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let curve = Curve::Bw6_761;
+            if is_optimized::<T::Backend>(&optimized) {
+                Self::groth16_verify::<sub_bw6_761::BW6_761>(curve, vk, inputs, proof)
+            } else {
+                Self::groth16_verify::<ark_bw6_761::BW6_761>(curve, vk, inputs, proof)
+            }
+        }
+
+        /// Register a verifying key for `verify_with_registered`.
+        ///
+        /// The key is encoded as for the `*_groth16_verify` calls. It is validated once
+        /// using the host calls backend and a deposit proportional to its stored size is
+        /// held from the origin account. Weight is charged for `Config::MaxPublicInputs`
+        /// inputs and the excess is refunded.
+        #[pallet::call_index(4)]
+        #[pallet::weight(Pallet::<T>::register_verifying_key_weight(
+            *curve,
+            T::MaxPublicInputs::get()
+        ))]
+        pub fn register_verifying_key(
+            origin: OriginFor<T>,
+            circuit_id: CircuitId,
+            curve: Curve,
+            vk: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let owner = T::RegisterOrigin::ensure_origin(origin)?;
+            ensure!(
+                !VerifyingKeys::<T>::contains_key(circuit_id),
+                Error::<T>::CircuitExists
+            );
+            let vk_hash = T::Hashing::hash(&vk);
+            let (key, inputs) = match curve {
+                Curve::Bls12_381 => Self::prepare_verifying_key::<sub_bls12_381::Bls12_381>(vk),
+                Curve::Bls12_377 => Self::prepare_verifying_key::<sub_bls12_377::Bls12_377>(vk),
+                Curve::Bw6_761 => Self::prepare_verifying_key::<sub_bw6_761::BW6_761>(vk),
+            }?;
+            let key: BoundedVec<_, _> = key
+                .try_into()
+                .map_err(|_| Error::<T>::VerifyingKeyTooLarge)?;
+            let deposit = Self::key_deposit(key.len() as u32);
+            T::Currency::hold(&HoldReason::VerifyingKeyDeposit.into(), &owner, deposit)?;
+            VerifyingKeys::<T>::insert(
+                circuit_id,
+                RegisteredKey {
+                    owner: owner.clone(),
+                    deposit,
+                    curve,
+                    vk_hash,
+                    key,
+                },
+            );
+            Self::deposit_event(Event::VerifyingKeyRegistered {
+                circuit_id,
+                curve,
+                owner,
+            });
+            Ok(Some(Self::register_verifying_key_weight(curve, inputs)).into())
+        }
+
+        /// Deregister a verifying key, releasing its deposit.
+        ///
+        /// Only the account which registered the key can deregister it.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::deregister_verifying_key())]
+        pub fn deregister_verifying_key(
+            origin: OriginFor<T>,
+            circuit_id: CircuitId,
+        ) -> DispatchResult {
+            let who = T::RegisterOrigin::ensure_origin(origin)?;
+            let key = VerifyingKeys::<T>::get(circuit_id).ok_or(Error::<T>::UnknownCircuit)?;
+            ensure!(key.owner == who, Error::<T>::NotKeyOwner);
+            T::Currency::release(
+                &HoldReason::VerifyingKeyDeposit.into(),
+                &who,
+                key.deposit,
+                Precision::BestEffort,
+            )?;
+            VerifyingKeys::<T>::remove(circuit_id);
+            Self::deposit_event(Event::VerifyingKeyDeregistered { circuit_id });
+            Ok(())
+        }
+
+        /// Groth16 proof verification with a registered verifying key.
+        ///
+        /// `inputs` and `proof` are encoded as for the `*_groth16_verify` calls of the key
+        /// curve. Weight is charged for the most expensive curve and the excess is refunded.
+        #[pallet::call_index(6)]
+        #[pallet::weight(Pallet::<T>::verify_with_registered_max_weight(
+            inputs_len::<T>(inputs),
+            is_optimized::<T::Backend>(optimized)
+        ))]
+        pub fn verify_with_registered(
+            _: OriginFor<T>,
+            circuit_id: CircuitId,
+            inputs: Vec<u8>,
             proof: Vec<u8>,
-            optimized: bool,
+            optimized: BackendSwitch,
+        ) -> DispatchResultWithPostInfo {
+            use Curve::*;
+            let optimized = is_optimized::<T::Backend>(&optimized);
+            let key = VerifyingKeys::<T>::get(circuit_id).ok_or(Error::<T>::UnknownCircuit)?;
+            let weight =
+                Self::verify_with_registered_weight(key.curve, inputs_len::<T>(&inputs), optimized);
+            match (key.curve, optimized) {
+                (Bls12_381, true) => {
+                    Self::verify_prepared::<sub_bls12_381::Bls12_381>(&key, inputs, proof)
+                }
+                (Bls12_381, false) => {
+                    Self::verify_prepared::<ark_bls12_381::Bls12_381>(&key, inputs, proof)
+                }
+                (Bls12_377, true) => {
+                    Self::verify_prepared::<sub_bls12_377::Bls12_377>(&key, inputs, proof)
+                }
+                (Bls12_377, false) => {
+                    Self::verify_prepared::<ark_bls12_377::Bls12_377>(&key, inputs, proof)
+                }
+                (Bw6_761, true) => {
+                    Self::verify_prepared::<sub_bw6_761::BW6_761>(&key, inputs, proof)
+                }
+                (Bw6_761, false) => {
+                    Self::verify_prepared::<ark_bw6_761::BW6_761>(&key, inputs, proof)
+                }
+            }?;
+            Ok(Some(weight).into())
+        }
+
+        /// BLS12-381 Groth16 batch verification
+        ///
+        /// Verifies the proofs of `batch` against `vk`, each encoded as for
+        /// `bls12_381_groth16_verify`, with a single multi Miller loop and final
+        /// exponentiation.
+        #[pallet::call_index(7)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_381_groth16_verify_batch(
+                batch.len() as u32,
+                batch_inputs_len::<T, ark_bls12_381::Bls12_381>(vk, batch)
+            )
+        } else {
+            T::WeightInfo::ark_bls12_381_groth16_verify_batch(
+                batch.len() as u32,
+                batch_inputs_len::<T, ark_bls12_381::Bls12_381>(vk, batch)
+            )
+        })]
+        pub fn bls12_381_groth16_verify_batch(
+            _: OriginFor<T>,
+            vk: Vec<u8>,
+            batch: ProofBatch<T::MaxBatchSize>,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
-                groth16_verify::<sub_bw6_761::BW6_761>(vk, c, proof);
+            let curve = Curve::Bls12_381;
+            if is_optimized::<T::Backend>(&optimized) {
+                Self::groth16_verify_batch::<sub_bls12_381::Bls12_381>(curve, vk, batch)
             } else {
-                groth16_verify::<ark_bw6_761::BW6_761>(vk, c, proof);
+                Self::groth16_verify_batch::<ark_bls12_381::Bls12_381>(curve, vk, batch)
             }
+        }
+
+        /// BLS12-377 Groth16 batch verification
+        ///
+        /// Verifies the proofs of `batch` against `vk`, each encoded as for
+        /// `bls12_377_groth16_verify`, with a single multi Miller loop and final
+        /// exponentiation.
+        #[pallet::call_index(8)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_377_groth16_verify_batch(
+                batch.len() as u32,
+                batch_inputs_len::<T, ark_bls12_377::Bls12_377>(vk, batch)
+            )
+        } else {
+            T::WeightInfo::ark_bls12_377_groth16_verify_batch(
+                batch.len() as u32,
+                batch_inputs_len::<T, ark_bls12_377::Bls12_377>(vk, batch)
+            )
+        })]
+        pub fn bls12_377_groth16_verify_batch(
+            _: OriginFor<T>,
+            vk: Vec<u8>,
+            batch: ProofBatch<T::MaxBatchSize>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let curve = Curve::Bls12_377;
+            if is_optimized::<T::Backend>(&optimized) {
+                Self::groth16_verify_batch::<sub_bls12_377::Bls12_377>(curve, vk, batch)
+            } else {
+                Self::groth16_verify_batch::<ark_bls12_377::Bls12_377>(curve, vk, batch)
+            }
+        }
+
+        /// BW6-761 Groth16 batch verification
+        ///
+        /// Verifies the proofs of `batch` against `vk`, each encoded as for
+        /// `bw6_761_groth16_verify`, with a single multi Miller loop and final
+        /// exponentiation.
+        #[pallet::call_index(9)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bw6_761_groth16_verify_batch(
+                batch.len() as u32,
+                batch_inputs_len::<T, ark_bw6_761::BW6_761>(vk, batch)
+            )
+        } else {
+            T::WeightInfo::ark_bw6_761_groth16_verify_batch(
+                batch.len() as u32,
+                batch_inputs_len::<T, ark_bw6_761::BW6_761>(vk, batch)
+            )
+        })]
+        pub fn bw6_761_groth16_verify_batch(
+            _: OriginFor<T>,
+            vk: Vec<u8>,
+            batch: ProofBatch<T::MaxBatchSize>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let curve = Curve::Bw6_761;
+            if is_optimized::<T::Backend>(&optimized) {
+                Self::groth16_verify_batch::<sub_bw6_761::BW6_761>(curve, vk, batch)
+            } else {
+                Self::groth16_verify_batch::<ark_bw6_761::BW6_761>(curve, vk, batch)
+            }
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        fn groth16_verify<P: Pairing>(
+            curve: Curve,
+            vk: Vec<u8>,
+            inputs: Vec<u8>,
+            proof: Vec<u8>,
+        ) -> DispatchResult {
+            ensure!(
+                encoded_vec_len(&inputs) <= T::MaxPublicInputs::get(),
+                Error::<T>::TooManyInputs
+            );
+            let vk_hash = T::Hashing::hash(&vk);
+            let vk = Self::decode_verifying_key::<P>(&vk)?;
+            let public_inputs = Self::decode_inputs::<P>(&vk, &inputs)?;
+            let proof = Self::decode_proof::<P>(&proof)?;
+            let valid = Groth16::<P>::verify(&vk, &public_inputs, &proof)
+                .map_err(|_| Error::<T>::InvalidProof)?;
+            ensure!(valid, Error::<T>::InvalidProof);
+            Self::deposit_proof_verified(curve, vk_hash, &inputs);
             Ok(())
         }
+
+        /// Verify a batch of proofs against the same verifying key.
+        ///
+        /// The `i`-th proof `(A_i, B_i, C_i)`, with prepared inputs `L_i`, is weighted by
+        /// the challenge `r_i`, checking
+        /// `Π e(r_i A_i, B_i) · e(-Σ r_i L_i, γ) · e(-Σ r_i C_i, δ) · e(-(Σ r_i) α, β) = 1`.
+        /// The inputs of all the proofs are prepared by a single MSM, as
+        /// `Σ r_i L_i = (Σ r_i) γ_abc_0 + Σ_j (Σ_i r_i x_ij) γ_abc_j`.
+        fn groth16_verify_batch<P: Pairing>(
+            curve: Curve,
+            vk: Vec<u8>,
+            batch: ProofBatch<T::MaxBatchSize>,
+        ) -> DispatchResult {
+            ensure!(!batch.is_empty(), Error::<T>::EmptyBatch);
+            ensure!(
+                batch
+                    .iter()
+                    .all(|item| encoded_vec_len(&item.inputs) <= T::MaxPublicInputs::get()),
+                Error::<T>::TooManyInputs
+            );
+            let challenges = Self::batch_challenges::<ScalarFieldFor<P>>(&vk, &batch);
+            let vk_hash = T::Hashing::hash(&vk);
+            let vk = Self::decode_verifying_key::<P>(&vk)?;
+
+            let mut input_scalars =
+                ark_std::vec![ScalarFieldFor::<P>::zero(); vk.gamma_abc_g1.len()];
+            let mut g1 = Vec::with_capacity(batch.len() + 3);
+            let mut g2 = Vec::with_capacity(batch.len() + 3);
+            let mut c = Vec::with_capacity(batch.len());
+            for (item, r) in batch.iter().zip(&challenges) {
+                let inputs = Self::decode_inputs::<P>(&vk, &item.inputs)?;
+                let proof = Self::decode_proof::<P>(&item.proof)?;
+                input_scalars[0] += r;
+                for (scalar, input) in input_scalars[1..].iter_mut().zip(inputs) {
+                    *scalar += *r * input;
+                }
+                g1.push((proof.a * r).into_affine());
+                g2.push(proof.b);
+                c.push(proof.c);
+            }
+            let r_sum = challenges.iter().sum::<ScalarFieldFor<P>>();
+            let inputs = P::G1::msm_unchecked(&vk.gamma_abc_g1, &input_scalars);
+            let c = P::G1::msm_unchecked(&c, &challenges);
+            g1.extend([
+                (-inputs).into_affine(),
+                (-c).into_affine(),
+                (vk.alpha_g1 * -r_sum).into_affine(),
+            ]);
+            g2.extend([vk.gamma_g2, vk.delta_g2, vk.beta_g2]);
+
+            let result = P::multi_pairing(g1, g2);
+            ensure!(result.is_zero(), Error::<T>::InvalidProof);
+            for item in &batch {
+                Self::deposit_proof_verified(curve, vk_hash, &item.inputs);
+            }
+            Ok(())
+        }
+
+        /// Fiat-Shamir challenges of a batch verification, one per proof.
+        ///
+        /// Derived from the hash of the verifying key and of the whole batch, so they are
+        /// fixed only once all the proofs are.
+        fn batch_challenges<F: PrimeField>(vk: &[u8], batch: &[ProofBatchItem]) -> Vec<F> {
+            let seed = T::Hashing::hash_of(&(BATCH_DOMAIN, vk, batch));
+            (0..batch.len() as u32)
+                .map(|i| F::from_le_bytes_mod_order(T::Hashing::hash_of(&(seed, i)).as_ref()))
+                .collect()
+        }
+
+        /// Verify a proof with a key encoded by `prepare_verifying_key`.
+        ///
+        /// Weight is charged for the supplied inputs, thus a different number of key inputs
+        /// is rejected before decoding the key.
+        fn verify_prepared<P: Pairing>(
+            key: &RegisteredKey<T>,
+            inputs: Vec<u8>,
+            proof: Vec<u8>,
+        ) -> DispatchResult {
+            ensure!(
+                encoded_vec_len(&inputs) <= T::MaxPublicInputs::get(),
+                Error::<T>::TooManyInputs
+            );
+            ensure!(
+                split_gamma_abc_g1::<P>(&key.key).map(|(len, _)| len)
+                    == Some(encoded_vec_len(&inputs) as u64 + 1),
+                Error::<T>::InputCountMismatch
+            );
+            // Validated on registration
+            let (vk, alpha_g1_beta_g2): (VerifierKeyFor<P>, P::TargetField) =
+                deserialize_uncompressed(&key.key, Validate::No)
+                    .map_err(|_| Error::<T>::MalformedVerifyingKey)?;
+            let public_inputs = Self::decode_inputs::<P>(&vk, &inputs)?;
+            let proof = Self::decode_proof::<P>(&proof)?;
+            let pvk = PreparedVerifyingKey {
+                gamma_g2_neg_pc: P::G2Prepared::from(-vk.gamma_g2.into_group()),
+                delta_g2_neg_pc: P::G2Prepared::from(-vk.delta_g2.into_group()),
+                alpha_g1_beta_g2,
+                vk,
+            };
+            let valid = Groth16::<P>::verify_with_processed_vk(&pvk, &public_inputs, &proof)
+                .map_err(|_| Error::<T>::InvalidProof)?;
+            ensure!(valid, Error::<T>::InvalidProof);
+            Self::deposit_proof_verified(key.curve, key.vk_hash, &inputs);
+            Ok(())
+        }
+
+        /// Decode an uncompressed verifying key.
+        ///
+        /// Arkworks preallocates `gamma_abc_g1` using its encoded length prefix, thus we reject
+        /// prefixes which can't possibly be backed by the input data, or which exceed
+        /// `Config::MaxPublicInputs` plus one, before decoding.
+        fn decode_verifying_key<P: Pairing>(vk: &[u8]) -> Result<VerifierKeyFor<P>, Error<T>> {
+            let g1_size = P::G1Affine::generator().uncompressed_size();
+            let (len, points) =
+                split_gamma_abc_g1::<P>(vk).ok_or(Error::<T>::MalformedVerifyingKey)?;
+            ensure!(
+                len.saturating_mul(g1_size as u64) <= points.len() as u64,
+                Error::<T>::MalformedVerifyingKey
+            );
+            ensure!(
+                len <= T::MaxPublicInputs::get() as u64 + 1,
+                Error::<T>::TooManyInputs
+            );
+            deserialize_uncompressed(vk, validation(T::ValidatePoints::get()))
+                .map_err(|_| Error::<T>::MalformedVerifyingKey)
+        }
+
+        fn decode_proof<P: Pairing>(proof: &[u8]) -> Result<ProofFor<P>, Error<T>> {
+            deserialize_uncompressed(proof, validation(T::ValidatePoints::get()))
+                .map_err(|_| Error::<T>::MalformedProof)
+        }
+
+        fn decode_inputs<P: Pairing>(
+            vk: &VerifierKeyFor<P>,
+            inputs: &[u8],
+        ) -> Result<Vec<ScalarFieldFor<P>>, Error<T>> {
+            let inputs = deserialize_uncompressed::<Vec<ScalarFieldFor<P>>>(inputs, Validate::Yes)
+                .map_err(|_| Error::<T>::MalformedInput)?;
+            ensure!(
+                inputs.len() + 1 == vk.gamma_abc_g1.len(),
+                Error::<T>::InputCountMismatch
+            );
+            Ok(inputs)
+        }
+
+        fn deposit_proof_verified(curve: Curve, vk_hash: T::Hash, inputs: &[u8]) {
+            Self::deposit_event(Event::ProofVerified {
+                curve,
+                vk_hash,
+                inputs_hash: T::Hashing::hash(inputs),
+            });
+        }
+
+        /// Validate a verifying key and encode it along with its `alpha_g1_beta_g2` pairing.
+        ///
+        /// Returns the encoding and the number of public inputs of the key.
+        fn prepare_verifying_key<P: Pairing>(vk: Vec<u8>) -> Result<(Vec<u8>, u32), Error<T>> {
+            // Bounded by `Config::MaxPublicInputs` on decoding.
+            let vk = Self::decode_verifying_key::<P>(&vk)?;
+            let inputs = vk.gamma_abc_g1.len().saturating_sub(1) as u32;
+            let pvk =
+                Groth16::<P>::process_vk(&vk).map_err(|_| Error::<T>::MalformedVerifyingKey)?;
+            Ok((
+                serialize_uncompressed_host((pvk.vk, pvk.alpha_g1_beta_g2)),
+                inputs,
+            ))
+        }
+
+        /// Deposit held for a registered verifying key encoding of `len` bytes.
+        pub(crate) fn key_deposit(len: u32) -> BalanceOf<T> {
+            T::KeyDepositPerByte::get()
+                .saturating_mul(len.into())
+                .saturating_add(T::KeyDepositBase::get())
+        }
+
+        fn register_verifying_key_weight(curve: Curve, inputs: u32) -> Weight {
+            use Curve::*;
+            match curve {
+                Bls12_381 => T::WeightInfo::bls12_381_register_verifying_key(inputs),
+                Bls12_377 => T::WeightInfo::bls12_377_register_verifying_key(inputs),
+                Bw6_761 => T::WeightInfo::bw6_761_register_verifying_key(inputs),
+            }
+        }
+
+        fn verify_with_registered_weight(curve: Curve, inputs: u32, optimized: bool) -> Weight {
+            use Curve::*;
+            match (curve, optimized) {
+                (Bls12_381, true) => {
+                    T::WeightInfo::sub_bls12_381_groth16_verify_with_registered(inputs)
+                }
+                (Bls12_381, false) => {
+                    T::WeightInfo::ark_bls12_381_groth16_verify_with_registered(inputs)
+                }
+                (Bls12_377, true) => {
+                    T::WeightInfo::sub_bls12_377_groth16_verify_with_registered(inputs)
+                }
+                (Bls12_377, false) => {
+                    T::WeightInfo::ark_bls12_377_groth16_verify_with_registered(inputs)
+                }
+                (Bw6_761, true) => {
+                    T::WeightInfo::sub_bw6_761_groth16_verify_with_registered(inputs)
+                }
+                (Bw6_761, false) => {
+                    T::WeightInfo::ark_bw6_761_groth16_verify_with_registered(inputs)
+                }
+            }
+        }
+
+        fn verify_with_registered_max_weight(inputs: u32, optimized: bool) -> Weight {
+            use Curve::*;
+            [Bls12_381, Bls12_377, Bw6_761]
+                .into_iter()
+                .map(|curve| Self::verify_with_registered_weight(curve, inputs, optimized))
+                .fold(Weight::zero(), Weight::max)
+        }
     }
 }
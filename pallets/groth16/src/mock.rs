@@ -1,4 +1,8 @@
-use frame_support::{self, derive_impl, sp_runtime::BuildStorage};
+use crate::{ArkBackend, BackendSwitch};
+use frame_support::{
+    self, derive_impl, parameter_types, sp_runtime::BuildStorage, traits::ConstBool,
+};
+use frame_system::EnsureSigned;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -25,15 +29,77 @@ mod runtime {
     pub type System = frame_system::Pallet<Test>;
 
     #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances::Pallet<Test>;
+
+    #[runtime::pallet_index(2)]
     pub type ArkGroth16 = crate::Pallet<Test>;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for Test {
     type Block = Block;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+
+/// Initial balance of the endowed accounts.
+pub const BALANCE: u64 = 1_000_000;
+
+parameter_types! {
+    pub static Optimized: bool = true;
+    pub MaxPublicInputs: u32 = 16;
+    pub MaxBatchSize: u32 = 16;
+    pub MaxVerifyingKeyLen: u32 = 8192;
+    pub KeyDepositBase: u64 = 1_000;
+    pub KeyDepositPerByte: u64 = 10;
+}
+
+/// Backend selected by the `Optimized` parameter.
+pub struct TestBackend;
+
+impl ArkBackend for TestBackend {
+    fn optimized() -> bool {
+        Optimized::get()
+    }
+}
+
+/// Select the backend of a call.
+///
+/// With the `comparison-mode` feature the selection is passed to the call, otherwise
+/// the backend of the mock runtime is switched.
+#[cfg(feature = "comparison-mode")]
+pub fn backend(optimized: bool) -> BackendSwitch {
+    optimized
+}
+
+/// Select the backend of a call.
+///
+/// With the `comparison-mode` feature the selection is passed to the call, otherwise
+/// the backend of the mock runtime is switched.
+#[cfg(not(feature = "comparison-mode"))]
+pub fn backend(optimized: bool) -> BackendSwitch {
+    Optimized::set(optimized);
+    crate::ConfigBackend
 }
 
 impl crate::Config for Test {
+    type ValidatePoints = ConstBool<true>;
+    type MaxPublicInputs = MaxPublicInputs;
+    type MaxBatchSize = MaxBatchSize;
+    type MaxVerifyingKeyLen = MaxVerifyingKeyLen;
+    type RegisterOrigin = EnsureSigned<u64>;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type KeyDepositBase = KeyDepositBase;
+    type KeyDepositPerByte = KeyDepositPerByte;
+    type Backend = TestBackend;
     type WeightInfo = ();
 }
 
@@ -41,8 +107,17 @@ impl crate::Config for Test {
 pub fn new_test_ext() -> sp_io::TestExternalities {
     sp_tracing::try_init_simple();
 
-    frame_system::GenesisConfig::<Test>::default()
+    let mut storage = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
-        .unwrap()
-        .into()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(ALICE, BALANCE), (BOB, BALANCE)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    let mut ext: sp_io::TestExternalities = storage.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
 }
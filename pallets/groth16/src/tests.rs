@@ -1,18 +1,57 @@
 use crate::{
-    mock::{new_test_ext, ArkGroth16, RuntimeOrigin},
-    utils,
+    mock::{
+        backend, new_test_ext, ArkGroth16, Balances, KeyDepositBase, KeyDepositPerByte,
+        MaxPublicInputs, RuntimeOrigin, System, Test, ALICE, BALANCE, BOB,
+    },
+    sub_bls12_377, sub_bls12_381, sub_bw6_761, utils, CircuitId, Curve, Error, Event,
+    ProofBatchItem, ProofFor, ScalarFieldFor, VerifierKeyFor, VerifyingKeys, WeightInfo,
 };
-use frame_support::assert_ok;
+use ark_ec::{pairing::Pairing, CurveGroup};
+use ark_ff::{One, UniformRand, Zero};
+use ark_groth16::Groth16;
+use ark_scale::ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_snark::SNARK;
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::DispatchResult,
+    sp_runtime::{traits::Hash, DispatchError},
+    traits::fungible::InspectHold,
+};
+
+/// Seed of the random public inputs of the differential tests.
+const SEED: u64 = 0xd1ff;
+
+/// Number of public inputs of the multiple inputs tests.
+const INPUTS: u32 = 4;
+
+/// Number of proofs of the batch verification tests.
+const BATCH_LEN: u32 = 3;
+
+fn hash(data: &[u8]) -> <Test as frame_system::Config>::Hash {
+    <Test as frame_system::Config>::Hashing::hash(data)
+}
+
+fn assert_proof_verified(curve: Curve, vk: &[u8], inputs: &[u8]) {
+    System::assert_has_event(
+        Event::<Test>::ProofVerified {
+            curve,
+            vk_hash: hash(vk),
+            inputs_hash: hash(inputs),
+        }
+        .into(),
+    );
+}
 
 // ---------------------------------------------
 // Tests for bls12-381
 // ---------------------------------------------
 
 fn bls12_381_groth16_verify(optimized: bool, pregen: bool) {
-    let (vk, public_input, proof) = if pregen {
+    let (vk, public_inputs, proof) = if pregen {
         // For parameters generation we use the built-in arkworks implementation,
         // the hostcalls are relevant only during the verification phase.
-        utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>()
+        utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(1)
     } else {
         utils::bls12_381_groth16_verify_params_get_pregen()
     };
@@ -20,11 +59,12 @@ fn bls12_381_groth16_verify(optimized: bool, pregen: bool) {
     new_test_ext().execute_with(|| {
         assert_ok!(ArkGroth16::bls12_381_groth16_verify(
             RuntimeOrigin::none(),
-            vk.0,
-            public_input.0,
+            vk.0.clone(),
+            public_inputs.0.clone(),
             proof.0,
-            optimized
+            backend(optimized)
         ));
+        assert_proof_verified(Curve::Bls12_381, &vk.0, &public_inputs.0);
     });
 }
 
@@ -48,58 +88,738 @@ fn sub_bls12_381_groth16_verify_pregen() {
     bls12_381_groth16_verify(true, true);
 }
 
+fn bls12_381_groth16_verify_multiple_inputs(optimized: bool) {
+    let (vk, public_inputs, proof) =
+        utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(INPUTS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkGroth16::bls12_381_groth16_verify(
+            RuntimeOrigin::none(),
+            vk.0,
+            public_inputs.0,
+            proof.0,
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_bls12_381_groth16_verify_multiple_inputs() {
+    bls12_381_groth16_verify_multiple_inputs(false);
+}
+
+#[test]
+fn sub_bls12_381_groth16_verify_multiple_inputs() {
+    bls12_381_groth16_verify_multiple_inputs(true);
+}
+
+#[test]
+fn groth16_verify_input_count_mismatch() {
+    let (vk, _, proof) = utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(INPUTS);
+    let (_, public_inputs, _) = utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(1);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkGroth16::bls12_381_groth16_verify(
+                RuntimeOrigin::none(),
+                vk.0,
+                public_inputs.0,
+                proof.0,
+                backend(true)
+            ),
+            Error::<Test>::InputCountMismatch
+        );
+    });
+}
+
+#[test]
+fn groth16_verify_weight_counts_key_inputs() {
+    use crate::mock::RuntimeCall;
+    use frame_support::dispatch::GetDispatchInfo;
+
+    let (vk, _, proof) = utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(INPUTS);
+    let (_, public_inputs, _) = utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(1);
+
+    let call: RuntimeCall = crate::Call::<Test>::bls12_381_groth16_verify {
+        vk: vk.0,
+        inputs: public_inputs.0,
+        proof: proof.0,
+        optimized: backend(true),
+    }
+    .into();
+    assert_eq!(
+        call.get_dispatch_info().call_weight,
+        <() as WeightInfo>::sub_bls12_381_groth16_verify(INPUTS)
+    );
+}
+
+#[test]
+fn groth16_verify_too_many_inputs() {
+    let (vk, _, proof) = utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(1);
+    let public_inputs =
+        utils::serialize_uncompressed_host(vec![
+            ScalarFieldFor::<ark_bls12_381::Bls12_381>::one();
+            MaxPublicInputs::get() as usize + 1
+        ]);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkGroth16::bls12_381_groth16_verify(
+                RuntimeOrigin::none(),
+                vk.0,
+                public_inputs,
+                proof.0,
+                backend(true)
+            ),
+            Error::<Test>::TooManyInputs
+        );
+    });
+}
+
 // ---------------------------------------------
 // Tests for bls12-377
 // ---------------------------------------------
 
-fn bls12_377_groth16_verify(optimized: bool) {
-    let (vk, public_input, proof) = utils::groth16_verify_params_gen::<ark_bls12_377::Bls12_377>();
+fn bls12_377_groth16_verify(optimized: bool, inputs: u32) {
+    let (vk, public_inputs, proof) =
+        utils::groth16_verify_params_gen::<ark_bls12_377::Bls12_377>(inputs);
 
     new_test_ext().execute_with(|| {
         assert_ok!(ArkGroth16::bls12_377_groth16_verify(
             RuntimeOrigin::none(),
             vk.0,
-            public_input.0,
+            public_inputs.0,
             proof.0,
-            optimized
+            backend(optimized)
         ));
     });
 }
 
 #[test]
 fn ark_bls12_377_groth16_verify() {
-    bls12_377_groth16_verify(false);
+    bls12_377_groth16_verify(false, 1);
+}
+
+#[test]
+fn ark_bls12_377_groth16_verify_multiple_inputs() {
+    bls12_377_groth16_verify(false, INPUTS);
 }
 
 #[test]
 fn sub_bls12_377_groth16_verify() {
-    bls12_377_groth16_verify(true);
+    bls12_377_groth16_verify(true, 1);
+}
+
+#[test]
+fn sub_bls12_377_groth16_verify_multiple_inputs() {
+    bls12_377_groth16_verify(true, INPUTS);
 }
 
 // ---------------------------------------------
 // Tests for BW6-761
 // ---------------------------------------------
 
-fn bw6_761_groth16_verify(optimized: bool) {
-    let (vk, public_input, proof) = utils::groth16_verify_params_gen::<ark_bw6_761::BW6_761>();
+fn bw6_761_groth16_verify(optimized: bool, inputs: u32) {
+    let (vk, public_inputs, proof) =
+        utils::groth16_verify_params_gen::<ark_bw6_761::BW6_761>(inputs);
 
     new_test_ext().execute_with(|| {
         assert_ok!(ArkGroth16::bw6_761_groth16_verify(
             RuntimeOrigin::none(),
             vk.0,
-            public_input.0,
+            public_inputs.0,
             proof.0,
-            optimized
+            backend(optimized)
         ));
     });
 }
 
 #[test]
 fn ark_bw6_761_groth16_verify() {
-    bw6_761_groth16_verify(false);
+    bw6_761_groth16_verify(false, 1);
+}
+
+#[test]
+fn ark_bw6_761_groth16_verify_multiple_inputs() {
+    bw6_761_groth16_verify(false, INPUTS);
 }
 
 #[test]
 fn sub_bw6_761_groth16_verify() {
-    bw6_761_groth16_verify(true);
+    bw6_761_groth16_verify(true, 1);
+}
+
+#[test]
+fn sub_bw6_761_groth16_verify_multiple_inputs() {
+    bw6_761_groth16_verify(true, INPUTS);
+}
+
+// ---------------------------------------------
+// Verifying key registry
+// ---------------------------------------------
+
+/// Identifier of the registered verifying keys.
+const CIRCUIT_ID: CircuitId = 7;
+
+fn register(curve: Curve, vk: Vec<u8>) {
+    assert_ok!(ArkGroth16::register_verifying_key(
+        RuntimeOrigin::signed(ALICE),
+        CIRCUIT_ID,
+        curve,
+        vk
+    ));
+}
+
+#[test]
+fn register_verifying_key_works() {
+    type P = ark_bls12_381::Bls12_381;
+    let (vk, _, _) = utils::groth16_verify_params_gen::<P>(INPUTS);
+    let pvk = Groth16::<P>::process_vk(
+        &utils::deserialize_uncompressed::<VerifierKeyFor<P>>(
+            &vk.0,
+            ark_scale::ark_serialize::Validate::Yes,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    new_test_ext().execute_with(|| {
+        register(Curve::Bls12_381, vk.0.clone());
+        System::assert_last_event(
+            Event::<Test>::VerifyingKeyRegistered {
+                circuit_id: CIRCUIT_ID,
+                curve: Curve::Bls12_381,
+                owner: ALICE,
+            }
+            .into(),
+        );
+
+        let key = VerifyingKeys::<Test>::get(CIRCUIT_ID).unwrap();
+        assert_eq!(key.owner, ALICE);
+        assert_eq!(key.curve, Curve::Bls12_381);
+        assert_eq!(key.vk_hash, hash(&vk.0));
+        // Prepared by the host calls backend as by Arkworks
+        assert_eq!(
+            key.key.to_vec(),
+            utils::serialize_uncompressed_host((pvk.vk, pvk.alpha_g1_beta_g2))
+        );
+        let deposit = KeyDepositBase::get() + KeyDepositPerByte::get() * key.key.len() as u64;
+        assert_eq!(key.deposit, deposit);
+        assert_eq!(Balances::total_balance_on_hold(&ALICE), deposit);
+    });
+}
+
+#[test]
+fn register_verifying_key_requires_signed_origin() {
+    let (vk, _, _) = utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(1);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkGroth16::register_verifying_key(
+                RuntimeOrigin::none(),
+                CIRCUIT_ID,
+                Curve::Bls12_381,
+                vk.0
+            ),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn register_existing_circuit_fails() {
+    let (vk, _, _) = utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(1);
+
+    new_test_ext().execute_with(|| {
+        register(Curve::Bls12_381, vk.0.clone());
+        assert_noop!(
+            ArkGroth16::register_verifying_key(
+                RuntimeOrigin::signed(BOB),
+                CIRCUIT_ID,
+                Curve::Bls12_381,
+                vk.0
+            ),
+            Error::<Test>::CircuitExists
+        );
+    });
+}
+
+#[test]
+fn register_verifying_key_without_deposit_fails() {
+    let (vk, _, _) = utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(1);
+
+    new_test_ext().execute_with(|| {
+        let unfunded = 3;
+        assert!(ArkGroth16::register_verifying_key(
+            RuntimeOrigin::signed(unfunded),
+            CIRCUIT_ID,
+            Curve::Bls12_381,
+            vk.0
+        )
+        .is_err());
+        assert!(!VerifyingKeys::<Test>::contains_key(CIRCUIT_ID));
+    });
+}
+
+#[test]
+fn deregister_verifying_key_works() {
+    let (vk, _, _) = utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(1);
+
+    new_test_ext().execute_with(|| {
+        register(Curve::Bls12_381, vk.0);
+        assert_ok!(ArkGroth16::deregister_verifying_key(
+            RuntimeOrigin::signed(ALICE),
+            CIRCUIT_ID
+        ));
+        System::assert_last_event(
+            Event::<Test>::VerifyingKeyDeregistered {
+                circuit_id: CIRCUIT_ID,
+            }
+            .into(),
+        );
+        assert!(!VerifyingKeys::<Test>::contains_key(CIRCUIT_ID));
+        assert_eq!(Balances::total_balance_on_hold(&ALICE), 0);
+        assert_eq!(Balances::free_balance(ALICE), BALANCE);
+    });
+}
+
+#[test]
+fn deregister_verifying_key_requires_owner() {
+    let (vk, _, _) = utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(1);
+
+    new_test_ext().execute_with(|| {
+        register(Curve::Bls12_381, vk.0);
+        assert_noop!(
+            ArkGroth16::deregister_verifying_key(RuntimeOrigin::signed(BOB), CIRCUIT_ID),
+            Error::<Test>::NotKeyOwner
+        );
+    });
+}
+
+#[test]
+fn deregister_unknown_circuit_fails() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkGroth16::deregister_verifying_key(RuntimeOrigin::signed(ALICE), CIRCUIT_ID),
+            Error::<Test>::UnknownCircuit
+        );
+    });
+}
+
+fn groth16_verify_with_registered<P: Pairing>(curve: Curve, optimized: bool) {
+    let (vk, public_inputs, proof) = utils::groth16_verify_params_gen::<P>(INPUTS);
+
+    new_test_ext().execute_with(|| {
+        register(curve, vk.0.clone());
+        assert_ok!(ArkGroth16::verify_with_registered(
+            RuntimeOrigin::none(),
+            CIRCUIT_ID,
+            public_inputs.0.clone(),
+            proof.0,
+            backend(optimized)
+        ));
+        assert_proof_verified(curve, &vk.0, &public_inputs.0);
+    });
+}
+
+#[test]
+fn ark_bls12_381_groth16_verify_with_registered() {
+    groth16_verify_with_registered::<ark_bls12_381::Bls12_381>(Curve::Bls12_381, false);
+}
+
+#[test]
+fn sub_bls12_381_groth16_verify_with_registered() {
+    groth16_verify_with_registered::<ark_bls12_381::Bls12_381>(Curve::Bls12_381, true);
+}
+
+#[test]
+fn ark_bls12_377_groth16_verify_with_registered() {
+    groth16_verify_with_registered::<ark_bls12_377::Bls12_377>(Curve::Bls12_377, false);
+}
+
+#[test]
+fn sub_bls12_377_groth16_verify_with_registered() {
+    groth16_verify_with_registered::<ark_bls12_377::Bls12_377>(Curve::Bls12_377, true);
+}
+
+#[test]
+fn ark_bw6_761_groth16_verify_with_registered() {
+    groth16_verify_with_registered::<ark_bw6_761::BW6_761>(Curve::Bw6_761, false);
+}
+
+#[test]
+fn sub_bw6_761_groth16_verify_with_registered() {
+    groth16_verify_with_registered::<ark_bw6_761::BW6_761>(Curve::Bw6_761, true);
+}
+
+#[test]
+fn verify_with_registered_refunds_weight() {
+    let (vk, public_inputs, proof) =
+        utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(INPUTS);
+
+    new_test_ext().execute_with(|| {
+        register(Curve::Bls12_381, vk.0);
+        let info = ArkGroth16::verify_with_registered(
+            RuntimeOrigin::none(),
+            CIRCUIT_ID,
+            public_inputs.0,
+            proof.0,
+            backend(true),
+        )
+        .unwrap();
+        assert_eq!(
+            info.actual_weight,
+            Some(<() as WeightInfo>::sub_bls12_381_groth16_verify_with_registered(INPUTS))
+        );
+    });
+}
+
+#[test]
+fn verify_with_registered_input_count_mismatch() {
+    let (vk, _, proof) = utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(INPUTS);
+    let (_, public_inputs, _) = utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(1);
+
+    new_test_ext().execute_with(|| {
+        register(Curve::Bls12_381, vk.0);
+        assert_noop!(
+            ArkGroth16::verify_with_registered(
+                RuntimeOrigin::none(),
+                CIRCUIT_ID,
+                public_inputs.0,
+                proof.0,
+                backend(true)
+            ),
+            Error::<Test>::InputCountMismatch
+        );
+    });
+}
+
+#[test]
+fn verify_with_unknown_circuit_fails() {
+    let (_, public_inputs, proof) = utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(1);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkGroth16::verify_with_registered(
+                RuntimeOrigin::none(),
+                CIRCUIT_ID,
+                public_inputs.0,
+                proof.0,
+                backend(true)
+            ),
+            Error::<Test>::UnknownCircuit
+        );
+    });
+}
+
+// ---------------------------------------------
+// Batch verification
+// ---------------------------------------------
+
+fn groth16_verify_batch_call(
+    curve: Curve,
+    vk: Vec<u8>,
+    batch: Vec<ProofBatchItem>,
+    optimized: bool,
+) -> DispatchResult {
+    let batch = batch.try_into().unwrap();
+    let optimized = backend(optimized);
+    match curve {
+        Curve::Bls12_381 => {
+            ArkGroth16::bls12_381_groth16_verify_batch(RuntimeOrigin::none(), vk, batch, optimized)
+        }
+        Curve::Bls12_377 => {
+            ArkGroth16::bls12_377_groth16_verify_batch(RuntimeOrigin::none(), vk, batch, optimized)
+        }
+        Curve::Bw6_761 => {
+            ArkGroth16::bw6_761_groth16_verify_batch(RuntimeOrigin::none(), vk, batch, optimized)
+        }
+    }
+}
+
+fn groth16_verify_batch<P: Pairing>(curve: Curve, optimized: bool) {
+    let (vk, batch) = utils::groth16_verify_batch_params_gen::<P>(BATCH_LEN, INPUTS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(groth16_verify_batch_call(
+            curve,
+            vk.0.clone(),
+            batch.clone(),
+            optimized
+        ));
+        for item in &batch {
+            assert_proof_verified(curve, &vk.0, &item.inputs);
+        }
+    });
+}
+
+#[test]
+fn ark_bls12_381_groth16_verify_batch() {
+    groth16_verify_batch::<ark_bls12_381::Bls12_381>(Curve::Bls12_381, false);
+}
+
+#[test]
+fn sub_bls12_381_groth16_verify_batch() {
+    groth16_verify_batch::<ark_bls12_381::Bls12_381>(Curve::Bls12_381, true);
+}
+
+#[test]
+fn ark_bls12_377_groth16_verify_batch() {
+    groth16_verify_batch::<ark_bls12_377::Bls12_377>(Curve::Bls12_377, false);
+}
+
+#[test]
+fn sub_bls12_377_groth16_verify_batch() {
+    groth16_verify_batch::<ark_bls12_377::Bls12_377>(Curve::Bls12_377, true);
+}
+
+#[test]
+fn ark_bw6_761_groth16_verify_batch() {
+    groth16_verify_batch::<ark_bw6_761::BW6_761>(Curve::Bw6_761, false);
+}
+
+#[test]
+fn sub_bw6_761_groth16_verify_batch() {
+    groth16_verify_batch::<ark_bw6_761::BW6_761>(Curve::Bw6_761, true);
+}
+
+#[test]
+fn groth16_verify_batch_of_one_proof() {
+    let (vk, batch) = utils::groth16_verify_batch_params_gen::<ark_bls12_381::Bls12_381>(1, 1);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(groth16_verify_batch_call(
+            Curve::Bls12_381,
+            vk.0,
+            batch,
+            true
+        ));
+    });
+}
+
+#[test]
+fn groth16_verify_batch_with_invalid_proof_fails() {
+    let (vk, mut batch) =
+        utils::groth16_verify_batch_params_gen::<ark_bls12_381::Bls12_381>(BATCH_LEN, INPUTS);
+    // Inputs of another proof of the batch
+    batch[1].inputs = batch[0].inputs.clone();
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            groth16_verify_batch_call(Curve::Bls12_381, vk.0, batch, true),
+            Error::<Test>::InvalidProof
+        );
+    });
+}
+
+#[test]
+fn groth16_verify_batch_with_malformed_proof_fails() {
+    let (vk, mut batch) =
+        utils::groth16_verify_batch_params_gen::<ark_bls12_381::Bls12_381>(BATCH_LEN, INPUTS);
+    batch[2].proof.pop();
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            groth16_verify_batch_call(Curve::Bls12_381, vk.0, batch, true),
+            Error::<Test>::MalformedProof
+        );
+    });
+}
+
+#[test]
+fn groth16_verify_empty_batch_fails() {
+    let (vk, _) = utils::groth16_verify_batch_params_gen::<ark_bls12_381::Bls12_381>(0, 1);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            groth16_verify_batch_call(Curve::Bls12_381, vk.0, Vec::new(), true),
+            Error::<Test>::EmptyBatch
+        );
+    });
+}
+
+#[test]
+fn groth16_verify_batch_input_count_mismatch() {
+    let (vk, mut batch) =
+        utils::groth16_verify_batch_params_gen::<ark_bls12_381::Bls12_381>(BATCH_LEN, INPUTS);
+    let (_, public_inputs, _) = utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(1);
+    batch[2].inputs = public_inputs.0;
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            groth16_verify_batch_call(Curve::Bls12_381, vk.0, batch, true),
+            Error::<Test>::InputCountMismatch
+        );
+    });
+}
+
+// ---------------------------------------------
+// Errors
+// ---------------------------------------------
+
+fn bls12_381_groth16_verify_call(vk: Vec<u8>, inputs: Vec<u8>, proof: Vec<u8>) -> DispatchResult {
+    ArkGroth16::bls12_381_groth16_verify(RuntimeOrigin::none(), vk, inputs, proof, backend(true))
+}
+
+#[test]
+fn groth16_verify_invalid_proof_fails() {
+    type P = ark_bls12_381::Bls12_381;
+    let (vk, _, proof) = utils::groth16_verify_params_gen::<P>(1);
+    let public_inputs =
+        utils::serialize_uncompressed_host(vec![utils::test_proof_builder::public_input::<P>(4)]);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            bls12_381_groth16_verify_call(vk.0, public_inputs, proof.0),
+            Error::<Test>::InvalidProof
+        );
+    });
+}
+
+#[test]
+fn groth16_verify_malformed_verifying_key_fails() {
+    let (mut vk, public_inputs, proof) =
+        utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(1);
+    vk.0.truncate(vk.0.len() / 2);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            bls12_381_groth16_verify_call(vk.0, public_inputs.0, proof.0),
+            Error::<Test>::MalformedVerifyingKey
+        );
+    });
+}
+
+#[test]
+fn groth16_verify_huge_gamma_abc_len_fails() {
+    let (mut vk, public_inputs, proof) =
+        utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(1);
+    // Length prefix of `gamma_abc_g1`, followed by its two points.
+    let g1_size = <ark_bls12_381::Bls12_381 as Pairing>::G1Affine::default().uncompressed_size();
+    let offset = vk.0.len() - 2 * g1_size - 8;
+    vk.0[offset..offset + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            bls12_381_groth16_verify_call(vk.0, public_inputs.0, proof.0),
+            Error::<Test>::MalformedVerifyingKey
+        );
+    });
+}
+
+#[test]
+fn groth16_verify_malformed_proof_fails() {
+    let (vk, public_inputs, mut proof) =
+        utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(1);
+    // Not on the curve
+    proof.0[0] ^= 1;
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            bls12_381_groth16_verify_call(vk.0, public_inputs.0, proof.0),
+            Error::<Test>::MalformedProof
+        );
+    });
+}
+
+#[test]
+fn groth16_verify_malformed_input_fails() {
+    let (vk, mut public_inputs, proof) =
+        utils::groth16_verify_params_gen::<ark_bls12_381::Bls12_381>(1);
+    public_inputs.0.pop();
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            bls12_381_groth16_verify_call(vk.0, public_inputs.0, proof.0),
+            Error::<Test>::MalformedInput
+        );
+    });
+}
+
+#[test]
+fn verify_with_registered_invalid_proof_fails() {
+    let (vk, batch) = utils::groth16_verify_batch_params_gen::<ark_bls12_381::Bls12_381>(2, INPUTS);
+
+    new_test_ext().execute_with(|| {
+        register(Curve::Bls12_381, vk.0);
+        assert_noop!(
+            ArkGroth16::verify_with_registered(
+                RuntimeOrigin::none(),
+                CIRCUIT_ID,
+                batch[1].inputs.clone(),
+                batch[0].proof.clone(),
+                backend(true)
+            ),
+            Error::<Test>::InvalidProof
+        );
+    });
+}
+
+// ---------------------------------------------
+// Differential tests
+// ---------------------------------------------
+
+fn serialize(value: &impl CanonicalSerialize) -> Vec<u8> {
+    let mut buf = Vec::new();
+    value.serialize_uncompressed(&mut buf).unwrap();
+    buf
+}
+
+/// Convert a value to the type of the other backend via its serialization.
+fn convert<T: CanonicalDeserialize>(value: &impl CanonicalSerialize) -> T {
+    T::deserialize_uncompressed(&serialize(value)[..]).unwrap()
+}
+
+/// Verify proofs with both backends over valid and invalid public inputs, checking that
+/// the prepared verifying key, the prepared inputs and the outcomes are bit-identical.
+fn assert_same_verification<Ark: Pairing, Sub: Pairing>() {
+    use utils::test_proof_builder::{prove, setup};
+
+    let rng = &mut StdRng::seed_from_u64(SEED);
+    let (prover, vk) = setup::<Ark>();
+    let ark_vk = Groth16::<Ark>::process_vk(&vk).unwrap();
+    let sub_vk = Groth16::<Sub>::process_vk(&convert(&vk)).unwrap();
+    assert_eq!(serialize(&ark_vk), serialize(&sub_vk));
+
+    // Witnesses and public values of `x^3 + x + 5 = y`.
+    for (x, y) in [(3u32, 35u32), (4, 73)] {
+        let proof = prove::<Ark>(&prover, x);
+        let sub_proof: ProofFor<Sub> = convert(&proof);
+        let inputs = [
+            y.into(),
+            Zero::zero(),
+            One::one(),
+            (y + 1).into(),
+            ScalarFieldFor::<Ark>::rand(rng),
+        ];
+        for input in inputs {
+            let sub_input: ScalarFieldFor<Sub> = convert(&input);
+            let ark_prepared = Groth16::<Ark>::prepare_inputs(&ark_vk, &[input]).unwrap();
+            let sub_prepared = Groth16::<Sub>::prepare_inputs(&sub_vk, &[sub_input]).unwrap();
+            assert_eq!(
+                serialize(&ark_prepared.into_affine()),
+                serialize(&sub_prepared.into_affine())
+            );
+
+            let ark_res = Groth16::<Ark>::verify_proof(&ark_vk, &proof, &[input]).unwrap();
+            let sub_res = Groth16::<Sub>::verify_proof(&sub_vk, &sub_proof, &[sub_input]).unwrap();
+            assert_eq!(ark_res, input == ScalarFieldFor::<Ark>::from(y));
+            assert_eq!(ark_res, sub_res);
+        }
+    }
+}
+
+#[test]
+fn bls12_381_groth16_same_verification() {
+    assert_same_verification::<ark_bls12_381::Bls12_381, sub_bls12_381::Bls12_381>();
+}
+
+#[test]
+fn bls12_377_groth16_same_verification() {
+    assert_same_verification::<ark_bls12_377::Bls12_377, sub_bls12_377::Bls12_377>();
+}
+
+#[test]
+fn bw6_761_groth16_same_verification() {
+    assert_same_verification::<ark_bw6_761::BW6_761, sub_bw6_761::BW6_761>();
 }
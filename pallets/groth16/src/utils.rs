@@ -2,17 +2,22 @@
 
 use ark_ec::pairing::Pairing;
 use ark_ff::Field;
-use ark_scale::ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_scale::ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Validate,
+};
 use ark_scale::scale::{Decode, Encode};
 use ark_snark::CircuitSpecificSetupSNARK;
 use ark_std::rand::rngs::StdRng;
 use ark_std::rand::{CryptoRng, RngCore, SeedableRng};
 use ark_std::vec::Vec;
 
-use crate::{ArkScaleHost, ArkScaleWire, ProofFor, ProverKeyFor, ScalarFieldFor, VerifierKeyFor};
+use crate::{
+    ArkScaleHost, ArkScaleWire, ProofBatchItem, ProofFor, ProverKeyFor, ScalarFieldFor,
+    VerifierKeyFor,
+};
 
 pub struct VerifierRaw(pub Vec<u8>);
-pub struct PublicInputRaw(pub Vec<u8>);
+pub struct PublicInputsRaw(pub Vec<u8>);
 pub struct ProofRaw(pub Vec<u8>);
 
 pub fn serialize_uncompressed_host(argument: impl CanonicalSerialize) -> Vec<u8> {
@@ -23,6 +28,13 @@ pub fn deserialize_uncompressed_host<T: CanonicalDeserialize>(data: impl AsRef<[
     ArkScaleHost::decode(&mut data.as_ref()).unwrap().0
 }
 
+pub fn deserialize_uncompressed<T: CanonicalDeserialize>(
+    data: impl AsRef<[u8]>,
+    validate: Validate,
+) -> Result<T, SerializationError> {
+    T::deserialize_with_mode(data.as_ref(), Compress::No, validate)
+}
+
 pub fn deserialize_compressed_wire<T: CanonicalDeserialize>(data: impl AsRef<[u8]>) -> T {
     ArkScaleWire::decode(&mut data.as_ref()).unwrap().0
 }
@@ -34,38 +46,75 @@ pub fn wire_to_host<T: CanonicalDeserialize + CanonicalSerialize>(
     serialize_uncompressed_host(v)
 }
 
-pub fn bls12_381_groth16_verify_params_get_pregen() -> (VerifierRaw, PublicInputRaw, ProofRaw) {
+pub fn bls12_381_groth16_verify_params_get_pregen() -> (VerifierRaw, PublicInputsRaw, ProofRaw) {
     use test_bls12_381_pregen::*;
     let vk = wire_to_host::<VerifierKeyFor<ark_bls12_381::Bls12_381>>(VERIFIER_KEY_SERIALIZED);
-    let public_input =
-        wire_to_host::<ScalarFieldFor<ark_bls12_381::Bls12_381>>(PUBLIC_INPUT_SERIALIZED);
+    let public_input = deserialize_compressed_wire::<ScalarFieldFor<ark_bls12_381::Bls12_381>>(
+        PUBLIC_INPUT_SERIALIZED,
+    );
+    let public_inputs = serialize_uncompressed_host(ark_std::vec![public_input]);
     let proof = wire_to_host::<ProofFor<ark_bls12_381::Bls12_381>>(PROOF_SERIALIZED);
     (
         VerifierRaw(vk),
-        PublicInputRaw(public_input),
+        PublicInputsRaw(public_inputs),
         ProofRaw(proof),
     )
 }
 
-pub fn groth16_verify_params_gen<P: Pairing>() -> (VerifierRaw, PublicInputRaw, ProofRaw) {
+/// Proof of the cubic circuit with `inputs` public inputs.
+pub fn groth16_verify_params_gen<P: Pairing>(
+    inputs: u32,
+) -> (VerifierRaw, PublicInputsRaw, ProofRaw) {
     use test_proof_builder::*;
 
-    let (prover, verifier) = setup::<P>();
-    let proof = prove(&prover, 3);
+    let witnesses = (0..inputs).map(|i| i + 3).collect::<Vec<_>>();
+    let (prover, verifier) = setup_with_inputs::<P>(inputs);
+    let proof = prove_with_inputs(&prover, &witnesses);
 
-    let public_input = ScalarFieldFor::<P>::from(35);
+    let public_inputs = witnesses
+        .iter()
+        .map(|x| public_input::<P>(*x))
+        .collect::<Vec<_>>();
 
-    let public_input_raw = serialize_uncompressed_host(public_input);
+    let public_inputs_raw = serialize_uncompressed_host(public_inputs);
     let verifier_raw = serialize_uncompressed_host(verifier);
     let proof_raw = serialize_uncompressed_host(proof);
 
     (
         VerifierRaw(verifier_raw),
-        PublicInputRaw(public_input_raw),
+        PublicInputsRaw(public_inputs_raw),
         ProofRaw(proof_raw),
     )
 }
 
+/// Batch of `batch_len` proofs of the cubic circuit with `inputs` public inputs.
+///
+/// The `k`-th proof is for the witnesses `k + 3..`.
+pub fn groth16_verify_batch_params_gen<P: Pairing>(
+    batch_len: u32,
+    inputs: u32,
+) -> (VerifierRaw, Vec<ProofBatchItem>) {
+    use test_proof_builder::*;
+
+    let (prover, verifier) = setup_with_inputs::<P>(inputs);
+    let batch = (0..batch_len)
+        .map(|k| {
+            let witnesses = (0..inputs).map(|i| i + k + 3).collect::<Vec<_>>();
+            let proof = prove_with_inputs(&prover, &witnesses);
+            let public_inputs = witnesses
+                .iter()
+                .map(|x| public_input::<P>(*x))
+                .collect::<Vec<_>>();
+            ProofBatchItem {
+                inputs: serialize_uncompressed_host(public_inputs),
+                proof: serialize_uncompressed_host(proof),
+            }
+        })
+        .collect();
+
+    (VerifierRaw(serialize_uncompressed_host(verifier)), batch)
+}
+
 mod test_bls12_381_pregen {
     // Pregenerated BLS12-381 proof
     pub static PROOF_SERIALIZED: &[u8] = &[
@@ -115,7 +164,7 @@ mod test_bls12_381_pregen {
     ];
 }
 
-mod test_proof_builder {
+pub(crate) mod test_proof_builder {
     use super::*;
     use ark_groth16::Groth16;
     use ark_relations::{
@@ -177,6 +226,26 @@ mod test_proof_builder {
         }
     }
 
+    // Independent instances of the cubic circuit, with a public value each.
+    pub struct MultiCubicCircuit<F: Field> {
+        pub xs: Vec<Option<F>>,
+    }
+
+    impl<F: Field> ConstraintSynthesizer<F> for MultiCubicCircuit<F> {
+        fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+            for x in self.xs {
+                CubicCircuit { x }.generate_constraints(cs.clone())?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Public value `x^3 + x + 5` of the witness `x`.
+    pub fn public_input<P: Pairing>(witness: u32) -> ScalarFieldFor<P> {
+        let x = ScalarFieldFor::<P>::from(witness);
+        x * x * x + x + ScalarFieldFor::<P>::from(5)
+    }
+
     pub fn setup<P: Pairing>() -> (ProverKeyFor<P>, VerifierKeyFor<P>) {
         let mut rng = test_rng();
         let c = CubicCircuit::<ScalarFieldFor<P>> { x: None };
@@ -191,6 +260,28 @@ mod test_proof_builder {
         Groth16::<P>::prove(prover, circuit, &mut rng).unwrap()
     }
 
+    pub fn setup_with_inputs<P: Pairing>(inputs: u32) -> (ProverKeyFor<P>, VerifierKeyFor<P>) {
+        let mut rng = test_rng();
+        let c = MultiCubicCircuit::<ScalarFieldFor<P>> {
+            xs: ark_std::vec![None; inputs as usize],
+        };
+        Groth16::<P>::setup(c, &mut rng).unwrap()
+    }
+
+    pub fn prove_with_inputs<P: Pairing>(
+        prover: &ProverKeyFor<P>,
+        witnesses: &[u32],
+    ) -> ProofFor<P> {
+        let mut rng = test_rng();
+        let circuit = MultiCubicCircuit::<ScalarFieldFor<P>> {
+            xs: witnesses
+                .iter()
+                .map(|x| Some(ScalarFieldFor::<P>::from(*x)))
+                .collect(),
+        };
+        Groth16::<P>::prove(prover, circuit, &mut rng).unwrap()
+    }
+
     pub fn verify<P: Pairing>(
         verifier: &VerifierKeyFor<P>,
         public_input: u32,
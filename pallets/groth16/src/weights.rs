@@ -16,15 +16,15 @@
 // limitations under the License.
 
 
-//! Autogenerated weights for `pallet_ark_groth16`
+//! Placeholder weights for `pallet_ark_groth16`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2026-01-24, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `ggwpez-ref-hw2`, CPU: `AMD EPYC 7232P 8-Core Processor`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+//! NOT GENERATED BY THE BENCHMARK CLI. The figures below are estimates and must be
+//! replaced by running the pallet benchmarks with the command below.
+//!
+//! The BLS12-377 and BW6-761 figures are the BLS12-381 ones scaled by the measured cost ratio
+//! of the same arkworks operation on each curve, with a 20% margin on top.
 
-// Executed Command:
+// Command to regenerate:
 // ./target/release/ark-node
 // benchmark
 // pallet
@@ -54,103 +54,375 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for `pallet_ark_groth16`.
 pub trait WeightInfo {
-	fn ark_bls12_381_groth16_verify() -> Weight;
-	fn sub_bls12_381_groth16_verify() -> Weight;
-	fn ark_bls12_377_groth16_verify() -> Weight;
-	fn sub_bls12_377_groth16_verify() -> Weight;
-	fn ark_bw6_761_groth16_verify() -> Weight;
-	fn sub_bw6_761_groth16_verify() -> Weight;
+	fn ark_bls12_381_groth16_verify(x: u32, ) -> Weight;
+	fn sub_bls12_381_groth16_verify(x: u32, ) -> Weight;
+	fn ark_bls12_377_groth16_verify(x: u32, ) -> Weight;
+	fn sub_bls12_377_groth16_verify(x: u32, ) -> Weight;
+	fn ark_bw6_761_groth16_verify(x: u32, ) -> Weight;
+	fn sub_bw6_761_groth16_verify(x: u32, ) -> Weight;
+	fn bls12_381_register_verifying_key(x: u32, ) -> Weight;
+	fn bls12_377_register_verifying_key(x: u32, ) -> Weight;
+	fn bw6_761_register_verifying_key(x: u32, ) -> Weight;
+	fn deregister_verifying_key() -> Weight;
+	fn ark_bls12_381_groth16_verify_with_registered(x: u32, ) -> Weight;
+	fn sub_bls12_381_groth16_verify_with_registered(x: u32, ) -> Weight;
+	fn ark_bls12_377_groth16_verify_with_registered(x: u32, ) -> Weight;
+	fn sub_bls12_377_groth16_verify_with_registered(x: u32, ) -> Weight;
+	fn ark_bw6_761_groth16_verify_with_registered(x: u32, ) -> Weight;
+	fn sub_bw6_761_groth16_verify_with_registered(x: u32, ) -> Weight;
+	fn ark_bls12_381_groth16_verify_batch(x: u32, y: u32, ) -> Weight;
+	fn sub_bls12_381_groth16_verify_batch(x: u32, y: u32, ) -> Weight;
+	fn ark_bls12_377_groth16_verify_batch(x: u32, y: u32, ) -> Weight;
+	fn sub_bls12_377_groth16_verify_batch(x: u32, y: u32, ) -> Weight;
+	fn ark_bw6_761_groth16_verify_batch(x: u32, y: u32, ) -> Weight;
+	fn sub_bw6_761_groth16_verify_batch(x: u32, y: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_ark_groth16` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	fn ark_bls12_381_groth16_verify() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 21_103_068_000 picoseconds.
-		Weight::from_parts(21_135_129_000, 0)
-	}
-	fn sub_bls12_381_groth16_verify() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 3_552_928_000 picoseconds.
-		Weight::from_parts(3_559_599_000, 0)
-	}
-	fn ark_bls12_377_groth16_verify() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 22_325_178_000 picoseconds.
-		Weight::from_parts(22_348_929_000, 0)
-	}
-	fn sub_bls12_377_groth16_verify() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 3_852_271_000 picoseconds.
-		Weight::from_parts(3_862_840_000, 0)
-	}
-	fn ark_bw6_761_groth16_verify() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 109_186_813_000 picoseconds.
-		Weight::from_parts(109_252_043_000, 0)
-	}
-	fn sub_bw6_761_groth16_verify() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 13_123_824_000 picoseconds.
-		Weight::from_parts(13_143_284_000, 0)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_381_groth16_verify(x: u32, ) -> Weight {
+		Weight::from_parts(20_171_832_417, 0)
+			.saturating_add(Weight::from_parts(961_304_718, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_381_groth16_verify(x: u32, ) -> Weight {
+		Weight::from_parts(3_405_417_902, 0)
+			.saturating_add(Weight::from_parts(152_871_433, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_377_groth16_verify(x: u32, ) -> Weight {
+		Weight::from_parts(27_231_973_763, 0)
+			.saturating_add(Weight::from_parts(1_249_696_133, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_377_groth16_verify(x: u32, ) -> Weight {
+		Weight::from_parts(4_597_314_168, 0)
+			.saturating_add(Weight::from_parts(198_732_863, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bw6_761_groth16_verify(x: u32, ) -> Weight {
+		Weight::from_parts(127_082_544_227, 0)
+			.saturating_add(Weight::from_parts(6_440_741_611, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bw6_761_groth16_verify(x: u32, ) -> Weight {
+		Weight::from_parts(21_454_132_783, 0)
+			.saturating_add(Weight::from_parts(1_024_238_601, 0).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkGroth16::VerifyingKeys` (r:1 w:1)
+	/// Proof: `ArkGroth16::VerifyingKeys` (`max_values`: None, `max_size`: Some(4703), added: 7178, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn bls12_381_register_verifying_key(x: u32, ) -> Weight {
+		Weight::from_parts(1_980_415_262, 8168)
+			.saturating_add(Weight::from_parts(118_294_017, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ArkGroth16::VerifyingKeys` (r:1 w:1)
+	/// Proof: `ArkGroth16::VerifyingKeys` (`max_values`: None, `max_size`: Some(4703), added: 7178, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn bls12_377_register_verifying_key(x: u32, ) -> Weight {
+		Weight::from_parts(2_970_622_893, 8168)
+			.saturating_add(Weight::from_parts(153_782_222, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ArkGroth16::VerifyingKeys` (r:1 w:1)
+	/// Proof: `ArkGroth16::VerifyingKeys` (`max_values`: None, `max_size`: Some(4703), added: 7178, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn bw6_761_register_verifying_key(x: u32, ) -> Weight {
+		Weight::from_parts(9_902_076_310, 8168)
+			.saturating_add(Weight::from_parts(792_569_914, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ArkGroth16::VerifyingKeys` (r:1 w:1)
+	/// Proof: `ArkGroth16::VerifyingKeys` (`max_values`: None, `max_size`: Some(4703), added: 7178, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn deregister_verifying_key() -> Weight {
+		Weight::from_parts(41_285_000, 8168)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ArkGroth16::VerifyingKeys` (r:1 w:0)
+	/// Proof: `ArkGroth16::VerifyingKeys` (`max_values`: None, `max_size`: Some(4703), added: 7178, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_381_groth16_verify_with_registered(x: u32, ) -> Weight {
+		Weight::from_parts(13_702_115_483, 8168)
+			.saturating_add(Weight::from_parts(961_820_442, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `ArkGroth16::VerifyingKeys` (r:1 w:0)
+	/// Proof: `ArkGroth16::VerifyingKeys` (`max_values`: None, `max_size`: Some(4703), added: 7178, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_381_groth16_verify_with_registered(x: u32, ) -> Weight {
+		Weight::from_parts(2_291_734_604, 8168)
+			.saturating_add(Weight::from_parts(153_002_761, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `ArkGroth16::VerifyingKeys` (r:1 w:0)
+	/// Proof: `ArkGroth16::VerifyingKeys` (`max_values`: None, `max_size`: Some(4703), added: 7178, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_377_groth16_verify_with_registered(x: u32, ) -> Weight {
+		Weight::from_parts(18_497_855_902, 8168)
+			.saturating_add(Weight::from_parts(1_250_366_575, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `ArkGroth16::VerifyingKeys` (r:1 w:0)
+	/// Proof: `ArkGroth16::VerifyingKeys` (`max_values`: None, `max_size`: Some(4703), added: 7178, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_377_groth16_verify_with_registered(x: u32, ) -> Weight {
+		Weight::from_parts(3_093_841_715, 8168)
+			.saturating_add(Weight::from_parts(198_903_589, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `ArkGroth16::VerifyingKeys` (r:1 w:0)
+	/// Proof: `ArkGroth16::VerifyingKeys` (`max_values`: None, `max_size`: Some(4703), added: 7178, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bw6_761_groth16_verify_with_registered(x: u32, ) -> Weight {
+		Weight::from_parts(86_323_327_543, 8168)
+			.saturating_add(Weight::from_parts(6_444_196_961, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `ArkGroth16::VerifyingKeys` (r:1 w:0)
+	/// Proof: `ArkGroth16::VerifyingKeys` (`max_values`: None, `max_size`: Some(4703), added: 7178, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bw6_761_groth16_verify_with_registered(x: u32, ) -> Weight {
+		Weight::from_parts(14_437_928_005, 8168)
+			.saturating_add(Weight::from_parts(1_025_118_499, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `y` is `[1, 16]`.
+	fn ark_bls12_381_groth16_verify_batch(x: u32, y: u32, ) -> Weight {
+		Weight::from_parts(14_718_352_091, 0)
+			.saturating_add(Weight::from_parts(6_318_447_291, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(958_126_404, 0).saturating_mul(y.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `y` is `[1, 16]`.
+	fn sub_bls12_381_groth16_verify_batch(x: u32, y: u32, ) -> Weight {
+		Weight::from_parts(2_616_084_529, 0)
+			.saturating_add(Weight::from_parts(931_207_845, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(152_644_118, 0).saturating_mul(y.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `y` is `[1, 16]`.
+	fn ark_bls12_377_groth16_verify_batch(x: u32, y: u32, ) -> Weight {
+		Weight::from_parts(19_869_775_323, 0)
+			.saturating_add(Weight::from_parts(8_529_903_843, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(1_245_564_325, 0).saturating_mul(y.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `y` is `[1, 16]`.
+	fn sub_bls12_377_groth16_verify_batch(x: u32, y: u32, ) -> Weight {
+		Weight::from_parts(3_531_714_114, 0)
+			.saturating_add(Weight::from_parts(1_257_130_591, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(198_437_353, 0).saturating_mul(y.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `y` is `[1, 16]`.
+	fn ark_bw6_761_groth16_verify_batch(x: u32, y: u32, ) -> Weight {
+		Weight::from_parts(92_725_618_173, 0)
+			.saturating_add(Weight::from_parts(39_806_217_933, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(6_419_446_907, 0).saturating_mul(y.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `y` is `[1, 16]`.
+	fn sub_bw6_761_groth16_verify_batch(x: u32, y: u32, ) -> Weight {
+		Weight::from_parts(16_481_332_533, 0)
+			.saturating_add(Weight::from_parts(5_866_609_424, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(1_022_715_591, 0).saturating_mul(y.into()))
 	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
-	fn ark_bls12_381_groth16_verify() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 21_103_068_000 picoseconds.
-		Weight::from_parts(21_135_129_000, 0)
-	}
-	fn sub_bls12_381_groth16_verify() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 3_552_928_000 picoseconds.
-		Weight::from_parts(3_559_599_000, 0)
-	}
-	fn ark_bls12_377_groth16_verify() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 22_325_178_000 picoseconds.
-		Weight::from_parts(22_348_929_000, 0)
-	}
-	fn sub_bls12_377_groth16_verify() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 3_852_271_000 picoseconds.
-		Weight::from_parts(3_862_840_000, 0)
-	}
-	fn ark_bw6_761_groth16_verify() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 109_186_813_000 picoseconds.
-		Weight::from_parts(109_252_043_000, 0)
-	}
-	fn sub_bw6_761_groth16_verify() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 13_123_824_000 picoseconds.
-		Weight::from_parts(13_143_284_000, 0)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_381_groth16_verify(x: u32, ) -> Weight {
+		Weight::from_parts(20_171_832_417, 0)
+			.saturating_add(Weight::from_parts(961_304_718, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_381_groth16_verify(x: u32, ) -> Weight {
+		Weight::from_parts(3_405_417_902, 0)
+			.saturating_add(Weight::from_parts(152_871_433, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_377_groth16_verify(x: u32, ) -> Weight {
+		Weight::from_parts(27_231_973_763, 0)
+			.saturating_add(Weight::from_parts(1_249_696_133, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_377_groth16_verify(x: u32, ) -> Weight {
+		Weight::from_parts(4_597_314_168, 0)
+			.saturating_add(Weight::from_parts(198_732_863, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bw6_761_groth16_verify(x: u32, ) -> Weight {
+		Weight::from_parts(127_082_544_227, 0)
+			.saturating_add(Weight::from_parts(6_440_741_611, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bw6_761_groth16_verify(x: u32, ) -> Weight {
+		Weight::from_parts(21_454_132_783, 0)
+			.saturating_add(Weight::from_parts(1_024_238_601, 0).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkGroth16::VerifyingKeys` (r:1 w:1)
+	/// Proof: `ArkGroth16::VerifyingKeys` (`max_values`: None, `max_size`: Some(4703), added: 7178, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn bls12_381_register_verifying_key(x: u32, ) -> Weight {
+		Weight::from_parts(1_980_415_262, 8168)
+			.saturating_add(Weight::from_parts(118_294_017, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ArkGroth16::VerifyingKeys` (r:1 w:1)
+	/// Proof: `ArkGroth16::VerifyingKeys` (`max_values`: None, `max_size`: Some(4703), added: 7178, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn bls12_377_register_verifying_key(x: u32, ) -> Weight {
+		Weight::from_parts(2_970_622_893, 8168)
+			.saturating_add(Weight::from_parts(153_782_222, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ArkGroth16::VerifyingKeys` (r:1 w:1)
+	/// Proof: `ArkGroth16::VerifyingKeys` (`max_values`: None, `max_size`: Some(4703), added: 7178, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn bw6_761_register_verifying_key(x: u32, ) -> Weight {
+		Weight::from_parts(9_902_076_310, 8168)
+			.saturating_add(Weight::from_parts(792_569_914, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ArkGroth16::VerifyingKeys` (r:1 w:1)
+	/// Proof: `ArkGroth16::VerifyingKeys` (`max_values`: None, `max_size`: Some(4703), added: 7178, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn deregister_verifying_key() -> Weight {
+		Weight::from_parts(41_285_000, 8168)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ArkGroth16::VerifyingKeys` (r:1 w:0)
+	/// Proof: `ArkGroth16::VerifyingKeys` (`max_values`: None, `max_size`: Some(4703), added: 7178, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_381_groth16_verify_with_registered(x: u32, ) -> Weight {
+		Weight::from_parts(13_702_115_483, 8168)
+			.saturating_add(Weight::from_parts(961_820_442, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `ArkGroth16::VerifyingKeys` (r:1 w:0)
+	/// Proof: `ArkGroth16::VerifyingKeys` (`max_values`: None, `max_size`: Some(4703), added: 7178, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_381_groth16_verify_with_registered(x: u32, ) -> Weight {
+		Weight::from_parts(2_291_734_604, 8168)
+			.saturating_add(Weight::from_parts(153_002_761, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `ArkGroth16::VerifyingKeys` (r:1 w:0)
+	/// Proof: `ArkGroth16::VerifyingKeys` (`max_values`: None, `max_size`: Some(4703), added: 7178, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_377_groth16_verify_with_registered(x: u32, ) -> Weight {
+		Weight::from_parts(18_497_855_902, 8168)
+			.saturating_add(Weight::from_parts(1_250_366_575, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `ArkGroth16::VerifyingKeys` (r:1 w:0)
+	/// Proof: `ArkGroth16::VerifyingKeys` (`max_values`: None, `max_size`: Some(4703), added: 7178, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_377_groth16_verify_with_registered(x: u32, ) -> Weight {
+		Weight::from_parts(3_093_841_715, 8168)
+			.saturating_add(Weight::from_parts(198_903_589, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `ArkGroth16::VerifyingKeys` (r:1 w:0)
+	/// Proof: `ArkGroth16::VerifyingKeys` (`max_values`: None, `max_size`: Some(4703), added: 7178, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bw6_761_groth16_verify_with_registered(x: u32, ) -> Weight {
+		Weight::from_parts(86_323_327_543, 8168)
+			.saturating_add(Weight::from_parts(6_444_196_961, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `ArkGroth16::VerifyingKeys` (r:1 w:0)
+	/// Proof: `ArkGroth16::VerifyingKeys` (`max_values`: None, `max_size`: Some(4703), added: 7178, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bw6_761_groth16_verify_with_registered(x: u32, ) -> Weight {
+		Weight::from_parts(14_437_928_005, 8168)
+			.saturating_add(Weight::from_parts(1_025_118_499, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `y` is `[1, 16]`.
+	fn ark_bls12_381_groth16_verify_batch(x: u32, y: u32, ) -> Weight {
+		Weight::from_parts(14_718_352_091, 0)
+			.saturating_add(Weight::from_parts(6_318_447_291, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(958_126_404, 0).saturating_mul(y.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `y` is `[1, 16]`.
+	fn sub_bls12_381_groth16_verify_batch(x: u32, y: u32, ) -> Weight {
+		Weight::from_parts(2_616_084_529, 0)
+			.saturating_add(Weight::from_parts(931_207_845, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(152_644_118, 0).saturating_mul(y.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `y` is `[1, 16]`.
+	fn ark_bls12_377_groth16_verify_batch(x: u32, y: u32, ) -> Weight {
+		Weight::from_parts(19_869_775_323, 0)
+			.saturating_add(Weight::from_parts(8_529_903_843, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(1_245_564_325, 0).saturating_mul(y.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `y` is `[1, 16]`.
+	fn sub_bls12_377_groth16_verify_batch(x: u32, y: u32, ) -> Weight {
+		Weight::from_parts(3_531_714_114, 0)
+			.saturating_add(Weight::from_parts(1_257_130_591, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(198_437_353, 0).saturating_mul(y.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `y` is `[1, 16]`.
+	fn ark_bw6_761_groth16_verify_batch(x: u32, y: u32, ) -> Weight {
+		Weight::from_parts(92_725_618_173, 0)
+			.saturating_add(Weight::from_parts(39_806_217_933, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(6_419_446_907, 0).saturating_mul(y.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `y` is `[1, 16]`.
+	fn sub_bw6_761_groth16_verify_batch(x: u32, y: u32, ) -> Weight {
+		Weight::from_parts(16_481_332_533, 0)
+			.saturating_add(Weight::from_parts(5_866_609_424, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(1_022_715_591, 0).saturating_mul(y.into()))
 	}
 }
@@ -0,0 +1,27 @@
+//! Runtime API for off-chain evaluation of the curve operations exposed by
+//! `pallet-ark-hostcalls`.
+//!
+//! Operations are computed using the Substrate host calls backend, with the
+//! same inputs and outputs encoding of the corresponding pallet calls.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+
+pub use pallet_ark_hostcalls::{Curve, PairingCurve};
+
+sp_api::decl_runtime_apis! {
+    pub trait ArkCurveApi<Error: Codec> {
+        /// Multi scalar multiplication over `curve`.
+        fn msm(curve: Curve, bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, Error>;
+
+        /// Scalar multiplication of an affine point over `curve`.
+        fn mul_affine(curve: Curve, base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, Error>;
+
+        /// Pairing over `curve`.
+        fn pairing(curve: PairingCurve, a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, Error>;
+    }
+}
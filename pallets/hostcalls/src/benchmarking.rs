@@ -4,6 +4,7 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as ArkHostcalls;
+use ark_std::vec;
 use frame_benchmarking::v2::*;
 use frame_system::RawOrigin;
 
@@ -11,6 +12,18 @@ use frame_system::RawOrigin;
 const MSM_LEN_MIN: u32 = 10;
 // Max number of elements for multi scalar multiplication
 const MSM_LEN_MAX: u32 = 100;
+// Max number of 64-bit words of scalar multiplication scalars
+const SCALAR_WORDS_MAX: u32 = 16;
+// Max number of pages of a base set
+const BASE_SET_PAGES_MAX: u32 = 16;
+// Min number of pairs for multi pairing
+const PAIRING_LEN_MIN: u32 = 1;
+// Max number of pairs for multi pairing
+const PAIRING_LEN_MAX: u32 = 10;
+// Max message length for hash to curve, the runtime `MaxHashMsgLen` must not be lower
+const HASH_MSG_LEN_MAX: u32 = 1024;
+// Domain separation tag used by hash to curve benchmarks
+const HASH_DST: &[u8] = b"BENCH-V01-CS02-with-BLS12381_XMD:SHA-256_SSWU_RO_";
 
 #[benchmarks]
 mod benchmarks {
@@ -53,35 +66,35 @@ mod benchmarks {
     }
 
     #[benchmark]
-    fn ark_bls12_381_mul_projective_g1() {
-        let (base, scalar) = utils::make_mul_projective_args::<ark_bls12_381::G1Projective>();
+    fn ark_bls12_381_mul_projective_g1(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args_projective::<ark_bls12_381::G1Projective>(x);
 
         #[extrinsic_call]
         bls12_381_mul_projective_g1(RawOrigin::None, base.encode(), scalar.encode(), false);
     }
 
     #[benchmark]
-    fn sub_bls12_381_mul_projective_g1() {
-        let (base, scalar) = utils::make_mul_projective_args::<ark_bls12_381::G1Projective>();
+    fn sub_bls12_381_mul_projective_g1(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args_projective::<ark_bls12_381::G1Projective>(x);
 
         #[extrinsic_call]
         bls12_381_mul_projective_g1(RawOrigin::None, base.encode(), scalar.encode(), true);
     }
 
     #[benchmark]
-    fn ark_bls12_381_mul_affine_g1() {
-        let (base, scalar) = utils::make_mul_affine_args::<ark_bls12_381::G1Affine>();
+    fn ark_bls12_381_mul_affine_g1(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args::<ark_bls12_381::G1Affine>(x);
 
         #[extrinsic_call]
         bls12_381_mul_affine_g1(RawOrigin::None, base.encode(), scalar.encode(), false);
     }
 
     #[benchmark]
-    fn sub_bls12_381_mul_affine_g1() {
-        let (base, scalar) = utils::make_mul_affine_args::<ark_bls12_381::G1Affine>();
+    fn sub_bls12_381_mul_affine_g1(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args::<ark_bls12_381::G1Affine>(x);
 
         #[extrinsic_call]
-        bls12_381_mul_affine_g1(RawOrigin::None, base.encode(), scalar.encode(), false);
+        bls12_381_mul_affine_g1(RawOrigin::None, base.encode(), scalar.encode(), true);
     }
 
     #[benchmark]
@@ -101,296 +114,1806 @@ mod benchmarks {
     }
 
     #[benchmark]
-    fn ark_bls12_381_mul_projective_g2() {
-        let (base, scalar) = utils::make_mul_projective_args::<ark_bls12_381::G2Projective>();
+    fn ark_bls12_381_mul_projective_g2(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args_projective::<ark_bls12_381::G2Projective>(x);
 
         #[extrinsic_call]
         bls12_381_mul_projective_g2(RawOrigin::None, base.encode(), scalar.encode(), false);
     }
 
     #[benchmark]
-    fn sub_bls12_381_mul_projective_g2() {
-        let (base, scalar) = utils::make_mul_projective_args::<ark_bls12_381::G2Projective>();
+    fn sub_bls12_381_mul_projective_g2(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args_projective::<ark_bls12_381::G2Projective>(x);
 
         #[extrinsic_call]
         bls12_381_mul_projective_g2(RawOrigin::None, base.encode(), scalar.encode(), true);
     }
 
     #[benchmark]
-    fn ark_bls12_381_mul_affine_g2() {
-        let (base, scalar) = utils::make_mul_affine_args::<ark_bls12_381::G2Affine>();
+    fn ark_bls12_381_mul_affine_g2(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args::<ark_bls12_381::G2Affine>(x);
 
         #[extrinsic_call]
         bls12_381_mul_affine_g2(RawOrigin::None, base.encode(), scalar.encode(), false);
     }
 
     #[benchmark]
-    fn sub_bls12_381_mul_affine_g2() {
-        let (base, scalar) = utils::make_mul_affine_args::<ark_bls12_381::G2Affine>();
+    fn sub_bls12_381_mul_affine_g2(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args::<ark_bls12_381::G2Affine>(x);
 
         #[extrinsic_call]
-        bls12_381_mul_affine_g2(RawOrigin::None, base.encode(), scalar.encode(), false);
+        bls12_381_mul_affine_g2(RawOrigin::None, base.encode(), scalar.encode(), true);
     }
 
-    // ---------------------------------------------
-    // Calls for ed-on-bls12-377
-    // ---------------------------------------------
+    #[benchmark]
+    fn ark_bls12_381_multi_pairing(x: Linear<PAIRING_LEN_MIN, PAIRING_LEN_MAX>) {
+        let (a, b) =
+            utils::make_multi_pairing_args::<ark_bls12_381::G1Affine, ark_bls12_381::G2Affine>(x);
 
-    // Twisted Edwards
+        #[extrinsic_call]
+        bls12_381_multi_pairing(RawOrigin::None, a.encode(), b.encode(), false, false);
+    }
 
     #[benchmark]
-    fn ark_ed_on_bls12_377_msm_te(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
-        let (bases, scalars) = utils::make_msm_args::<ark_ed_on_bls12_377::EdwardsProjective>(x);
+    fn sub_bls12_381_multi_pairing(x: Linear<PAIRING_LEN_MIN, PAIRING_LEN_MAX>) {
+        let (a, b) =
+            utils::make_multi_pairing_args::<ark_bls12_381::G1Affine, ark_bls12_381::G2Affine>(x);
 
         #[extrinsic_call]
-        ed_on_bls12_377_msm_te(
-            RawOrigin::Signed(whitelisted_caller()),
-            bases.encode(),
-            scalars.encode(),
-            false,
-        );
+        bls12_381_multi_pairing(RawOrigin::None, a.encode(), b.encode(), false, true);
     }
 
     #[benchmark]
-    fn sub_ed_on_bls12_377_msm_te(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
-        let (bases, scalars) = utils::make_msm_args::<ark_ed_on_bls12_377::EdwardsProjective>(x);
+    fn ark_bls12_381_multi_miller_loop(x: Linear<PAIRING_LEN_MIN, PAIRING_LEN_MAX>) {
+        let (a, b) =
+            utils::make_multi_pairing_args::<ark_bls12_381::G1Affine, ark_bls12_381::G2Affine>(x);
 
         #[extrinsic_call]
-        ed_on_bls12_377_msm_te(
-            RawOrigin::Signed(whitelisted_caller()),
-            bases.encode(),
-            scalars.encode(),
-            true,
-        );
+        bls12_381_multi_miller_loop(RawOrigin::None, a.encode(), b.encode(), false);
     }
 
     #[benchmark]
-    fn ark_ed_on_bls12_377_mul_projective_te() {
-        let (base, scalar) =
-            utils::make_mul_projective_args::<ark_ed_on_bls12_377::EdwardsProjective>();
+    fn sub_bls12_381_multi_miller_loop(x: Linear<PAIRING_LEN_MIN, PAIRING_LEN_MAX>) {
+        let (a, b) =
+            utils::make_multi_pairing_args::<ark_bls12_381::G1Affine, ark_bls12_381::G2Affine>(x);
 
         #[extrinsic_call]
-        ed_on_bls12_377_mul_projective_te(
-            RawOrigin::Signed(whitelisted_caller()),
-            base.encode(),
-            scalar.encode(),
-            false,
-        );
+        bls12_381_multi_miller_loop(RawOrigin::None, a.encode(), b.encode(), true);
     }
 
     #[benchmark]
-    fn sub_ed_on_bls12_377_mul_projective_te() {
-        let (base, scalar) =
-            utils::make_mul_projective_args::<ark_ed_on_bls12_377::EdwardsProjective>();
+    fn ark_bls12_381_final_exponentiation() {
+        let target = utils::make_final_exponentiation_args::<ark_bls12_381::Bls12_381>();
 
         #[extrinsic_call]
-        ed_on_bls12_377_mul_projective_te(
-            RawOrigin::Signed(whitelisted_caller()),
-            base.encode(),
-            scalar.encode(),
-            true,
-        );
+        bls12_381_final_exponentiation(RawOrigin::None, target.encode(), false);
     }
 
     #[benchmark]
-    fn ark_ed_on_bls12_377_mul_affine_te() {
-        let (base, scalar) = utils::make_mul_affine_args::<ark_ed_on_bls12_377::EdwardsAffine>();
+    fn sub_bls12_381_final_exponentiation() {
+        let target = utils::make_final_exponentiation_args::<ark_bls12_381::Bls12_381>();
 
         #[extrinsic_call]
-        ed_on_bls12_377_mul_affine_te(
-            RawOrigin::Signed(whitelisted_caller()),
-            base.encode(),
-            scalar.encode(),
-            false,
-        );
+        bls12_381_final_exponentiation(RawOrigin::None, target.encode(), true);
     }
 
     #[benchmark]
-    fn sub_ed_on_bls12_377_mul_affine_te() {
-        let (base, scalar) = utils::make_mul_affine_args::<ark_ed_on_bls12_377::EdwardsAffine>();
+    fn ark_bls12_381_hash_to_g1(x: Linear<0, HASH_MSG_LEN_MAX>) {
+        let dst = HASH_DST.to_vec().try_into().unwrap();
+        let msg = vec![0xab; x as usize].try_into().unwrap();
 
         #[extrinsic_call]
-        ed_on_bls12_377_mul_affine_te(
-            RawOrigin::Signed(whitelisted_caller()),
-            base.encode(),
-            scalar.encode(),
-            true,
-        );
+        bls12_381_hash_to_g1(RawOrigin::None, dst, msg, false);
+    }
+
+    #[benchmark]
+    fn sub_bls12_381_hash_to_g1(x: Linear<0, HASH_MSG_LEN_MAX>) {
+        let dst = HASH_DST.to_vec().try_into().unwrap();
+        let msg = vec![0xab; x as usize].try_into().unwrap();
+
+        #[extrinsic_call]
+        bls12_381_hash_to_g1(RawOrigin::None, dst, msg, true);
+    }
+
+    #[benchmark]
+    fn ark_bls12_381_hash_to_g2(x: Linear<0, HASH_MSG_LEN_MAX>) {
+        let dst = HASH_DST.to_vec().try_into().unwrap();
+        let msg = vec![0xab; x as usize].try_into().unwrap();
+
+        #[extrinsic_call]
+        bls12_381_hash_to_g2(RawOrigin::None, dst, msg, false);
+    }
+
+    #[benchmark]
+    fn sub_bls12_381_hash_to_g2(x: Linear<0, HASH_MSG_LEN_MAX>) {
+        let dst = HASH_DST.to_vec().try_into().unwrap();
+        let msg = vec![0xab; x as usize].try_into().unwrap();
+
+        #[extrinsic_call]
+        bls12_381_hash_to_g2(RawOrigin::None, dst, msg, true);
     }
 
     // ---------------------------------------------
-    // Calls for ed-on-bls12-381-bandersnatch
+    // Calls for bls12-377
     // ---------------------------------------------
 
-    // Short Weierstrass
+    #[benchmark]
+    fn ark_bls12_377_pairing() {
+        let (a, b) = utils::make_pairing_args::<ark_bls12_377::G1Affine, ark_bls12_377::G2Affine>();
+
+        #[extrinsic_call]
+        bls12_377_pairing(RawOrigin::None, a.encode(), b.encode(), false);
+    }
 
     #[benchmark]
-    fn ark_ed_on_bls12_381_bandersnatch_msm_sw(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
-        let (bases, scalars) =
-            utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::SWProjective>(x);
+    fn sub_bls12_377_pairing() {
+        let (a, b) = utils::make_pairing_args::<sub_bls12_377::G1Affine, sub_bls12_377::G2Affine>();
 
         #[extrinsic_call]
-        ed_on_bls12_381_bandersnatch_msm_sw(
-            RawOrigin::Signed(whitelisted_caller()),
-            bases.encode(),
-            scalars.encode(),
-            false,
-        );
+        bls12_377_pairing(RawOrigin::None, a.encode(), b.encode(), true);
     }
 
     #[benchmark]
-    fn sub_ed_on_bls12_381_bandersnatch_msm_sw(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
-        let (bases, scalars) =
-            utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::SWProjective>(x);
+    fn ark_bls12_377_msm_g1(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_bls12_377::G1Projective>(x);
+
         #[extrinsic_call]
-        ed_on_bls12_381_bandersnatch_msm_sw(
-            RawOrigin::Signed(whitelisted_caller()),
-            bases.encode(),
-            scalars.encode(),
-            true,
-        );
+        bls12_377_msm_g1(RawOrigin::None, bases.encode(), scalars.encode(), false);
     }
 
     #[benchmark]
-    fn ark_ed_on_bls12_381_bandersnatch_mul_projective_sw() {
-        let (base, scalar) =
-            utils::make_mul_projective_args::<ark_ed_on_bls12_381_bandersnatch::SWProjective>();
+    fn sub_bls12_377_msm_g1(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_bls12_377::G1Projective>(x);
 
         #[extrinsic_call]
-        ed_on_bls12_381_bandersnatch_mul_projective_sw(
-            RawOrigin::Signed(whitelisted_caller()),
-            base.encode(),
-            scalar.encode(),
-            false,
-        );
+        bls12_377_msm_g1(RawOrigin::None, bases.encode(), scalars.encode(), true);
     }
 
     #[benchmark]
-    fn sub_ed_on_bls12_381_bandersnatch_mul_projective_sw() {
-        let (base, scalar) =
-            utils::make_mul_projective_args::<ark_ed_on_bls12_381_bandersnatch::SWProjective>();
+    fn ark_bls12_377_mul_projective_g1(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args_projective::<ark_bls12_377::G1Projective>(x);
 
         #[extrinsic_call]
-        ed_on_bls12_381_bandersnatch_mul_projective_sw(
-            RawOrigin::Signed(whitelisted_caller()),
-            base.encode(),
-            scalar.encode(),
-            true,
-        );
+        bls12_377_mul_projective_g1(RawOrigin::None, base.encode(), scalar.encode(), false);
     }
 
     #[benchmark]
-    fn ark_ed_on_bls12_381_bandersnatch_mul_affine_sw() {
-        let (base, scalar) =
-            utils::make_mul_affine_args::<ark_ed_on_bls12_381_bandersnatch::SWAffine>();
+    fn sub_bls12_377_mul_projective_g1(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args_projective::<ark_bls12_377::G1Projective>(x);
 
         #[extrinsic_call]
-        ed_on_bls12_381_bandersnatch_mul_affine_sw(
-            RawOrigin::Signed(whitelisted_caller()),
-            base.encode(),
-            scalar.encode(),
-            false,
-        );
+        bls12_377_mul_projective_g1(RawOrigin::None, base.encode(), scalar.encode(), true);
     }
 
     #[benchmark]
-    fn sub_ed_on_bls12_381_bandersnatch_mul_affine_sw() {
-        let (base, scalar) =
-            utils::make_mul_affine_args::<ark_ed_on_bls12_381_bandersnatch::SWAffine>();
+    fn ark_bls12_377_mul_affine_g1(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args::<ark_bls12_377::G1Affine>(x);
 
         #[extrinsic_call]
-        ed_on_bls12_381_bandersnatch_mul_affine_sw(
-            RawOrigin::Signed(whitelisted_caller()),
-            base.encode(),
-            scalar.encode(),
-            true,
-        );
+        bls12_377_mul_affine_g1(RawOrigin::None, base.encode(), scalar.encode(), false);
     }
 
-    // Twisted Edwards
+    #[benchmark]
+    fn sub_bls12_377_mul_affine_g1(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args::<ark_bls12_377::G1Affine>(x);
+
+        #[extrinsic_call]
+        bls12_377_mul_affine_g1(RawOrigin::None, base.encode(), scalar.encode(), true);
+    }
 
     #[benchmark]
-    fn ark_ed_on_bls12_381_bandersnatch_msm_te(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
-        let (bases, scalars) =
-            utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsProjective>(x);
+    fn ark_bls12_377_msm_g2(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_bls12_377::G2Projective>(x);
+
         #[extrinsic_call]
-        ed_on_bls12_381_bandersnatch_msm_te(
-            RawOrigin::Signed(whitelisted_caller()),
-            bases.encode(),
-            scalars.encode(),
-            false,
-        );
+        bls12_377_msm_g2(RawOrigin::None, bases.encode(), scalars.encode(), false);
     }
 
     #[benchmark]
-    fn sub_ed_on_bls12_381_bandersnatch_msm_te(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
-        let (bases, scalars) =
-            utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsProjective>(x);
+    fn sub_bls12_377_msm_g2(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_bls12_377::G2Projective>(x);
+
         #[extrinsic_call]
-        ed_on_bls12_381_bandersnatch_msm_te(
-            RawOrigin::Signed(whitelisted_caller()),
-            bases.encode(),
-            scalars.encode(),
-            true,
-        );
+        bls12_377_msm_g2(RawOrigin::None, bases.encode(), scalars.encode(), true);
     }
 
     #[benchmark]
-    fn ark_ed_on_bls12_381_bandersnatch_mul_projective_te() {
-        let (base, scalar) = utils::make_mul_projective_args::<
-            ark_ed_on_bls12_381_bandersnatch::EdwardsProjective,
-        >();
+    fn ark_bls12_377_mul_projective_g2(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args_projective::<ark_bls12_377::G2Projective>(x);
 
         #[extrinsic_call]
-        ed_on_bls12_381_bandersnatch_mul_projective_te(
-            RawOrigin::Signed(whitelisted_caller()),
-            base.encode(),
-            scalar.encode(),
-            false,
-        );
+        bls12_377_mul_projective_g2(RawOrigin::None, base.encode(), scalar.encode(), false);
     }
 
     #[benchmark]
-    fn sub_ed_on_bls12_381_bandersnatch_mul_projective_te() {
-        let (base, scalar) = utils::make_mul_projective_args::<
-            ark_ed_on_bls12_381_bandersnatch::EdwardsProjective,
-        >();
+    fn sub_bls12_377_mul_projective_g2(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args_projective::<ark_bls12_377::G2Projective>(x);
 
         #[extrinsic_call]
-        ed_on_bls12_381_bandersnatch_mul_projective_te(
-            RawOrigin::Signed(whitelisted_caller()),
-            base.encode(),
-            scalar.encode(),
-            true,
-        );
+        bls12_377_mul_projective_g2(RawOrigin::None, base.encode(), scalar.encode(), true);
     }
 
     #[benchmark]
-    fn ark_ed_on_bls12_381_bandersnatch_mul_affine_te() {
-        let (base, scalar) =
-            utils::make_mul_affine_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsAffine>();
+    fn ark_bls12_377_mul_affine_g2(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args::<ark_bls12_377::G2Affine>(x);
 
         #[extrinsic_call]
-        ed_on_bls12_381_bandersnatch_mul_affine_te(
-            RawOrigin::Signed(whitelisted_caller()),
-            base.encode(),
-            scalar.encode(),
-            false,
-        );
+        bls12_377_mul_affine_g2(RawOrigin::None, base.encode(), scalar.encode(), false);
     }
 
     #[benchmark]
-    fn sub_ed_on_bls12_381_bandersnatch_mul_affine_te() {
-        let (base, scalar) =
-            utils::make_mul_affine_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsAffine>();
+    fn sub_bls12_377_mul_affine_g2(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args::<ark_bls12_377::G2Affine>(x);
 
         #[extrinsic_call]
-        ed_on_bls12_381_bandersnatch_mul_affine_te(
-            RawOrigin::Signed(whitelisted_caller()),
-            base.encode(),
-            scalar.encode(),
-            true,
-        );
+        bls12_377_mul_affine_g2(RawOrigin::None, base.encode(), scalar.encode(), true);
+    }
+
+    #[benchmark]
+    fn ark_bls12_377_multi_pairing(x: Linear<PAIRING_LEN_MIN, PAIRING_LEN_MAX>) {
+        let (a, b) =
+            utils::make_multi_pairing_args::<ark_bls12_377::G1Affine, ark_bls12_377::G2Affine>(x);
+
+        #[extrinsic_call]
+        bls12_377_multi_pairing(RawOrigin::None, a.encode(), b.encode(), false, false);
+    }
+
+    #[benchmark]
+    fn sub_bls12_377_multi_pairing(x: Linear<PAIRING_LEN_MIN, PAIRING_LEN_MAX>) {
+        let (a, b) =
+            utils::make_multi_pairing_args::<ark_bls12_377::G1Affine, ark_bls12_377::G2Affine>(x);
+
+        #[extrinsic_call]
+        bls12_377_multi_pairing(RawOrigin::None, a.encode(), b.encode(), false, true);
+    }
+
+    #[benchmark]
+    fn ark_bls12_377_multi_miller_loop(x: Linear<PAIRING_LEN_MIN, PAIRING_LEN_MAX>) {
+        let (a, b) =
+            utils::make_multi_pairing_args::<ark_bls12_377::G1Affine, ark_bls12_377::G2Affine>(x);
+
+        #[extrinsic_call]
+        bls12_377_multi_miller_loop(RawOrigin::None, a.encode(), b.encode(), false);
+    }
+
+    #[benchmark]
+    fn sub_bls12_377_multi_miller_loop(x: Linear<PAIRING_LEN_MIN, PAIRING_LEN_MAX>) {
+        let (a, b) =
+            utils::make_multi_pairing_args::<ark_bls12_377::G1Affine, ark_bls12_377::G2Affine>(x);
+
+        #[extrinsic_call]
+        bls12_377_multi_miller_loop(RawOrigin::None, a.encode(), b.encode(), true);
+    }
+
+    #[benchmark]
+    fn ark_bls12_377_final_exponentiation() {
+        let target = utils::make_final_exponentiation_args::<ark_bls12_377::Bls12_377>();
+
+        #[extrinsic_call]
+        bls12_377_final_exponentiation(RawOrigin::None, target.encode(), false);
+    }
+
+    #[benchmark]
+    fn sub_bls12_377_final_exponentiation() {
+        let target = utils::make_final_exponentiation_args::<ark_bls12_377::Bls12_377>();
+
+        #[extrinsic_call]
+        bls12_377_final_exponentiation(RawOrigin::None, target.encode(), true);
+    }
+
+    // ---------------------------------------------
+    // Calls for bw6-761
+    // ---------------------------------------------
+
+    #[benchmark]
+    fn ark_bw6_761_pairing() {
+        let (a, b) = utils::make_pairing_args::<ark_bw6_761::G1Affine, ark_bw6_761::G2Affine>();
+
+        #[extrinsic_call]
+        bw6_761_pairing(RawOrigin::None, a.encode(), b.encode(), false);
+    }
+
+    #[benchmark]
+    fn sub_bw6_761_pairing() {
+        let (a, b) = utils::make_pairing_args::<sub_bw6_761::G1Affine, sub_bw6_761::G2Affine>();
+
+        #[extrinsic_call]
+        bw6_761_pairing(RawOrigin::None, a.encode(), b.encode(), true);
+    }
+
+    #[benchmark]
+    fn ark_bw6_761_msm_g1(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_bw6_761::G1Projective>(x);
+
+        #[extrinsic_call]
+        bw6_761_msm_g1(RawOrigin::None, bases.encode(), scalars.encode(), false);
+    }
+
+    #[benchmark]
+    fn sub_bw6_761_msm_g1(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_bw6_761::G1Projective>(x);
+
+        #[extrinsic_call]
+        bw6_761_msm_g1(RawOrigin::None, bases.encode(), scalars.encode(), true);
+    }
+
+    #[benchmark]
+    fn ark_bw6_761_mul_projective_g1(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args_projective::<ark_bw6_761::G1Projective>(x);
+
+        #[extrinsic_call]
+        bw6_761_mul_projective_g1(RawOrigin::None, base.encode(), scalar.encode(), false);
+    }
+
+    #[benchmark]
+    fn sub_bw6_761_mul_projective_g1(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args_projective::<ark_bw6_761::G1Projective>(x);
+
+        #[extrinsic_call]
+        bw6_761_mul_projective_g1(RawOrigin::None, base.encode(), scalar.encode(), true);
+    }
+
+    #[benchmark]
+    fn ark_bw6_761_mul_affine_g1(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args::<ark_bw6_761::G1Affine>(x);
+
+        #[extrinsic_call]
+        bw6_761_mul_affine_g1(RawOrigin::None, base.encode(), scalar.encode(), false);
+    }
+
+    #[benchmark]
+    fn sub_bw6_761_mul_affine_g1(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args::<ark_bw6_761::G1Affine>(x);
+
+        #[extrinsic_call]
+        bw6_761_mul_affine_g1(RawOrigin::None, base.encode(), scalar.encode(), true);
+    }
+
+    #[benchmark]
+    fn ark_bw6_761_msm_g2(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_bw6_761::G2Projective>(x);
+
+        #[extrinsic_call]
+        bw6_761_msm_g2(RawOrigin::None, bases.encode(), scalars.encode(), false);
+    }
+
+    #[benchmark]
+    fn sub_bw6_761_msm_g2(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_bw6_761::G2Projective>(x);
+
+        #[extrinsic_call]
+        bw6_761_msm_g2(RawOrigin::None, bases.encode(), scalars.encode(), true);
+    }
+
+    #[benchmark]
+    fn ark_bw6_761_mul_projective_g2(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args_projective::<ark_bw6_761::G2Projective>(x);
+
+        #[extrinsic_call]
+        bw6_761_mul_projective_g2(RawOrigin::None, base.encode(), scalar.encode(), false);
+    }
+
+    #[benchmark]
+    fn sub_bw6_761_mul_projective_g2(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args_projective::<ark_bw6_761::G2Projective>(x);
+
+        #[extrinsic_call]
+        bw6_761_mul_projective_g2(RawOrigin::None, base.encode(), scalar.encode(), true);
+    }
+
+    #[benchmark]
+    fn ark_bw6_761_mul_affine_g2(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args::<ark_bw6_761::G2Affine>(x);
+
+        #[extrinsic_call]
+        bw6_761_mul_affine_g2(RawOrigin::None, base.encode(), scalar.encode(), false);
+    }
+
+    #[benchmark]
+    fn sub_bw6_761_mul_affine_g2(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args::<ark_bw6_761::G2Affine>(x);
+
+        #[extrinsic_call]
+        bw6_761_mul_affine_g2(RawOrigin::None, base.encode(), scalar.encode(), true);
+    }
+
+    #[benchmark]
+    fn ark_bw6_761_multi_pairing(x: Linear<PAIRING_LEN_MIN, PAIRING_LEN_MAX>) {
+        let (a, b) =
+            utils::make_multi_pairing_args::<ark_bw6_761::G1Affine, ark_bw6_761::G2Affine>(x);
+
+        #[extrinsic_call]
+        bw6_761_multi_pairing(RawOrigin::None, a.encode(), b.encode(), false, false);
+    }
+
+    #[benchmark]
+    fn sub_bw6_761_multi_pairing(x: Linear<PAIRING_LEN_MIN, PAIRING_LEN_MAX>) {
+        let (a, b) =
+            utils::make_multi_pairing_args::<ark_bw6_761::G1Affine, ark_bw6_761::G2Affine>(x);
+
+        #[extrinsic_call]
+        bw6_761_multi_pairing(RawOrigin::None, a.encode(), b.encode(), false, true);
+    }
+
+    #[benchmark]
+    fn ark_bw6_761_multi_miller_loop(x: Linear<PAIRING_LEN_MIN, PAIRING_LEN_MAX>) {
+        let (a, b) =
+            utils::make_multi_pairing_args::<ark_bw6_761::G1Affine, ark_bw6_761::G2Affine>(x);
+
+        #[extrinsic_call]
+        bw6_761_multi_miller_loop(RawOrigin::None, a.encode(), b.encode(), false);
+    }
+
+    #[benchmark]
+    fn sub_bw6_761_multi_miller_loop(x: Linear<PAIRING_LEN_MIN, PAIRING_LEN_MAX>) {
+        let (a, b) =
+            utils::make_multi_pairing_args::<ark_bw6_761::G1Affine, ark_bw6_761::G2Affine>(x);
+
+        #[extrinsic_call]
+        bw6_761_multi_miller_loop(RawOrigin::None, a.encode(), b.encode(), true);
+    }
+
+    #[benchmark]
+    fn ark_bw6_761_final_exponentiation() {
+        let target = utils::make_final_exponentiation_args::<ark_bw6_761::BW6_761>();
+
+        #[extrinsic_call]
+        bw6_761_final_exponentiation(RawOrigin::None, target.encode(), false);
+    }
+
+    #[benchmark]
+    fn sub_bw6_761_final_exponentiation() {
+        let target = utils::make_final_exponentiation_args::<ark_bw6_761::BW6_761>();
+
+        #[extrinsic_call]
+        bw6_761_final_exponentiation(RawOrigin::None, target.encode(), true);
+    }
+
+    // ---------------------------------------------
+    // Calls for ed-on-bls12-377
+    // ---------------------------------------------
+
+    // Twisted Edwards
+
+    #[benchmark]
+    fn ark_ed_on_bls12_377_msm_te(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_ed_on_bls12_377::EdwardsProjective>(x);
+
+        #[extrinsic_call]
+        ed_on_bls12_377_msm_te(
+            RawOrigin::Signed(whitelisted_caller()),
+            bases.encode(),
+            scalars.encode(),
+            false,
+        );
+    }
+
+    #[benchmark]
+    fn sub_ed_on_bls12_377_msm_te(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_ed_on_bls12_377::EdwardsProjective>(x);
+
+        #[extrinsic_call]
+        ed_on_bls12_377_msm_te(
+            RawOrigin::Signed(whitelisted_caller()),
+            bases.encode(),
+            scalars.encode(),
+            true,
+        );
+    }
+
+    #[benchmark]
+    fn ark_ed_on_bls12_377_mul_projective_te(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) =
+            utils::make_scalar_args_projective::<ark_ed_on_bls12_377::EdwardsProjective>(x);
+
+        #[extrinsic_call]
+        ed_on_bls12_377_mul_projective_te(
+            RawOrigin::Signed(whitelisted_caller()),
+            base.encode(),
+            scalar.encode(),
+            false,
+        );
+    }
+
+    #[benchmark]
+    fn sub_ed_on_bls12_377_mul_projective_te(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) =
+            utils::make_scalar_args_projective::<ark_ed_on_bls12_377::EdwardsProjective>(x);
+
+        #[extrinsic_call]
+        ed_on_bls12_377_mul_projective_te(
+            RawOrigin::Signed(whitelisted_caller()),
+            base.encode(),
+            scalar.encode(),
+            true,
+        );
+    }
+
+    #[benchmark]
+    fn ark_ed_on_bls12_377_mul_affine_te(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args::<ark_ed_on_bls12_377::EdwardsAffine>(x);
+
+        #[extrinsic_call]
+        ed_on_bls12_377_mul_affine_te(
+            RawOrigin::Signed(whitelisted_caller()),
+            base.encode(),
+            scalar.encode(),
+            false,
+        );
+    }
+
+    #[benchmark]
+    fn sub_ed_on_bls12_377_mul_affine_te(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args::<ark_ed_on_bls12_377::EdwardsAffine>(x);
+
+        #[extrinsic_call]
+        ed_on_bls12_377_mul_affine_te(
+            RawOrigin::Signed(whitelisted_caller()),
+            base.encode(),
+            scalar.encode(),
+            true,
+        );
+    }
+
+    // ---------------------------------------------
+    // Calls for ed-on-bls12-381-bandersnatch
+    // ---------------------------------------------
+
+    // Short Weierstrass
+
+    #[benchmark]
+    fn ark_ed_on_bls12_381_bandersnatch_msm_sw(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) =
+            utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::SWProjective>(x);
+
+        #[extrinsic_call]
+        ed_on_bls12_381_bandersnatch_msm_sw(
+            RawOrigin::Signed(whitelisted_caller()),
+            bases.encode(),
+            scalars.encode(),
+            false,
+        );
+    }
+
+    #[benchmark]
+    fn sub_ed_on_bls12_381_bandersnatch_msm_sw(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) =
+            utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::SWProjective>(x);
+        #[extrinsic_call]
+        ed_on_bls12_381_bandersnatch_msm_sw(
+            RawOrigin::Signed(whitelisted_caller()),
+            bases.encode(),
+            scalars.encode(),
+            true,
+        );
+    }
+
+    #[benchmark]
+    fn ark_ed_on_bls12_381_bandersnatch_mul_projective_sw(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) =
+            utils::make_scalar_args_projective::<ark_ed_on_bls12_381_bandersnatch::SWProjective>(x);
+
+        #[extrinsic_call]
+        ed_on_bls12_381_bandersnatch_mul_projective_sw(
+            RawOrigin::Signed(whitelisted_caller()),
+            base.encode(),
+            scalar.encode(),
+            false,
+        );
+    }
+
+    #[benchmark]
+    fn sub_ed_on_bls12_381_bandersnatch_mul_projective_sw(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) =
+            utils::make_scalar_args_projective::<ark_ed_on_bls12_381_bandersnatch::SWProjective>(x);
+
+        #[extrinsic_call]
+        ed_on_bls12_381_bandersnatch_mul_projective_sw(
+            RawOrigin::Signed(whitelisted_caller()),
+            base.encode(),
+            scalar.encode(),
+            true,
+        );
+    }
+
+    #[benchmark]
+    fn ark_ed_on_bls12_381_bandersnatch_mul_affine_sw(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) =
+            utils::make_scalar_args::<ark_ed_on_bls12_381_bandersnatch::SWAffine>(x);
+
+        #[extrinsic_call]
+        ed_on_bls12_381_bandersnatch_mul_affine_sw(
+            RawOrigin::Signed(whitelisted_caller()),
+            base.encode(),
+            scalar.encode(),
+            false,
+        );
+    }
+
+    #[benchmark]
+    fn sub_ed_on_bls12_381_bandersnatch_mul_affine_sw(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) =
+            utils::make_scalar_args::<ark_ed_on_bls12_381_bandersnatch::SWAffine>(x);
+
+        #[extrinsic_call]
+        ed_on_bls12_381_bandersnatch_mul_affine_sw(
+            RawOrigin::Signed(whitelisted_caller()),
+            base.encode(),
+            scalar.encode(),
+            true,
+        );
+    }
+
+    // Twisted Edwards
+
+    #[benchmark]
+    fn ark_ed_on_bls12_381_bandersnatch_msm_te(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) =
+            utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsProjective>(x);
+        #[extrinsic_call]
+        ed_on_bls12_381_bandersnatch_msm_te(
+            RawOrigin::Signed(whitelisted_caller()),
+            bases.encode(),
+            scalars.encode(),
+            false,
+        );
+    }
+
+    #[benchmark]
+    fn sub_ed_on_bls12_381_bandersnatch_msm_te(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) =
+            utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsProjective>(x);
+        #[extrinsic_call]
+        ed_on_bls12_381_bandersnatch_msm_te(
+            RawOrigin::Signed(whitelisted_caller()),
+            bases.encode(),
+            scalars.encode(),
+            true,
+        );
+    }
+
+    #[benchmark]
+    fn ark_ed_on_bls12_381_bandersnatch_mul_projective_te(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args_projective::<
+            ark_ed_on_bls12_381_bandersnatch::EdwardsProjective,
+        >(x);
+
+        #[extrinsic_call]
+        ed_on_bls12_381_bandersnatch_mul_projective_te(
+            RawOrigin::Signed(whitelisted_caller()),
+            base.encode(),
+            scalar.encode(),
+            false,
+        );
+    }
+
+    #[benchmark]
+    fn sub_ed_on_bls12_381_bandersnatch_mul_projective_te(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) = utils::make_scalar_args_projective::<
+            ark_ed_on_bls12_381_bandersnatch::EdwardsProjective,
+        >(x);
+
+        #[extrinsic_call]
+        ed_on_bls12_381_bandersnatch_mul_projective_te(
+            RawOrigin::Signed(whitelisted_caller()),
+            base.encode(),
+            scalar.encode(),
+            true,
+        );
+    }
+
+    #[benchmark]
+    fn ark_ed_on_bls12_381_bandersnatch_mul_affine_te(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) =
+            utils::make_scalar_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsAffine>(x);
+
+        #[extrinsic_call]
+        ed_on_bls12_381_bandersnatch_mul_affine_te(
+            RawOrigin::Signed(whitelisted_caller()),
+            base.encode(),
+            scalar.encode(),
+            false,
+        );
+    }
+
+    #[benchmark]
+    fn sub_ed_on_bls12_381_bandersnatch_mul_affine_te(x: Linear<1, SCALAR_WORDS_MAX>) {
+        let (base, scalar) =
+            utils::make_scalar_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsAffine>(x);
+
+        #[extrinsic_call]
+        ed_on_bls12_381_bandersnatch_mul_affine_te(
+            RawOrigin::Signed(whitelisted_caller()),
+            base.encode(),
+            scalar.encode(),
+            true,
+        );
+    }
+
+    // ---------------------------------------------
+    // Conversions for ed-on-bls12-381-bandersnatch
+    // ---------------------------------------------
+
+    #[benchmark]
+    fn ed_on_bls12_381_bandersnatch_te_to_sw() {
+        let point = utils::make_point_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsAffine>();
+
+        #[extrinsic_call]
+        ed_on_bls12_381_bandersnatch_te_to_sw(RawOrigin::None, point.encode());
+    }
+
+    #[benchmark]
+    fn ed_on_bls12_381_bandersnatch_sw_to_te() {
+        let point = utils::make_point_args::<ark_ed_on_bls12_381_bandersnatch::SWAffine>();
+
+        #[extrinsic_call]
+        ed_on_bls12_381_bandersnatch_sw_to_te(RawOrigin::None, point.encode());
+    }
+
+    #[benchmark]
+    fn ed_on_bls12_381_bandersnatch_te_to_sw_batch(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let points = utils::make_points_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsAffine>(x);
+
+        #[extrinsic_call]
+        ed_on_bls12_381_bandersnatch_te_to_sw_batch(RawOrigin::None, points.encode());
+    }
+
+    #[benchmark]
+    fn ed_on_bls12_381_bandersnatch_sw_to_te_batch(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let points = utils::make_points_args::<ark_ed_on_bls12_381_bandersnatch::SWAffine>(x);
+
+        #[extrinsic_call]
+        ed_on_bls12_381_bandersnatch_sw_to_te_batch(RawOrigin::None, points.encode());
+    }
+
+    // ---------------------------------------------
+    // Fixed base multi scalar multiplication
+    //
+    // Compared with the `msm` benchmarks these give the storage read cost
+    // against the cost of decoding and validating inline bases.
+    // ---------------------------------------------
+
+    #[benchmark]
+    fn ark_bls12_381_msm_fixed_base_g1(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_bls12_381::G1Projective>(x);
+        Pallet::<T>::insert_base_set(0, Curve::Bls12_381G1, &bases.encode()).unwrap();
+
+        #[extrinsic_call]
+        msm_fixed_base(RawOrigin::None, 0, scalars.encode(), false);
+    }
+
+    #[benchmark]
+    fn sub_bls12_381_msm_fixed_base_g1(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_bls12_381::G1Projective>(x);
+        Pallet::<T>::insert_base_set(0, Curve::Bls12_381G1, &bases.encode()).unwrap();
+
+        #[extrinsic_call]
+        msm_fixed_base(RawOrigin::None, 0, scalars.encode(), true);
+    }
+
+    #[benchmark]
+    fn ark_bls12_381_msm_fixed_base_g2(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_bls12_381::G2Projective>(x);
+        Pallet::<T>::insert_base_set(0, Curve::Bls12_381G2, &bases.encode()).unwrap();
+
+        #[extrinsic_call]
+        msm_fixed_base(RawOrigin::None, 0, scalars.encode(), false);
+    }
+
+    #[benchmark]
+    fn sub_bls12_381_msm_fixed_base_g2(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_bls12_381::G2Projective>(x);
+        Pallet::<T>::insert_base_set(0, Curve::Bls12_381G2, &bases.encode()).unwrap();
+
+        #[extrinsic_call]
+        msm_fixed_base(RawOrigin::None, 0, scalars.encode(), true);
+    }
+
+    #[benchmark]
+    fn ark_bls12_377_msm_fixed_base_g1(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_bls12_377::G1Projective>(x);
+        Pallet::<T>::insert_base_set(0, Curve::Bls12_377G1, &bases.encode()).unwrap();
+
+        #[extrinsic_call]
+        msm_fixed_base(RawOrigin::None, 0, scalars.encode(), false);
+    }
+
+    #[benchmark]
+    fn sub_bls12_377_msm_fixed_base_g1(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_bls12_377::G1Projective>(x);
+        Pallet::<T>::insert_base_set(0, Curve::Bls12_377G1, &bases.encode()).unwrap();
+
+        #[extrinsic_call]
+        msm_fixed_base(RawOrigin::None, 0, scalars.encode(), true);
+    }
+
+    #[benchmark]
+    fn ark_bls12_377_msm_fixed_base_g2(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_bls12_377::G2Projective>(x);
+        Pallet::<T>::insert_base_set(0, Curve::Bls12_377G2, &bases.encode()).unwrap();
+
+        #[extrinsic_call]
+        msm_fixed_base(RawOrigin::None, 0, scalars.encode(), false);
+    }
+
+    #[benchmark]
+    fn sub_bls12_377_msm_fixed_base_g2(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_bls12_377::G2Projective>(x);
+        Pallet::<T>::insert_base_set(0, Curve::Bls12_377G2, &bases.encode()).unwrap();
+
+        #[extrinsic_call]
+        msm_fixed_base(RawOrigin::None, 0, scalars.encode(), true);
+    }
+
+    #[benchmark]
+    fn ark_bw6_761_msm_fixed_base_g1(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_bw6_761::G1Projective>(x);
+        Pallet::<T>::insert_base_set(0, Curve::Bw6_761G1, &bases.encode()).unwrap();
+
+        #[extrinsic_call]
+        msm_fixed_base(RawOrigin::None, 0, scalars.encode(), false);
+    }
+
+    #[benchmark]
+    fn sub_bw6_761_msm_fixed_base_g1(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_bw6_761::G1Projective>(x);
+        Pallet::<T>::insert_base_set(0, Curve::Bw6_761G1, &bases.encode()).unwrap();
+
+        #[extrinsic_call]
+        msm_fixed_base(RawOrigin::None, 0, scalars.encode(), true);
+    }
+
+    #[benchmark]
+    fn ark_bw6_761_msm_fixed_base_g2(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_bw6_761::G2Projective>(x);
+        Pallet::<T>::insert_base_set(0, Curve::Bw6_761G2, &bases.encode()).unwrap();
+
+        #[extrinsic_call]
+        msm_fixed_base(RawOrigin::None, 0, scalars.encode(), false);
+    }
+
+    #[benchmark]
+    fn sub_bw6_761_msm_fixed_base_g2(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_bw6_761::G2Projective>(x);
+        Pallet::<T>::insert_base_set(0, Curve::Bw6_761G2, &bases.encode()).unwrap();
+
+        #[extrinsic_call]
+        msm_fixed_base(RawOrigin::None, 0, scalars.encode(), true);
+    }
+
+    #[benchmark]
+    fn ark_ed_on_bls12_377_msm_fixed_base_te(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_ed_on_bls12_377::EdwardsProjective>(x);
+        Pallet::<T>::insert_base_set(0, Curve::EdOnBls12_377Te, &bases.encode()).unwrap();
+
+        #[extrinsic_call]
+        msm_fixed_base(RawOrigin::None, 0, scalars.encode(), false);
+    }
+
+    #[benchmark]
+    fn sub_ed_on_bls12_377_msm_fixed_base_te(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) = utils::make_msm_args::<ark_ed_on_bls12_377::EdwardsProjective>(x);
+        Pallet::<T>::insert_base_set(0, Curve::EdOnBls12_377Te, &bases.encode()).unwrap();
+
+        #[extrinsic_call]
+        msm_fixed_base(RawOrigin::None, 0, scalars.encode(), true);
+    }
+
+    #[benchmark]
+    fn ark_ed_on_bls12_381_bandersnatch_msm_fixed_base_sw(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) =
+            utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::SWProjective>(x);
+        Pallet::<T>::insert_base_set(0, Curve::EdOnBls12_381BandersnatchSw, &bases.encode())
+            .unwrap();
+
+        #[extrinsic_call]
+        msm_fixed_base(RawOrigin::None, 0, scalars.encode(), false);
+    }
+
+    #[benchmark]
+    fn sub_ed_on_bls12_381_bandersnatch_msm_fixed_base_sw(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) =
+            utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::SWProjective>(x);
+        Pallet::<T>::insert_base_set(0, Curve::EdOnBls12_381BandersnatchSw, &bases.encode())
+            .unwrap();
+
+        #[extrinsic_call]
+        msm_fixed_base(RawOrigin::None, 0, scalars.encode(), true);
+    }
+
+    #[benchmark]
+    fn ark_ed_on_bls12_381_bandersnatch_msm_fixed_base_te(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) =
+            utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsProjective>(x);
+        Pallet::<T>::insert_base_set(0, Curve::EdOnBls12_381BandersnatchTe, &bases.encode())
+            .unwrap();
+
+        #[extrinsic_call]
+        msm_fixed_base(RawOrigin::None, 0, scalars.encode(), false);
+    }
+
+    #[benchmark]
+    fn sub_ed_on_bls12_381_bandersnatch_msm_fixed_base_te(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, scalars) =
+            utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsProjective>(x);
+        Pallet::<T>::insert_base_set(0, Curve::EdOnBls12_381BandersnatchTe, &bases.encode())
+            .unwrap();
+
+        #[extrinsic_call]
+        msm_fixed_base(RawOrigin::None, 0, scalars.encode(), true);
+    }
+
+    #[benchmark]
+    fn bls12_381_register_base_set_g1(
+        x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::BaseSetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let (bases, _) = utils::make_msm_args::<ark_bls12_381::G1Projective>(x);
+
+        #[extrinsic_call]
+        register_base_set(
+            origin as T::RuntimeOrigin,
+            0,
+            Curve::Bls12_381G1,
+            bases.encode(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn bls12_381_register_base_set_g2(
+        x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::BaseSetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let (bases, _) = utils::make_msm_args::<ark_bls12_381::G2Projective>(x);
+
+        #[extrinsic_call]
+        register_base_set(
+            origin as T::RuntimeOrigin,
+            0,
+            Curve::Bls12_381G2,
+            bases.encode(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn bls12_377_register_base_set_g1(
+        x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::BaseSetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let (bases, _) = utils::make_msm_args::<ark_bls12_377::G1Projective>(x);
+
+        #[extrinsic_call]
+        register_base_set(
+            origin as T::RuntimeOrigin,
+            0,
+            Curve::Bls12_377G1,
+            bases.encode(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn bls12_377_register_base_set_g2(
+        x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::BaseSetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let (bases, _) = utils::make_msm_args::<ark_bls12_377::G2Projective>(x);
+
+        #[extrinsic_call]
+        register_base_set(
+            origin as T::RuntimeOrigin,
+            0,
+            Curve::Bls12_377G2,
+            bases.encode(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn bw6_761_register_base_set_g1(
+        x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::BaseSetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let (bases, _) = utils::make_msm_args::<ark_bw6_761::G1Projective>(x);
+
+        #[extrinsic_call]
+        register_base_set(
+            origin as T::RuntimeOrigin,
+            0,
+            Curve::Bw6_761G1,
+            bases.encode(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn bw6_761_register_base_set_g2(
+        x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::BaseSetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let (bases, _) = utils::make_msm_args::<ark_bw6_761::G2Projective>(x);
+
+        #[extrinsic_call]
+        register_base_set(
+            origin as T::RuntimeOrigin,
+            0,
+            Curve::Bw6_761G2,
+            bases.encode(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn ed_on_bls12_377_register_base_set_te(
+        x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::BaseSetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let (bases, _) = utils::make_msm_args::<ark_ed_on_bls12_377::EdwardsProjective>(x);
+
+        #[extrinsic_call]
+        register_base_set(
+            origin as T::RuntimeOrigin,
+            0,
+            Curve::EdOnBls12_377Te,
+            bases.encode(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn ed_on_bls12_381_bandersnatch_register_base_set_sw(
+        x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::BaseSetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let (bases, _) = utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::SWProjective>(x);
+
+        #[extrinsic_call]
+        register_base_set(
+            origin as T::RuntimeOrigin,
+            0,
+            Curve::EdOnBls12_381BandersnatchSw,
+            bases.encode(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn ed_on_bls12_381_bandersnatch_register_base_set_te(
+        x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::BaseSetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let (bases, _) =
+            utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsProjective>(x);
+
+        #[extrinsic_call]
+        register_base_set(
+            origin as T::RuntimeOrigin,
+            0,
+            Curve::EdOnBls12_381BandersnatchTe,
+            bases.encode(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_base_set(x: Linear<1, BASE_SET_PAGES_MAX>) -> Result<(), BenchmarkError> {
+        let origin =
+            T::BaseSetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let (bases, _) =
+            utils::make_msm_args::<ark_bls12_381::G1Projective>(x * BASE_SET_PAGE_SIZE as u32);
+        Pallet::<T>::insert_base_set(0, Curve::Bls12_381G1, &bases.encode()).unwrap();
+
+        #[extrinsic_call]
+        remove_base_set(origin as T::RuntimeOrigin, 0);
+
+        Ok(())
+    }
+
+    // ---------------------------------------------
+    // Points decoding
+    //
+    // Call inputs are compressed, thus the difference between the
+    // `unchecked` and `uncompressed` variants is the decompression cost.
+    // ---------------------------------------------
+
+    #[benchmark]
+    fn ark_bls12_381_decode_g1_unchecked(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_381::G1Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<ark_bls12_381::g1::Config>>(&bases, Validate::No).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_bls12_381_decode_g1_unchecked(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_381::G1Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<sub_bls12_381::g1::Config>>(&bases, Validate::No).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_bls12_381_decode_g1_validated(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_381::G1Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<ark_bls12_381::g1::Config>>(&bases, Validate::Yes).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_bls12_381_decode_g1_validated(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_381::G1Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<sub_bls12_381::g1::Config>>(&bases, Validate::Yes).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_bls12_381_decode_g2_unchecked(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_381::G2Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<ark_bls12_381::g2::Config>>(&bases, Validate::No).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_bls12_381_decode_g2_unchecked(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_381::G2Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<sub_bls12_381::g2::Config>>(&bases, Validate::No).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_bls12_381_decode_g2_validated(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_381::G2Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<ark_bls12_381::g2::Config>>(&bases, Validate::Yes).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_bls12_381_decode_g2_validated(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_381::G2Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<sub_bls12_381::g2::Config>>(&bases, Validate::Yes).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_bls12_377_decode_g1_unchecked(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_377::G1Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<ark_bls12_377::g1::Config>>(&bases, Validate::No).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_bls12_377_decode_g1_unchecked(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_377::G1Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<sub_bls12_377::g1::Config>>(&bases, Validate::No).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_bls12_377_decode_g1_validated(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_377::G1Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<ark_bls12_377::g1::Config>>(&bases, Validate::Yes).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_bls12_377_decode_g1_validated(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_377::G1Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<sub_bls12_377::g1::Config>>(&bases, Validate::Yes).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_bls12_377_decode_g2_unchecked(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_377::G2Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<ark_bls12_377::g2::Config>>(&bases, Validate::No).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_bls12_377_decode_g2_unchecked(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_377::G2Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<sub_bls12_377::g2::Config>>(&bases, Validate::No).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_bls12_377_decode_g2_validated(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_377::G2Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<ark_bls12_377::g2::Config>>(&bases, Validate::Yes).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_bls12_377_decode_g2_validated(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_377::G2Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<sub_bls12_377::g2::Config>>(&bases, Validate::Yes).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_bw6_761_decode_g1_unchecked(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bw6_761::G1Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<ark_bw6_761::g1::Config>>(&bases, Validate::No).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_bw6_761_decode_g1_unchecked(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bw6_761::G1Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<sub_bw6_761::g1::Config>>(&bases, Validate::No).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_bw6_761_decode_g1_validated(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bw6_761::G1Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<ark_bw6_761::g1::Config>>(&bases, Validate::Yes).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_bw6_761_decode_g1_validated(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bw6_761::G1Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<sub_bw6_761::g1::Config>>(&bases, Validate::Yes).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_bw6_761_decode_g2_unchecked(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bw6_761::G2Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<ark_bw6_761::g2::Config>>(&bases, Validate::No).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_bw6_761_decode_g2_unchecked(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bw6_761::G2Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<sub_bw6_761::g2::Config>>(&bases, Validate::No).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_bw6_761_decode_g2_validated(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bw6_761::G2Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<ark_bw6_761::g2::Config>>(&bases, Validate::Yes).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_bw6_761_decode_g2_validated(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bw6_761::G2Projective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<sub_bw6_761::g2::Config>>(&bases, Validate::Yes).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_ed_on_bls12_377_decode_te_unchecked(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_ed_on_bls12_377::EdwardsProjective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<TEAffine<ark_ed_on_bls12_377::EdwardsConfig>>(&bases, Validate::No)
+                .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_ed_on_bls12_377_decode_te_unchecked(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_ed_on_bls12_377::EdwardsProjective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<TEAffine<sub_ed_on_bls12_377::EdwardsConfig>>(&bases, Validate::No)
+                .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_ed_on_bls12_377_decode_te_validated(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_ed_on_bls12_377::EdwardsProjective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<TEAffine<ark_ed_on_bls12_377::EdwardsConfig>>(&bases, Validate::Yes)
+                .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_ed_on_bls12_377_decode_te_validated(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_ed_on_bls12_377::EdwardsProjective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<TEAffine<sub_ed_on_bls12_377::EdwardsConfig>>(&bases, Validate::Yes)
+                .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_ed_on_bls12_381_bandersnatch_decode_sw_unchecked(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::SWProjective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<ark_ed_on_bls12_381_bandersnatch::SWConfig>>(
+                &bases,
+                Validate::No,
+            )
+            .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_ed_on_bls12_381_bandersnatch_decode_sw_unchecked(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::SWProjective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<sub_ed_on_bls12_381_bandersnatch::SWConfig>>(
+                &bases,
+                Validate::No,
+            )
+            .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_ed_on_bls12_381_bandersnatch_decode_sw_validated(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::SWProjective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<ark_ed_on_bls12_381_bandersnatch::SWConfig>>(
+                &bases,
+                Validate::Yes,
+            )
+            .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_ed_on_bls12_381_bandersnatch_decode_sw_validated(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::SWProjective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<SWAffine<sub_ed_on_bls12_381_bandersnatch::SWConfig>>(
+                &bases,
+                Validate::Yes,
+            )
+            .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_ed_on_bls12_381_bandersnatch_decode_te_unchecked(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) =
+            utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsProjective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<TEAffine<ark_ed_on_bls12_381_bandersnatch::EdwardsConfig>>(
+                &bases,
+                Validate::No,
+            )
+            .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_ed_on_bls12_381_bandersnatch_decode_te_unchecked(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) =
+            utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsProjective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<TEAffine<sub_ed_on_bls12_381_bandersnatch::EdwardsConfig>>(
+                &bases,
+                Validate::No,
+            )
+            .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_ed_on_bls12_381_bandersnatch_decode_te_validated(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) =
+            utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsProjective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<TEAffine<ark_ed_on_bls12_381_bandersnatch::EdwardsConfig>>(
+                &bases,
+                Validate::Yes,
+            )
+            .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_ed_on_bls12_381_bandersnatch_decode_te_validated(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) =
+            utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsProjective>(x);
+        let bases = bases.encode();
+
+        #[block]
+        {
+            decode_vec::<TEAffine<sub_ed_on_bls12_381_bandersnatch::EdwardsConfig>>(
+                &bases,
+                Validate::Yes,
+            )
+            .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_bls12_381_decode_g1_uncompressed(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_381::G1Projective>(x);
+        let bases = utils::serialize_argument(bases.0);
+
+        #[block]
+        {
+            Vec::<SWAffine<ark_bls12_381::g1::Config>>::deserialize_uncompressed_unchecked(
+                &bases[..],
+            )
+            .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_bls12_381_decode_g1_uncompressed(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_381::G1Projective>(x);
+        let bases = utils::serialize_argument(bases.0);
+
+        #[block]
+        {
+            Vec::<SWAffine<sub_bls12_381::g1::Config>>::deserialize_uncompressed_unchecked(
+                &bases[..],
+            )
+            .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_bls12_381_decode_g2_uncompressed(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_381::G2Projective>(x);
+        let bases = utils::serialize_argument(bases.0);
+
+        #[block]
+        {
+            Vec::<SWAffine<ark_bls12_381::g2::Config>>::deserialize_uncompressed_unchecked(
+                &bases[..],
+            )
+            .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_bls12_381_decode_g2_uncompressed(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_381::G2Projective>(x);
+        let bases = utils::serialize_argument(bases.0);
+
+        #[block]
+        {
+            Vec::<SWAffine<sub_bls12_381::g2::Config>>::deserialize_uncompressed_unchecked(
+                &bases[..],
+            )
+            .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_bls12_377_decode_g1_uncompressed(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_377::G1Projective>(x);
+        let bases = utils::serialize_argument(bases.0);
+
+        #[block]
+        {
+            Vec::<SWAffine<ark_bls12_377::g1::Config>>::deserialize_uncompressed_unchecked(
+                &bases[..],
+            )
+            .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_bls12_377_decode_g1_uncompressed(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_377::G1Projective>(x);
+        let bases = utils::serialize_argument(bases.0);
+
+        #[block]
+        {
+            Vec::<SWAffine<sub_bls12_377::g1::Config>>::deserialize_uncompressed_unchecked(
+                &bases[..],
+            )
+            .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_bls12_377_decode_g2_uncompressed(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_377::G2Projective>(x);
+        let bases = utils::serialize_argument(bases.0);
+
+        #[block]
+        {
+            Vec::<SWAffine<ark_bls12_377::g2::Config>>::deserialize_uncompressed_unchecked(
+                &bases[..],
+            )
+            .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_bls12_377_decode_g2_uncompressed(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bls12_377::G2Projective>(x);
+        let bases = utils::serialize_argument(bases.0);
+
+        #[block]
+        {
+            Vec::<SWAffine<sub_bls12_377::g2::Config>>::deserialize_uncompressed_unchecked(
+                &bases[..],
+            )
+            .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_bw6_761_decode_g1_uncompressed(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bw6_761::G1Projective>(x);
+        let bases = utils::serialize_argument(bases.0);
+
+        #[block]
+        {
+            Vec::<SWAffine<ark_bw6_761::g1::Config>>::deserialize_uncompressed_unchecked(
+                &bases[..],
+            )
+            .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_bw6_761_decode_g1_uncompressed(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bw6_761::G1Projective>(x);
+        let bases = utils::serialize_argument(bases.0);
+
+        #[block]
+        {
+            Vec::<SWAffine<sub_bw6_761::g1::Config>>::deserialize_uncompressed_unchecked(
+                &bases[..],
+            )
+            .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_bw6_761_decode_g2_uncompressed(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bw6_761::G2Projective>(x);
+        let bases = utils::serialize_argument(bases.0);
+
+        #[block]
+        {
+            Vec::<SWAffine<ark_bw6_761::g2::Config>>::deserialize_uncompressed_unchecked(
+                &bases[..],
+            )
+            .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_bw6_761_decode_g2_uncompressed(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_bw6_761::G2Projective>(x);
+        let bases = utils::serialize_argument(bases.0);
+
+        #[block]
+        {
+            Vec::<SWAffine<sub_bw6_761::g2::Config>>::deserialize_uncompressed_unchecked(
+                &bases[..],
+            )
+            .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_ed_on_bls12_377_decode_te_uncompressed(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_ed_on_bls12_377::EdwardsProjective>(x);
+        let bases = utils::serialize_argument(bases.0);
+
+        #[block]
+        {
+            Vec::<TEAffine<ark_ed_on_bls12_377::EdwardsConfig>>::deserialize_uncompressed_unchecked(&bases[..]).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_ed_on_bls12_377_decode_te_uncompressed(x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>) {
+        let (bases, _) = utils::make_msm_args::<ark_ed_on_bls12_377::EdwardsProjective>(x);
+        let bases = utils::serialize_argument(bases.0);
+
+        #[block]
+        {
+            Vec::<TEAffine<sub_ed_on_bls12_377::EdwardsConfig>>::deserialize_uncompressed_unchecked(&bases[..]).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_ed_on_bls12_381_bandersnatch_decode_sw_uncompressed(
+        x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>,
+    ) {
+        let (bases, _) = utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::SWProjective>(x);
+        let bases = utils::serialize_argument(bases.0);
+
+        #[block]
+        {
+            Vec::<SWAffine<ark_ed_on_bls12_381_bandersnatch::SWConfig>>::deserialize_uncompressed_unchecked(&bases[..]).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_ed_on_bls12_381_bandersnatch_decode_sw_uncompressed(
+        x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>,
+    ) {
+        let (bases, _) = utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::SWProjective>(x);
+        let bases = utils::serialize_argument(bases.0);
+
+        #[block]
+        {
+            Vec::<SWAffine<sub_ed_on_bls12_381_bandersnatch::SWConfig>>::deserialize_uncompressed_unchecked(&bases[..]).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn ark_ed_on_bls12_381_bandersnatch_decode_te_uncompressed(
+        x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>,
+    ) {
+        let (bases, _) =
+            utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsProjective>(x);
+        let bases = utils::serialize_argument(bases.0);
+
+        #[block]
+        {
+            Vec::<TEAffine<ark_ed_on_bls12_381_bandersnatch::EdwardsConfig>>::deserialize_uncompressed_unchecked(&bases[..]).unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn sub_ed_on_bls12_381_bandersnatch_decode_te_uncompressed(
+        x: Linear<MSM_LEN_MIN, MSM_LEN_MAX>,
+    ) {
+        let (bases, _) =
+            utils::make_msm_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsProjective>(x);
+        let bases = utils::serialize_argument(bases.0);
+
+        #[block]
+        {
+            Vec::<TEAffine<sub_ed_on_bls12_381_bandersnatch::EdwardsConfig>>::deserialize_uncompressed_unchecked(&bases[..]).unwrap();
+        }
     }
 
     impl_benchmark_test_suite!(ArkHostcalls, crate::mock::new_test_ext(), crate::mock::Test);
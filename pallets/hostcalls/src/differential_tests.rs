@@ -0,0 +1,866 @@
+//! Differential tests of the Arkworks and Substrate backends.
+//!
+//! Each operation is dispatched with both backends over random and edge case inputs,
+//! checking that the encoded outputs are bit-identical.
+
+use crate::{
+    mock::{backend, new_test_ext, ArkHostcalls, RuntimeOrigin, Test},
+    ArkScale, BackendSwitch, Curve, LastResult,
+};
+use ark_ec::{pairing::Pairing, AffineRepr};
+use ark_ff::{One, UniformRand};
+use ark_scale::{hazmat::ArkScaleProjective, scale::Encode};
+use ark_std::rand::{rngs::StdRng, Rng, SeedableRng};
+use frame_support::{assert_ok, pallet_prelude::DispatchResult};
+
+/// Seed of the random inputs.
+const SEED: u64 = 0xd1ff;
+/// Number of random inputs of each operation.
+const RANDOM_CASES: usize = 4;
+/// Longest random MSM.
+const MAX_MSM_LEN: usize = 32;
+/// Longest random multi pairing.
+const MAX_PAIRING_LEN: usize = 3;
+/// Longest scalar of the scalar multiplications (`MaxScalarWords` of the mock).
+const MAX_SCALAR_WORDS: usize = 8;
+/// Domain separation tag of the hash to curve inputs.
+const HASH_DST: &[u8] = b"DIFFERENTIAL-TESTS-V01";
+
+fn rng() -> StdRng {
+    StdRng::seed_from_u64(SEED)
+}
+
+fn random<T: UniformRand>(rng: &mut StdRng, len: usize) -> Vec<T> {
+    (0..len).map(|_| T::rand(rng)).collect()
+}
+
+/// Dispatch `call` with both backends, checking that the outputs are bit-identical.
+fn assert_same_output(call: impl Fn(BackendSwitch) -> DispatchResult) {
+    let ark = call(backend(false)).map(|_| LastResult::<Test>::take());
+    let sub = call(backend(true)).map(|_| LastResult::<Test>::take());
+    assert!(matches!(ark, Ok(Some(_))), "{ark:?}");
+    assert_eq!(ark, sub);
+}
+
+/// Zero length, identity bases, small scalars and random MSMs.
+fn msm_cases<A: AffineRepr>() -> Vec<(Vec<u8>, Vec<u8>)> {
+    let rng = &mut rng();
+    let small = |n: u64| (0..n).map(A::ScalarField::from).collect::<Vec<_>>();
+    let mut cases = vec![
+        (vec![], vec![]),
+        (vec![A::zero(); 3], random(rng, 3)),
+        (random(rng, 4), small(4)),
+        (vec![A::generator()], small(2)[1..].to_vec()),
+    ];
+    for _ in 0..RANDOM_CASES {
+        let len = rng.gen_range(1..=MAX_MSM_LEN);
+        cases.push((random(rng, len), random(rng, len)));
+    }
+    cases
+        .into_iter()
+        .map(|(bases, scalars)| {
+            (
+                ArkScale::from(bases).encode(),
+                ArkScale::from(scalars).encode(),
+            )
+        })
+        .collect()
+}
+
+/// Identity, generator and random bases, each paired with empty, small and random
+/// scalars of 1 to `MAX_SCALAR_WORDS` words.
+fn mul_cases<A: AffineRepr>() -> Vec<(A, Vec<u64>)> {
+    let rng = &mut rng();
+    let mut scalars = vec![vec![], vec![0], vec![1], vec![2], vec![u64::MAX]];
+    scalars.extend((1..=MAX_SCALAR_WORDS).map(|words| (0..words).map(|_| rng.gen()).collect()));
+    let mut bases = vec![A::zero(), A::generator()];
+    bases.extend(random::<A>(rng, 2));
+    bases
+        .into_iter()
+        .flat_map(|base| scalars.iter().map(move |scalar| (base, scalar.clone())))
+        .collect()
+}
+
+fn mul_affine_cases<A: AffineRepr>() -> Vec<(Vec<u8>, Vec<u8>)> {
+    mul_cases::<A>()
+        .into_iter()
+        .map(|(base, scalar)| {
+            (
+                ArkScale::from(base).encode(),
+                ArkScale::from(scalar).encode(),
+            )
+        })
+        .collect()
+}
+
+fn mul_projective_cases<A: AffineRepr>() -> Vec<(Vec<u8>, Vec<u8>)> {
+    mul_cases::<A>()
+        .into_iter()
+        .map(|(base, scalar)| {
+            let base = ArkScaleProjective::from(base.into_group()).encode();
+            (base, ArkScale::from(scalar).encode())
+        })
+        .collect()
+}
+
+/// Identity, generators and random pairs.
+fn pairing_cases<P: Pairing>() -> Vec<(Vec<u8>, Vec<u8>)> {
+    let rng = &mut rng();
+    let mut cases = vec![
+        (P::G1Affine::zero(), P::G2Affine::rand(rng)),
+        (P::G1Affine::rand(rng), P::G2Affine::zero()),
+        (P::G1Affine::generator(), P::G2Affine::generator()),
+    ];
+    cases.extend((0..RANDOM_CASES).map(|_| (P::G1Affine::rand(rng), P::G2Affine::rand(rng))));
+    cases
+        .into_iter()
+        .map(|(a, b)| (ArkScale::from(a).encode(), ArkScale::from(b).encode()))
+        .collect()
+}
+
+/// Random pairs of length 0 to `MAX_PAIRING_LEN` and pairs with identities.
+fn multi_pairing_cases<P: Pairing>() -> Vec<(Vec<u8>, Vec<u8>)> {
+    let rng = &mut rng();
+    let mut cases: Vec<(Vec<P::G1Affine>, Vec<P::G2Affine>)> = (0..=MAX_PAIRING_LEN)
+        .map(|len| (random(rng, len), random(rng, len)))
+        .collect();
+    cases.push((
+        vec![P::G1Affine::zero(), P::G1Affine::rand(rng)],
+        vec![P::G2Affine::rand(rng), P::G2Affine::zero()],
+    ));
+    cases
+        .into_iter()
+        .map(|(a, b)| (ArkScale::from(a).encode(), ArkScale::from(b).encode()))
+        .collect()
+}
+
+/// The unit and random Miller loop outputs.
+fn final_exponentiation_cases<P: Pairing>() -> Vec<Vec<u8>> {
+    let rng = &mut rng();
+    let mut targets = vec![P::TargetField::one()];
+    targets.extend((0..RANDOM_CASES).map(|_| {
+        let a: Vec<P::G1Affine> = random(rng, 2);
+        let b: Vec<P::G2Affine> = random(rng, 2);
+        P::multi_miller_loop(a, b).0
+    }));
+    targets
+        .into_iter()
+        .map(|target| ArkScale::from(target).encode())
+        .collect()
+}
+
+/// Empty and random messages.
+fn hash_to_curve_cases() -> Vec<Vec<u8>> {
+    let rng = &mut rng();
+    let mut msgs = vec![vec![]];
+    msgs.extend((0..RANDOM_CASES).map(|_| {
+        let len = rng.gen_range(1..=256);
+        (0..len).map(|_| rng.gen()).collect()
+    }));
+    msgs
+}
+
+// ---------------------------------------------
+// Differential tests for bls12-381
+// ---------------------------------------------
+
+#[test]
+fn bls12_381_pairing() {
+    new_test_ext().execute_with(|| {
+        for (a, b) in pairing_cases::<ark_bls12_381::Bls12_381>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_381_pairing(
+                    RuntimeOrigin::none(),
+                    a.clone(),
+                    b.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bls12_381_multi_pairing() {
+    new_test_ext().execute_with(|| {
+        for (a, b) in multi_pairing_cases::<ark_bls12_381::Bls12_381>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_381_multi_pairing(
+                    RuntimeOrigin::none(),
+                    a.clone(),
+                    b.clone(),
+                    false,
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bls12_381_multi_miller_loop() {
+    new_test_ext().execute_with(|| {
+        for (a, b) in multi_pairing_cases::<ark_bls12_381::Bls12_381>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_381_multi_miller_loop(
+                    RuntimeOrigin::none(),
+                    a.clone(),
+                    b.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bls12_381_final_exponentiation() {
+    new_test_ext().execute_with(|| {
+        for target in final_exponentiation_cases::<ark_bls12_381::Bls12_381>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_381_final_exponentiation(
+                    RuntimeOrigin::none(),
+                    target.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bls12_381_msm_g1() {
+    new_test_ext().execute_with(|| {
+        for (bases, scalars) in msm_cases::<ark_bls12_381::G1Affine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_381_msm_g1(
+                    RuntimeOrigin::none(),
+                    bases.clone(),
+                    scalars.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bls12_381_mul_projective_g1() {
+    new_test_ext().execute_with(|| {
+        for (base, scalar) in mul_projective_cases::<ark_bls12_381::G1Affine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_381_mul_projective_g1(
+                    RuntimeOrigin::none(),
+                    base.clone(),
+                    scalar.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bls12_381_mul_affine_g1() {
+    new_test_ext().execute_with(|| {
+        for (base, scalar) in mul_affine_cases::<ark_bls12_381::G1Affine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_381_mul_affine_g1(
+                    RuntimeOrigin::none(),
+                    base.clone(),
+                    scalar.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bls12_381_msm_g2() {
+    new_test_ext().execute_with(|| {
+        for (bases, scalars) in msm_cases::<ark_bls12_381::G2Affine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_381_msm_g2(
+                    RuntimeOrigin::none(),
+                    bases.clone(),
+                    scalars.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bls12_381_mul_projective_g2() {
+    new_test_ext().execute_with(|| {
+        for (base, scalar) in mul_projective_cases::<ark_bls12_381::G2Affine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_381_mul_projective_g2(
+                    RuntimeOrigin::none(),
+                    base.clone(),
+                    scalar.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bls12_381_mul_affine_g2() {
+    new_test_ext().execute_with(|| {
+        for (base, scalar) in mul_affine_cases::<ark_bls12_381::G2Affine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_381_mul_affine_g2(
+                    RuntimeOrigin::none(),
+                    base.clone(),
+                    scalar.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bls12_381_hash_to_g1() {
+    new_test_ext().execute_with(|| {
+        for msg in hash_to_curve_cases() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_381_hash_to_g1(
+                    RuntimeOrigin::none(),
+                    HASH_DST.to_vec(),
+                    msg.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bls12_381_hash_to_g2() {
+    new_test_ext().execute_with(|| {
+        for msg in hash_to_curve_cases() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_381_hash_to_g2(
+                    RuntimeOrigin::none(),
+                    HASH_DST.to_vec(),
+                    msg.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+// ---------------------------------------------
+// Differential tests for bls12-377
+// ---------------------------------------------
+
+#[test]
+fn bls12_377_pairing() {
+    new_test_ext().execute_with(|| {
+        for (a, b) in pairing_cases::<ark_bls12_377::Bls12_377>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_377_pairing(
+                    RuntimeOrigin::none(),
+                    a.clone(),
+                    b.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bls12_377_multi_pairing() {
+    new_test_ext().execute_with(|| {
+        for (a, b) in multi_pairing_cases::<ark_bls12_377::Bls12_377>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_377_multi_pairing(
+                    RuntimeOrigin::none(),
+                    a.clone(),
+                    b.clone(),
+                    false,
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bls12_377_multi_miller_loop() {
+    new_test_ext().execute_with(|| {
+        for (a, b) in multi_pairing_cases::<ark_bls12_377::Bls12_377>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_377_multi_miller_loop(
+                    RuntimeOrigin::none(),
+                    a.clone(),
+                    b.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bls12_377_final_exponentiation() {
+    new_test_ext().execute_with(|| {
+        for target in final_exponentiation_cases::<ark_bls12_377::Bls12_377>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_377_final_exponentiation(
+                    RuntimeOrigin::none(),
+                    target.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bls12_377_msm_g1() {
+    new_test_ext().execute_with(|| {
+        for (bases, scalars) in msm_cases::<ark_bls12_377::G1Affine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_377_msm_g1(
+                    RuntimeOrigin::none(),
+                    bases.clone(),
+                    scalars.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bls12_377_mul_projective_g1() {
+    new_test_ext().execute_with(|| {
+        for (base, scalar) in mul_projective_cases::<ark_bls12_377::G1Affine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_377_mul_projective_g1(
+                    RuntimeOrigin::none(),
+                    base.clone(),
+                    scalar.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bls12_377_mul_affine_g1() {
+    new_test_ext().execute_with(|| {
+        for (base, scalar) in mul_affine_cases::<ark_bls12_377::G1Affine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_377_mul_affine_g1(
+                    RuntimeOrigin::none(),
+                    base.clone(),
+                    scalar.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bls12_377_msm_g2() {
+    new_test_ext().execute_with(|| {
+        for (bases, scalars) in msm_cases::<ark_bls12_377::G2Affine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_377_msm_g2(
+                    RuntimeOrigin::none(),
+                    bases.clone(),
+                    scalars.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bls12_377_mul_projective_g2() {
+    new_test_ext().execute_with(|| {
+        for (base, scalar) in mul_projective_cases::<ark_bls12_377::G2Affine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_377_mul_projective_g2(
+                    RuntimeOrigin::none(),
+                    base.clone(),
+                    scalar.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bls12_377_mul_affine_g2() {
+    new_test_ext().execute_with(|| {
+        for (base, scalar) in mul_affine_cases::<ark_bls12_377::G2Affine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bls12_377_mul_affine_g2(
+                    RuntimeOrigin::none(),
+                    base.clone(),
+                    scalar.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+// ---------------------------------------------
+// Differential tests for bw6-761
+// ---------------------------------------------
+
+#[test]
+fn bw6_761_pairing() {
+    new_test_ext().execute_with(|| {
+        for (a, b) in pairing_cases::<ark_bw6_761::BW6_761>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bw6_761_pairing(
+                    RuntimeOrigin::none(),
+                    a.clone(),
+                    b.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bw6_761_multi_pairing() {
+    new_test_ext().execute_with(|| {
+        for (a, b) in multi_pairing_cases::<ark_bw6_761::BW6_761>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bw6_761_multi_pairing(
+                    RuntimeOrigin::none(),
+                    a.clone(),
+                    b.clone(),
+                    false,
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bw6_761_multi_miller_loop() {
+    new_test_ext().execute_with(|| {
+        for (a, b) in multi_pairing_cases::<ark_bw6_761::BW6_761>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bw6_761_multi_miller_loop(
+                    RuntimeOrigin::none(),
+                    a.clone(),
+                    b.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bw6_761_final_exponentiation() {
+    new_test_ext().execute_with(|| {
+        for target in final_exponentiation_cases::<ark_bw6_761::BW6_761>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bw6_761_final_exponentiation(
+                    RuntimeOrigin::none(),
+                    target.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bw6_761_msm_g1() {
+    new_test_ext().execute_with(|| {
+        for (bases, scalars) in msm_cases::<ark_bw6_761::G1Affine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bw6_761_msm_g1(
+                    RuntimeOrigin::none(),
+                    bases.clone(),
+                    scalars.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bw6_761_mul_projective_g1() {
+    new_test_ext().execute_with(|| {
+        for (base, scalar) in mul_projective_cases::<ark_bw6_761::G1Affine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bw6_761_mul_projective_g1(
+                    RuntimeOrigin::none(),
+                    base.clone(),
+                    scalar.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bw6_761_mul_affine_g1() {
+    new_test_ext().execute_with(|| {
+        for (base, scalar) in mul_affine_cases::<ark_bw6_761::G1Affine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bw6_761_mul_affine_g1(
+                    RuntimeOrigin::none(),
+                    base.clone(),
+                    scalar.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bw6_761_msm_g2() {
+    new_test_ext().execute_with(|| {
+        for (bases, scalars) in msm_cases::<ark_bw6_761::G2Affine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bw6_761_msm_g2(
+                    RuntimeOrigin::none(),
+                    bases.clone(),
+                    scalars.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bw6_761_mul_projective_g2() {
+    new_test_ext().execute_with(|| {
+        for (base, scalar) in mul_projective_cases::<ark_bw6_761::G2Affine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bw6_761_mul_projective_g2(
+                    RuntimeOrigin::none(),
+                    base.clone(),
+                    scalar.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn bw6_761_mul_affine_g2() {
+    new_test_ext().execute_with(|| {
+        for (base, scalar) in mul_affine_cases::<ark_bw6_761::G2Affine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::bw6_761_mul_affine_g2(
+                    RuntimeOrigin::none(),
+                    base.clone(),
+                    scalar.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+// ---------------------------------------------
+// Differential tests for ed-on-bls12-377
+// ---------------------------------------------
+
+#[test]
+fn ed_on_bls12_377_msm_te() {
+    new_test_ext().execute_with(|| {
+        for (bases, scalars) in msm_cases::<ark_ed_on_bls12_377::EdwardsAffine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::ed_on_bls12_377_msm_te(
+                    RuntimeOrigin::none(),
+                    bases.clone(),
+                    scalars.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn ed_on_bls12_377_mul_projective_te() {
+    new_test_ext().execute_with(|| {
+        for (base, scalar) in mul_projective_cases::<ark_ed_on_bls12_377::EdwardsAffine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::ed_on_bls12_377_mul_projective_te(
+                    RuntimeOrigin::none(),
+                    base.clone(),
+                    scalar.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn ed_on_bls12_377_mul_affine_te() {
+    new_test_ext().execute_with(|| {
+        for (base, scalar) in mul_affine_cases::<ark_ed_on_bls12_377::EdwardsAffine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::ed_on_bls12_377_mul_affine_te(
+                    RuntimeOrigin::none(),
+                    base.clone(),
+                    scalar.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+// ---------------------------------------------
+// Differential tests for ed-on-bls12-381-bandersnatch
+// ---------------------------------------------
+
+#[test]
+fn ed_on_bls12_381_bandersnatch_msm_sw() {
+    new_test_ext().execute_with(|| {
+        for (bases, scalars) in msm_cases::<ark_ed_on_bls12_381_bandersnatch::SWAffine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::ed_on_bls12_381_bandersnatch_msm_sw(
+                    RuntimeOrigin::none(),
+                    bases.clone(),
+                    scalars.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn ed_on_bls12_381_bandersnatch_mul_projective_sw() {
+    new_test_ext().execute_with(|| {
+        for (base, scalar) in mul_projective_cases::<ark_ed_on_bls12_381_bandersnatch::SWAffine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::ed_on_bls12_381_bandersnatch_mul_projective_sw(
+                    RuntimeOrigin::none(),
+                    base.clone(),
+                    scalar.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn ed_on_bls12_381_bandersnatch_mul_affine_sw() {
+    new_test_ext().execute_with(|| {
+        for (base, scalar) in mul_affine_cases::<ark_ed_on_bls12_381_bandersnatch::SWAffine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::ed_on_bls12_381_bandersnatch_mul_affine_sw(
+                    RuntimeOrigin::none(),
+                    base.clone(),
+                    scalar.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn ed_on_bls12_381_bandersnatch_msm_te() {
+    new_test_ext().execute_with(|| {
+        for (bases, scalars) in msm_cases::<ark_ed_on_bls12_381_bandersnatch::EdwardsAffine>() {
+            assert_same_output(|optimized| {
+                ArkHostcalls::ed_on_bls12_381_bandersnatch_msm_te(
+                    RuntimeOrigin::none(),
+                    bases.clone(),
+                    scalars.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn ed_on_bls12_381_bandersnatch_mul_projective_te() {
+    new_test_ext().execute_with(|| {
+        for (base, scalar) in
+            mul_projective_cases::<ark_ed_on_bls12_381_bandersnatch::EdwardsAffine>()
+        {
+            assert_same_output(|optimized| {
+                ArkHostcalls::ed_on_bls12_381_bandersnatch_mul_projective_te(
+                    RuntimeOrigin::none(),
+                    base.clone(),
+                    scalar.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+#[test]
+fn ed_on_bls12_381_bandersnatch_mul_affine_te() {
+    new_test_ext().execute_with(|| {
+        for (base, scalar) in mul_affine_cases::<ark_ed_on_bls12_381_bandersnatch::EdwardsAffine>()
+        {
+            assert_same_output(|optimized| {
+                ArkHostcalls::ed_on_bls12_381_bandersnatch_mul_affine_te(
+                    RuntimeOrigin::none(),
+                    base.clone(),
+                    scalar.clone(),
+                    optimized,
+                )
+            });
+        }
+    });
+}
+
+// ---------------------------------------------
+// Differential tests for fixed base MSM
+// ---------------------------------------------
+
+#[test]
+fn bls12_381_msm_fixed_base_g1() {
+    let rng = &mut rng();
+    let bases: Vec<ark_bls12_381::G1Affine> = random(rng, MAX_MSM_LEN);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::register_base_set(
+            RuntimeOrigin::root(),
+            0,
+            Curve::Bls12_381G1,
+            ArkScale::from(bases).encode(),
+        ));
+        for len in [0, 1, MAX_MSM_LEN / 2, MAX_MSM_LEN] {
+            let scalars: Vec<ark_bls12_381::Fr> = random(rng, len);
+            let scalars = ArkScale::from(scalars).encode();
+            assert_same_output(|optimized| {
+                ArkHostcalls::msm_fixed_base(RuntimeOrigin::none(), 0, scalars.clone(), optimized)
+            });
+        }
+    });
+}
@@ -1,13 +1,18 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(test)]
+mod differential_tests;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
+mod property_tests;
+#[cfg(test)]
 mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod te_sw;
 mod utils;
 mod weights;
 
@@ -15,16 +20,27 @@ use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::*;
 
 use ark_ec::{
-    pairing::Pairing,
+    hashing::{curve_maps::wb::WBConfig, HashToCurve},
+    pairing::{MillerLoopOutput, Pairing},
     short_weierstrass::{Affine as SWAffine, Projective as SWProjective, SWCurveConfig},
     twisted_edwards::{Affine as TEAffine, Projective as TEProjective, TECurveConfig},
-    AffineRepr,
+    AffineRepr, CurveConfig, CurveGroup,
+};
+use ark_ff::PrimeField;
+use ark_scale::{
+    ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate},
+    hazmat::ArkScaleProjective,
+    scale::{Decode, Encode},
+};
+use ark_std::{vec::Vec, Zero};
+use pallet_ark_common::{
+    decode_vec, encoded_vec_len, hashing::HasherFor, is_optimized, validation,
 };
-use ark_scale::{hazmat::ArkScaleProjective, scale::Decode};
-use ark_std::vec::Vec;
 
+pub use pallet_ark_common::{ArkBackend, Arkworks, BackendSwitch, ConfigBackend, Substrate};
 pub use sp_crypto_ec_utils::{
-    bls12_381 as sub_bls12_381, ed_on_bls12_377 as sub_ed_on_bls12_377,
+    bls12_377 as sub_bls12_377, bls12_381 as sub_bls12_381, bw6_761 as sub_bw6_761,
+    ed_on_bls12_377 as sub_ed_on_bls12_377,
     ed_on_bls12_381_bandersnatch as sub_ed_on_bls12_381_bandersnatch,
 };
 
@@ -32,51 +48,154 @@ pub type ScalarFieldFor<AffineT> = <AffineT as AffineRepr>::ScalarField;
 
 type ArkScale<T> = ark_scale::ArkScale<T>;
 
+/// Curve group selector.
+#[derive(
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum Curve {
+    Bls12_381G1,
+    Bls12_381G2,
+    Bls12_377G1,
+    Bls12_377G2,
+    Bw6_761G1,
+    Bw6_761G2,
+    EdOnBls12_377Te,
+    EdOnBls12_381BandersnatchSw,
+    EdOnBls12_381BandersnatchTe,
+}
+
+/// Pairing friendly curve selector for operations evaluated outside of dispatch.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Encode, Decode, DecodeWithMemTracking, TypeInfo)]
+pub enum PairingCurve {
+    Bls12_381,
+    Bls12_377,
+    Bw6_761,
+}
+
+/// Identifier of a registered base set.
+pub type BaseSetId = u32;
+
+/// Number of bases stored in a single base set page.
+const BASE_SET_PAGE_SIZE: usize = 1 << 5;
+
+/// Largest uncompressed affine point among the supported curve groups (BW6-761).
+const MAX_UNCOMPRESSED_POINT_SIZE: usize = 192;
+
+/// Uncompressed ArkScale encoding of a vector of up to `BASE_SET_PAGE_SIZE` bases.
+pub type BaseSetPage =
+    BoundedVec<u8, ConstU32<{ (8 + BASE_SET_PAGE_SIZE * MAX_UNCOMPRESSED_POINT_SIZE) as u32 }>>;
+
+/// Maximum length of a hash to curve domain separation tag, as set by RFC 9380.
+pub const MAX_DST_LEN: u32 = 255;
+
+/// Domain separation tag of the hash to curve calls.
+pub type HashDst = BoundedVec<u8, ConstU32<MAX_DST_LEN>>;
+
+/// Registered base set metadata.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct BaseSetInfo {
+    /// Curve group of the bases.
+    pub curve: Curve,
+    /// Number of bases.
+    pub len: u32,
+}
+
 pub use pallet::*;
 pub use weights::*;
 
-const DEFAULT_WEIGHT: u64 = 10_000;
+/// Number of MSM items accounted for by the call weight.
+///
+/// Inputs longer than `Config::MaxMsmLen` are rejected before any work is done.
+fn msm_len<T: Config>(bases: &[u8]) -> u32 {
+    encoded_vec_len(bases).min(T::MaxMsmLen::get())
+}
+
+/// Number of pairs accounted for by the call weight.
+///
+/// Inputs longer than `Config::MaxPairingLen` are rejected before any work is done.
+fn pairing_len<T: Config>(a: &[u8]) -> u32 {
+    encoded_vec_len(a).min(T::MaxPairingLen::get())
+}
 
-fn msm_sw<C: SWCurveConfig>(bases: Vec<u8>, scalars: Vec<u8>) {
-    let bases = ArkScale::<Vec<SWAffine<C>>>::decode(&mut bases.as_slice()).unwrap();
-    let scalars = ArkScale::<Vec<C::ScalarField>>::decode(&mut scalars.as_slice()).unwrap();
-    let _ = C::msm(&bases.0, &scalars.0).unwrap();
+/// Number of scalar words accounted for by the call weight.
+///
+/// Scalars longer than `Config::MaxScalarWords` are rejected before any work is done.
+fn scalar_words<T: Config>(scalar: &[u8]) -> u32 {
+    encoded_vec_len(scalar).min(T::MaxScalarWords::get())
 }
 
-fn mul_projective_sw<C: SWCurveConfig>(base: Vec<u8>, scalar: Vec<u8>) {
-    let base = ArkScaleProjective::<SWProjective<C>>::decode(&mut base.as_slice()).unwrap();
-    let scalar = ArkScale::<Vec<u64>>::decode(&mut scalar.as_slice()).unwrap();
-    let _ = C::mul_projective(&base.0, &scalar.0);
+/// Number of 64-bit words of a little-endian big integer scalar accounted for by the call weight.
+///
+/// Scalars longer than `Config::MaxScalarWords` words are rejected before any work is done.
+fn bigint_words<T: Config>(scalar: &[u8]) -> u32 {
+    scalar
+        .len()
+        .div_ceil(8)
+        .min(T::MaxScalarWords::get() as usize) as u32
 }
 
-fn mul_affine_sw<C: SWCurveConfig>(base: Vec<u8>, scalar: Vec<u8>) {
-    let base = ArkScale::<SWAffine<C>>::decode(&mut base.as_slice()).unwrap();
-    let scalar = ArkScale::<Vec<u64>>::decode(&mut scalar.as_slice()).unwrap();
-    let _ = C::mul_affine(&base.0, &scalar.0);
+/// Number of 64-bit words of the scalar field elements of `curve`.
+fn scalar_field_words(curve: Curve) -> u32 {
+    fn words<F: PrimeField>() -> u32 {
+        F::BigInt::NUM_LIMBS as u32
+    }
+    use Curve::*;
+    match curve {
+        Bls12_381G1 | Bls12_381G2 => words::<ark_bls12_381::Fr>(),
+        Bls12_377G1 | Bls12_377G2 => words::<ark_bls12_377::Fr>(),
+        Bw6_761G1 | Bw6_761G2 => words::<ark_bw6_761::Fr>(),
+        EdOnBls12_377Te => words::<ark_ed_on_bls12_377::Fr>(),
+        EdOnBls12_381BandersnatchSw | EdOnBls12_381BandersnatchTe => {
+            words::<ark_ed_on_bls12_381_bandersnatch::Fr>()
+        }
+    }
 }
 
-fn msm_te<C: TECurveConfig>(bases: Vec<u8>, scalars: Vec<u8>) {
-    let bases = ArkScale::<Vec<TEAffine<C>>>::decode(&mut bases.as_slice()).unwrap();
-    let scalars = ArkScale::<Vec<C::ScalarField>>::decode(&mut scalars.as_slice()).unwrap();
-    let _ = C::msm(&bases.0, &scalars.0).unwrap();
+/// Little-endian 64-bit words of a big integer given by its little-endian bytes.
+///
+/// The last word is zero padded, as the most significant one.
+fn bigint_limbs(bytes: &[u8]) -> Vec<u64> {
+    bytes
+        .chunks(8)
+        .map(|chunk| {
+            let mut word = [0; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            u64::from_le_bytes(word)
+        })
+        .collect()
 }
 
-fn mul_projective_te<C: TECurveConfig>(base: Vec<u8>, scalar: Vec<u8>) {
-    let base = ArkScaleProjective::<TEProjective<C>>::decode(&mut base.as_slice()).unwrap();
-    let scalar = ArkScale::<Vec<u64>>::decode(&mut scalar.as_slice()).unwrap();
-    let _ = C::mul_projective(&base.0, &scalar.0);
+/// Scalar of the `mul_scalar_field` and `mul_bigint` calls.
+enum MulScalar {
+    /// ArkScale encoded scalar field element.
+    FieldElement(Vec<u8>),
+    /// Little-endian bytes of a big integer.
+    BigInt(Vec<u8>),
 }
 
-fn mul_affine_te<C: TECurveConfig>(base: Vec<u8>, scalar: Vec<u8>) {
-    let base = ArkScale::<TEAffine<C>>::decode(&mut base.as_slice()).unwrap();
-    let scalar = ArkScale::<Vec<u64>>::decode(&mut scalar.as_slice()).unwrap();
-    let _ = C::mul_affine(&base.0, &scalar.0);
+/// Number of pages of a base set with `len` bases.
+fn base_set_pages(len: u32) -> u32 {
+    len.div_ceil(BASE_SET_PAGE_SIZE as u32)
 }
 
-fn pairing<P: Pairing>(a: Vec<u8>, b: Vec<u8>) {
-    let a = ArkScale::<P::G1Affine>::decode(&mut a.as_slice()).unwrap();
-    let b = ArkScale::<P::G2Affine>::decode(&mut b.as_slice()).unwrap();
-    let _ = P::multi_pairing([a.0], [b.0]);
+/// Decode an ArkScale encoded value.
+///
+/// Points are expected in compressed form, decompression requires a square root
+/// in the base field.
+///
+/// With `Validate::Yes` points are checked to be on the curve and in the prime order
+/// subgroup, using the curve config of `U` (thus host calls backed if possible).
+fn decode<U: CanonicalDeserialize>(data: &[u8], validate: Validate) -> Option<U> {
+    U::deserialize_with_mode(data, Compress::Yes, validate).ok()
 }
 
 #[frame_support::pallet]
@@ -88,11 +207,110 @@ pub mod pallet {
     pub struct Pallet<T>(_);
 
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> {
+        /// Keep a copy of the last computed output in the `LastResult` storage item.
+        #[pallet::constant]
+        type StoreLastResult: Get<bool>;
+
+        /// Maximum number of bases and scalars of a multi scalar multiplication.
+        ///
+        /// Also bounds the number of points of batch conversions and of registered
+        /// base sets.
+        #[pallet::constant]
+        type MaxMsmLen: Get<u32>;
+
+        /// Maximum number of pairs of a multi pairing or multi Miller loop.
+        #[pallet::constant]
+        type MaxPairingLen: Get<u32>;
+
+        /// Maximum number of 64-bit words of a scalar multiplication scalar.
+        #[pallet::constant]
+        type MaxScalarWords: Get<u32>;
+
+        /// Maximum length in bytes of a message hashed to the curve.
+        #[pallet::constant]
+        type MaxHashMsgLen: Get<u32>;
+
+        /// Check that decoded points are on the curve and in the prime order subgroup.
+        ///
+        /// Checks are performed using the curve config of the selected backend.
+        #[pallet::constant]
+        type ValidatePoints: Get<bool>;
+
+        /// Backend of the curve operations.
+        ///
+        /// Ignored with the `comparison-mode` feature, where the backend is selected per call.
+        type Backend: ArkBackend;
+
+        /// Origin allowed to register and remove base sets.
+        type BaseSetOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         /// Extrinsic weights
         type WeightInfo: WeightInfo;
     }
 
+    /// ArkScale encoded output of the last executed operation.
+    ///
+    /// Written only if `Config::StoreLastResult` is set.
+    #[pallet::storage]
+    #[pallet::unbounded]
+    pub type LastResult<T: Config> = StorageValue<_, Vec<u8>>;
+
+    /// Registered base sets for `msm_fixed_base`.
+    #[pallet::storage]
+    pub type BaseSets<T: Config> = StorageMap<_, Twox64Concat, BaseSetId, BaseSetInfo>;
+
+    /// Pages of the registered base sets.
+    ///
+    /// Bases are validated on registration and stored uncompressed.
+    #[pallet::storage]
+    pub type BaseSetPages<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, BaseSetId, Twox64Concat, u32, BaseSetPage>;
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Bases can't be decoded.
+        InvalidBases,
+        /// Scalars can't be decoded.
+        InvalidScalars,
+        /// Bases and scalars count mismatch.
+        LengthMismatch,
+        /// Point can't be decoded.
+        InvalidPoint,
+        /// Pairing product is not the identity.
+        PairingCheckFailed,
+        /// Target field element can't be decoded or is not invertible.
+        InvalidTarget,
+        /// Message can't be hashed to the curve.
+        HashToCurveFailed,
+        /// Input length exceeds the configured maximum.
+        InputTooLarge,
+        /// Base set identifier is already registered.
+        BaseSetExists,
+        /// Base set identifier is not registered.
+        UnknownBaseSet,
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// An operation has been executed.
+        ///
+        /// Output points are ArkScale encoded in compressed affine form, with the exception of
+        /// `mul_projective_*` outputs which use the same projective encoding as the input.
+        /// Pairing outputs are ArkScale encoded target group elements, while Miller loop
+        /// outputs are ArkScale encoded target field elements.
+        Computed { output: Vec<u8> },
+        /// A base set has been registered.
+        BaseSetRegistered {
+            set_id: BaseSetId,
+            curve: Curve,
+            len: u32,
+        },
+        /// A base set has been removed.
+        BaseSetRemoved { set_id: BaseSetId },
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         // ---------------------------------------------
@@ -100,114 +318,690 @@ pub mod pallet {
         // ---------------------------------------------
 
         #[pallet::call_index(10)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_381_pairing()
+        } else {
+            T::WeightInfo::ark_bls12_381_pairing()
+        })]
         pub fn bls12_381_pairing(
             _: OriginFor<T>,
             a: Vec<u8>,
             b: Vec<u8>,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
-                pairing::<sub_bls12_381::Bls12_381>(a, b);
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::pairing::<sub_bls12_381::Bls12_381>(a, b)?
             } else {
-                pairing::<ark_bls12_381::Bls12_381>(a, b);
-            }
+                Self::pairing::<ark_bls12_381::Bls12_381>(a, b)?
+            };
+            Self::deposit_result(output);
             Ok(())
         }
 
         #[pallet::call_index(11)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_381_msm_g1(msm_len::<T>(bases))
+        } else {
+            T::WeightInfo::ark_bls12_381_msm_g1(msm_len::<T>(bases))
+        })]
         pub fn bls12_381_msm_g1(
             _: OriginFor<T>,
             bases: Vec<u8>,
             scalars: Vec<u8>,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
-                msm_sw::<sub_bls12_381::g1::Config>(bases, scalars);
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::msm_sw::<sub_bls12_381::g1::Config>(bases, scalars)?
             } else {
-                msm_sw::<ark_bls12_381::g1::Config>(bases, scalars);
-            }
+                Self::msm_sw::<ark_bls12_381::g1::Config>(bases, scalars)?
+            };
+            Self::deposit_result(output);
             Ok(())
         }
 
         #[pallet::call_index(12)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_381_mul_projective_g1(scalar_words::<T>(scalar))
+        } else {
+            T::WeightInfo::ark_bls12_381_mul_projective_g1(scalar_words::<T>(scalar))
+        })]
         pub fn bls12_381_mul_projective_g1(
             _: OriginFor<T>,
             base: Vec<u8>,
             scalar: Vec<u8>,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
-                mul_projective_sw::<sub_bls12_381::g1::Config>(base, scalar);
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::mul_projective_sw::<sub_bls12_381::g1::Config>(base, scalar)?
             } else {
-                mul_projective_sw::<ark_bls12_381::g1::Config>(base, scalar);
-            }
+                Self::mul_projective_sw::<ark_bls12_381::g1::Config>(base, scalar)?
+            };
+            Self::deposit_result(output);
             Ok(())
         }
 
         #[pallet::call_index(13)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_381_mul_affine_g1(scalar_words::<T>(scalar))
+        } else {
+            T::WeightInfo::ark_bls12_381_mul_affine_g1(scalar_words::<T>(scalar))
+        })]
         pub fn bls12_381_mul_affine_g1(
             _: OriginFor<T>,
             base: Vec<u8>,
             scalar: Vec<u8>,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
-                mul_affine_sw::<sub_bls12_381::g1::Config>(base, scalar);
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::mul_affine_sw::<sub_bls12_381::g1::Config>(base, scalar)?
             } else {
-                mul_affine_sw::<ark_bls12_381::g1::Config>(base, scalar);
-            }
+                Self::mul_affine_sw::<ark_bls12_381::g1::Config>(base, scalar)?
+            };
+            Self::deposit_result(output);
             Ok(())
         }
 
         #[pallet::call_index(14)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_381_msm_g2(msm_len::<T>(bases))
+        } else {
+            T::WeightInfo::ark_bls12_381_msm_g2(msm_len::<T>(bases))
+        })]
         pub fn bls12_381_msm_g2(
             _: OriginFor<T>,
             bases: Vec<u8>,
             scalars: Vec<u8>,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
-                msm_sw::<sub_bls12_381::g2::Config>(bases, scalars);
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::msm_sw::<sub_bls12_381::g2::Config>(bases, scalars)?
             } else {
-                msm_sw::<ark_bls12_381::g2::Config>(bases, scalars);
-            }
+                Self::msm_sw::<ark_bls12_381::g2::Config>(bases, scalars)?
+            };
+            Self::deposit_result(output);
             Ok(())
         }
 
         #[pallet::call_index(15)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_381_mul_projective_g2(scalar_words::<T>(scalar))
+        } else {
+            T::WeightInfo::ark_bls12_381_mul_projective_g2(scalar_words::<T>(scalar))
+        })]
         pub fn bls12_381_mul_projective_g2(
             _: OriginFor<T>,
             base: Vec<u8>,
             scalar: Vec<u8>,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
-                mul_projective_sw::<sub_bls12_381::g2::Config>(base, scalar);
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::mul_projective_sw::<sub_bls12_381::g2::Config>(base, scalar)?
             } else {
-                mul_projective_sw::<ark_bls12_381::g2::Config>(base, scalar);
-            }
+                Self::mul_projective_sw::<ark_bls12_381::g2::Config>(base, scalar)?
+            };
+            Self::deposit_result(output);
             Ok(())
         }
 
         #[pallet::call_index(16)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_381_mul_affine_g2(scalar_words::<T>(scalar))
+        } else {
+            T::WeightInfo::ark_bls12_381_mul_affine_g2(scalar_words::<T>(scalar))
+        })]
         pub fn bls12_381_mul_affine_g2(
             _: OriginFor<T>,
             base: Vec<u8>,
             scalar: Vec<u8>,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
-                mul_affine_sw::<sub_bls12_381::g2::Config>(base, scalar);
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::mul_affine_sw::<sub_bls12_381::g2::Config>(base, scalar)?
             } else {
-                mul_affine_sw::<ark_bls12_381::g2::Config>(base, scalar);
-            }
+                Self::mul_affine_sw::<ark_bls12_381::g2::Config>(base, scalar)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(17)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_381_multi_pairing(pairing_len::<T>(a))
+        } else {
+            T::WeightInfo::ark_bls12_381_multi_pairing(pairing_len::<T>(a))
+        })]
+        pub fn bls12_381_multi_pairing(
+            _: OriginFor<T>,
+            a: Vec<u8>,
+            b: Vec<u8>,
+            check: bool,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::multi_pairing::<sub_bls12_381::Bls12_381>(a, b, check)?
+            } else {
+                Self::multi_pairing::<ark_bls12_381::Bls12_381>(a, b, check)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(18)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_381_multi_miller_loop(pairing_len::<T>(a))
+        } else {
+            T::WeightInfo::ark_bls12_381_multi_miller_loop(pairing_len::<T>(a))
+        })]
+        pub fn bls12_381_multi_miller_loop(
+            _: OriginFor<T>,
+            a: Vec<u8>,
+            b: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::multi_miller_loop::<sub_bls12_381::Bls12_381>(a, b)?
+            } else {
+                Self::multi_miller_loop::<ark_bls12_381::Bls12_381>(a, b)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(19)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_381_final_exponentiation()
+        } else {
+            T::WeightInfo::ark_bls12_381_final_exponentiation()
+        })]
+        pub fn bls12_381_final_exponentiation(
+            _: OriginFor<T>,
+            target: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::final_exponentiation::<sub_bls12_381::Bls12_381>(target)?
+            } else {
+                Self::final_exponentiation::<ark_bls12_381::Bls12_381>(target)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(50)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_381_hash_to_g1(msg.len() as u32)
+        } else {
+            T::WeightInfo::ark_bls12_381_hash_to_g1(msg.len() as u32)
+        })]
+        pub fn bls12_381_hash_to_g1(
+            _: OriginFor<T>,
+            dst: HashDst,
+            msg: BoundedVec<u8, T::MaxHashMsgLen>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::hash_to_curve::<ark_bls12_381::g1::Config, sub_bls12_381::g1::Config>(
+                    &dst, &msg,
+                )?
+            } else {
+                Self::hash_to_curve::<ark_bls12_381::g1::Config, ark_bls12_381::g1::Config>(
+                    &dst, &msg,
+                )?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(51)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_381_hash_to_g2(msg.len() as u32)
+        } else {
+            T::WeightInfo::ark_bls12_381_hash_to_g2(msg.len() as u32)
+        })]
+        pub fn bls12_381_hash_to_g2(
+            _: OriginFor<T>,
+            dst: HashDst,
+            msg: BoundedVec<u8, T::MaxHashMsgLen>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::hash_to_curve::<ark_bls12_381::g2::Config, sub_bls12_381::g2::Config>(
+                    &dst, &msg,
+                )?
+            } else {
+                Self::hash_to_curve::<ark_bls12_381::g2::Config, ark_bls12_381::g2::Config>(
+                    &dst, &msg,
+                )?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        // ---------------------------------------------
+        // Calls for bls12-377
+        // ---------------------------------------------
+
+        #[pallet::call_index(30)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_377_pairing()
+        } else {
+            T::WeightInfo::ark_bls12_377_pairing()
+        })]
+        pub fn bls12_377_pairing(
+            _: OriginFor<T>,
+            a: Vec<u8>,
+            b: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::pairing::<sub_bls12_377::Bls12_377>(a, b)?
+            } else {
+                Self::pairing::<ark_bls12_377::Bls12_377>(a, b)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(31)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_377_msm_g1(msm_len::<T>(bases))
+        } else {
+            T::WeightInfo::ark_bls12_377_msm_g1(msm_len::<T>(bases))
+        })]
+        pub fn bls12_377_msm_g1(
+            _: OriginFor<T>,
+            bases: Vec<u8>,
+            scalars: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::msm_sw::<sub_bls12_377::g1::Config>(bases, scalars)?
+            } else {
+                Self::msm_sw::<ark_bls12_377::g1::Config>(bases, scalars)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(32)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_377_mul_projective_g1(scalar_words::<T>(scalar))
+        } else {
+            T::WeightInfo::ark_bls12_377_mul_projective_g1(scalar_words::<T>(scalar))
+        })]
+        pub fn bls12_377_mul_projective_g1(
+            _: OriginFor<T>,
+            base: Vec<u8>,
+            scalar: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::mul_projective_sw::<sub_bls12_377::g1::Config>(base, scalar)?
+            } else {
+                Self::mul_projective_sw::<ark_bls12_377::g1::Config>(base, scalar)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(33)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_377_mul_affine_g1(scalar_words::<T>(scalar))
+        } else {
+            T::WeightInfo::ark_bls12_377_mul_affine_g1(scalar_words::<T>(scalar))
+        })]
+        pub fn bls12_377_mul_affine_g1(
+            _: OriginFor<T>,
+            base: Vec<u8>,
+            scalar: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::mul_affine_sw::<sub_bls12_377::g1::Config>(base, scalar)?
+            } else {
+                Self::mul_affine_sw::<ark_bls12_377::g1::Config>(base, scalar)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(34)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_377_msm_g2(msm_len::<T>(bases))
+        } else {
+            T::WeightInfo::ark_bls12_377_msm_g2(msm_len::<T>(bases))
+        })]
+        pub fn bls12_377_msm_g2(
+            _: OriginFor<T>,
+            bases: Vec<u8>,
+            scalars: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::msm_sw::<sub_bls12_377::g2::Config>(bases, scalars)?
+            } else {
+                Self::msm_sw::<ark_bls12_377::g2::Config>(bases, scalars)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(35)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_377_mul_projective_g2(scalar_words::<T>(scalar))
+        } else {
+            T::WeightInfo::ark_bls12_377_mul_projective_g2(scalar_words::<T>(scalar))
+        })]
+        pub fn bls12_377_mul_projective_g2(
+            _: OriginFor<T>,
+            base: Vec<u8>,
+            scalar: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::mul_projective_sw::<sub_bls12_377::g2::Config>(base, scalar)?
+            } else {
+                Self::mul_projective_sw::<ark_bls12_377::g2::Config>(base, scalar)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(36)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_377_mul_affine_g2(scalar_words::<T>(scalar))
+        } else {
+            T::WeightInfo::ark_bls12_377_mul_affine_g2(scalar_words::<T>(scalar))
+        })]
+        pub fn bls12_377_mul_affine_g2(
+            _: OriginFor<T>,
+            base: Vec<u8>,
+            scalar: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::mul_affine_sw::<sub_bls12_377::g2::Config>(base, scalar)?
+            } else {
+                Self::mul_affine_sw::<ark_bls12_377::g2::Config>(base, scalar)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(37)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_377_multi_pairing(pairing_len::<T>(a))
+        } else {
+            T::WeightInfo::ark_bls12_377_multi_pairing(pairing_len::<T>(a))
+        })]
+        pub fn bls12_377_multi_pairing(
+            _: OriginFor<T>,
+            a: Vec<u8>,
+            b: Vec<u8>,
+            check: bool,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::multi_pairing::<sub_bls12_377::Bls12_377>(a, b, check)?
+            } else {
+                Self::multi_pairing::<ark_bls12_377::Bls12_377>(a, b, check)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(38)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_377_multi_miller_loop(pairing_len::<T>(a))
+        } else {
+            T::WeightInfo::ark_bls12_377_multi_miller_loop(pairing_len::<T>(a))
+        })]
+        pub fn bls12_377_multi_miller_loop(
+            _: OriginFor<T>,
+            a: Vec<u8>,
+            b: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::multi_miller_loop::<sub_bls12_377::Bls12_377>(a, b)?
+            } else {
+                Self::multi_miller_loop::<ark_bls12_377::Bls12_377>(a, b)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(39)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bls12_377_final_exponentiation()
+        } else {
+            T::WeightInfo::ark_bls12_377_final_exponentiation()
+        })]
+        pub fn bls12_377_final_exponentiation(
+            _: OriginFor<T>,
+            target: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::final_exponentiation::<sub_bls12_377::Bls12_377>(target)?
+            } else {
+                Self::final_exponentiation::<ark_bls12_377::Bls12_377>(target)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        // ---------------------------------------------
+        // Calls for bw6-761
+        // ---------------------------------------------
+
+        #[pallet::call_index(40)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bw6_761_pairing()
+        } else {
+            T::WeightInfo::ark_bw6_761_pairing()
+        })]
+        pub fn bw6_761_pairing(
+            _: OriginFor<T>,
+            a: Vec<u8>,
+            b: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::pairing::<sub_bw6_761::BW6_761>(a, b)?
+            } else {
+                Self::pairing::<ark_bw6_761::BW6_761>(a, b)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(41)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bw6_761_msm_g1(msm_len::<T>(bases))
+        } else {
+            T::WeightInfo::ark_bw6_761_msm_g1(msm_len::<T>(bases))
+        })]
+        pub fn bw6_761_msm_g1(
+            _: OriginFor<T>,
+            bases: Vec<u8>,
+            scalars: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::msm_sw::<sub_bw6_761::g1::Config>(bases, scalars)?
+            } else {
+                Self::msm_sw::<ark_bw6_761::g1::Config>(bases, scalars)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(42)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bw6_761_mul_projective_g1(scalar_words::<T>(scalar))
+        } else {
+            T::WeightInfo::ark_bw6_761_mul_projective_g1(scalar_words::<T>(scalar))
+        })]
+        pub fn bw6_761_mul_projective_g1(
+            _: OriginFor<T>,
+            base: Vec<u8>,
+            scalar: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::mul_projective_sw::<sub_bw6_761::g1::Config>(base, scalar)?
+            } else {
+                Self::mul_projective_sw::<ark_bw6_761::g1::Config>(base, scalar)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(43)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bw6_761_mul_affine_g1(scalar_words::<T>(scalar))
+        } else {
+            T::WeightInfo::ark_bw6_761_mul_affine_g1(scalar_words::<T>(scalar))
+        })]
+        pub fn bw6_761_mul_affine_g1(
+            _: OriginFor<T>,
+            base: Vec<u8>,
+            scalar: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::mul_affine_sw::<sub_bw6_761::g1::Config>(base, scalar)?
+            } else {
+                Self::mul_affine_sw::<ark_bw6_761::g1::Config>(base, scalar)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(44)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bw6_761_msm_g2(msm_len::<T>(bases))
+        } else {
+            T::WeightInfo::ark_bw6_761_msm_g2(msm_len::<T>(bases))
+        })]
+        pub fn bw6_761_msm_g2(
+            _: OriginFor<T>,
+            bases: Vec<u8>,
+            scalars: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::msm_sw::<sub_bw6_761::g2::Config>(bases, scalars)?
+            } else {
+                Self::msm_sw::<ark_bw6_761::g2::Config>(bases, scalars)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(45)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bw6_761_mul_projective_g2(scalar_words::<T>(scalar))
+        } else {
+            T::WeightInfo::ark_bw6_761_mul_projective_g2(scalar_words::<T>(scalar))
+        })]
+        pub fn bw6_761_mul_projective_g2(
+            _: OriginFor<T>,
+            base: Vec<u8>,
+            scalar: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::mul_projective_sw::<sub_bw6_761::g2::Config>(base, scalar)?
+            } else {
+                Self::mul_projective_sw::<ark_bw6_761::g2::Config>(base, scalar)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(46)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bw6_761_mul_affine_g2(scalar_words::<T>(scalar))
+        } else {
+            T::WeightInfo::ark_bw6_761_mul_affine_g2(scalar_words::<T>(scalar))
+        })]
+        pub fn bw6_761_mul_affine_g2(
+            _: OriginFor<T>,
+            base: Vec<u8>,
+            scalar: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::mul_affine_sw::<sub_bw6_761::g2::Config>(base, scalar)?
+            } else {
+                Self::mul_affine_sw::<ark_bw6_761::g2::Config>(base, scalar)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(47)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bw6_761_multi_pairing(pairing_len::<T>(a))
+        } else {
+            T::WeightInfo::ark_bw6_761_multi_pairing(pairing_len::<T>(a))
+        })]
+        pub fn bw6_761_multi_pairing(
+            _: OriginFor<T>,
+            a: Vec<u8>,
+            b: Vec<u8>,
+            check: bool,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::multi_pairing::<sub_bw6_761::BW6_761>(a, b, check)?
+            } else {
+                Self::multi_pairing::<ark_bw6_761::BW6_761>(a, b, check)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(48)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bw6_761_multi_miller_loop(pairing_len::<T>(a))
+        } else {
+            T::WeightInfo::ark_bw6_761_multi_miller_loop(pairing_len::<T>(a))
+        })]
+        pub fn bw6_761_multi_miller_loop(
+            _: OriginFor<T>,
+            a: Vec<u8>,
+            b: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::multi_miller_loop::<sub_bw6_761::BW6_761>(a, b)?
+            } else {
+                Self::multi_miller_loop::<ark_bw6_761::BW6_761>(a, b)?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        #[pallet::call_index(49)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_bw6_761_final_exponentiation()
+        } else {
+            T::WeightInfo::ark_bw6_761_final_exponentiation()
+        })]
+        pub fn bw6_761_final_exponentiation(
+            _: OriginFor<T>,
+            target: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::final_exponentiation::<sub_bw6_761::BW6_761>(target)?
+            } else {
+                Self::final_exponentiation::<ark_bw6_761::BW6_761>(target)?
+            };
+            Self::deposit_result(output);
             Ok(())
         }
 
@@ -216,50 +1010,65 @@ pub mod pallet {
         // ---------------------------------------------
 
         #[pallet::call_index(20)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_ed_on_bls12_377_msm_te(msm_len::<T>(bases))
+        } else {
+            T::WeightInfo::ark_ed_on_bls12_377_msm_te(msm_len::<T>(bases))
+        })]
         pub fn ed_on_bls12_377_msm_te(
             _: OriginFor<T>,
             bases: Vec<u8>,
             scalars: Vec<u8>,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
-                msm_te::<sub_ed_on_bls12_377::EdwardsConfig>(bases, scalars)
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::msm_te::<sub_ed_on_bls12_377::EdwardsConfig>(bases, scalars)?
             } else {
-                msm_te::<ark_ed_on_bls12_377::EdwardsConfig>(bases, scalars)
-            }
+                Self::msm_te::<ark_ed_on_bls12_377::EdwardsConfig>(bases, scalars)?
+            };
+            Self::deposit_result(output);
             Ok(())
         }
 
         #[pallet::call_index(21)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_ed_on_bls12_377_mul_projective_te(scalar_words::<T>(scalar))
+        } else {
+            T::WeightInfo::ark_ed_on_bls12_377_mul_projective_te(scalar_words::<T>(scalar))
+        })]
         pub fn ed_on_bls12_377_mul_projective_te(
             _: OriginFor<T>,
             base: Vec<u8>,
             scalar: Vec<u8>,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
-                mul_projective_te::<sub_ed_on_bls12_377::EdwardsConfig>(base, scalar);
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::mul_projective_te::<sub_ed_on_bls12_377::EdwardsConfig>(base, scalar)?
             } else {
-                mul_projective_te::<ark_ed_on_bls12_377::EdwardsConfig>(base, scalar);
-            }
+                Self::mul_projective_te::<ark_ed_on_bls12_377::EdwardsConfig>(base, scalar)?
+            };
+            Self::deposit_result(output);
             Ok(())
         }
 
         #[pallet::call_index(22)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_ed_on_bls12_377_mul_affine_te(scalar_words::<T>(scalar))
+        } else {
+            T::WeightInfo::ark_ed_on_bls12_377_mul_affine_te(scalar_words::<T>(scalar))
+        })]
         pub fn ed_on_bls12_377_mul_affine_te(
             _: OriginFor<T>,
             base: Vec<u8>,
             scalar: Vec<u8>,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
-                mul_affine_te::<sub_ed_on_bls12_377::EdwardsConfig>(base, scalar);
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::mul_affine_te::<sub_ed_on_bls12_377::EdwardsConfig>(base, scalar)?
             } else {
-                mul_affine_te::<ark_ed_on_bls12_377::EdwardsConfig>(base, scalar);
-            }
+                Self::mul_affine_te::<ark_ed_on_bls12_377::EdwardsConfig>(base, scalar)?
+            };
+            Self::deposit_result(output);
             Ok(())
         }
 
@@ -270,101 +1079,1187 @@ pub mod pallet {
         // Short Weierstrass
 
         #[pallet::call_index(1)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_ed_on_bls12_381_bandersnatch_msm_sw(msm_len::<T>(bases))
+        } else {
+            T::WeightInfo::ark_ed_on_bls12_381_bandersnatch_msm_sw(msm_len::<T>(bases))
+        })]
         pub fn ed_on_bls12_381_bandersnatch_msm_sw(
             _: OriginFor<T>,
             bases: Vec<u8>,
             scalars: Vec<u8>,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
-                msm_sw::<sub_ed_on_bls12_381_bandersnatch::SWConfig>(bases, scalars)
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::msm_sw::<sub_ed_on_bls12_381_bandersnatch::SWConfig>(bases, scalars)?
             } else {
-                msm_sw::<ark_ed_on_bls12_381_bandersnatch::SWConfig>(bases, scalars)
-            }
+                Self::msm_sw::<ark_ed_on_bls12_381_bandersnatch::SWConfig>(bases, scalars)?
+            };
+            Self::deposit_result(output);
             Ok(())
         }
 
         #[pallet::call_index(2)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_ed_on_bls12_381_bandersnatch_mul_projective_sw(scalar_words::<T>(scalar))
+        } else {
+            T::WeightInfo::ark_ed_on_bls12_381_bandersnatch_mul_projective_sw(scalar_words::<T>(scalar))
+        })]
         pub fn ed_on_bls12_381_bandersnatch_mul_projective_sw(
             _: OriginFor<T>,
             base: Vec<u8>,
             scalar: Vec<u8>,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
-                mul_projective_sw::<sub_ed_on_bls12_381_bandersnatch::SWConfig>(base, scalar);
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::mul_projective_sw::<sub_ed_on_bls12_381_bandersnatch::SWConfig>(base, scalar)?
             } else {
-                mul_projective_sw::<ark_ed_on_bls12_381_bandersnatch::SWConfig>(base, scalar);
-            }
+                Self::mul_projective_sw::<ark_ed_on_bls12_381_bandersnatch::SWConfig>(base, scalar)?
+            };
+            Self::deposit_result(output);
             Ok(())
         }
 
         #[pallet::call_index(3)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_ed_on_bls12_381_bandersnatch_mul_affine_sw(scalar_words::<T>(scalar))
+        } else {
+            T::WeightInfo::ark_ed_on_bls12_381_bandersnatch_mul_affine_sw(scalar_words::<T>(scalar))
+        })]
         pub fn ed_on_bls12_381_bandersnatch_mul_affine_sw(
             _: OriginFor<T>,
             base: Vec<u8>,
             scalar: Vec<u8>,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
-                mul_affine_sw::<sub_ed_on_bls12_381_bandersnatch::SWConfig>(base, scalar);
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::mul_affine_sw::<sub_ed_on_bls12_381_bandersnatch::SWConfig>(base, scalar)?
             } else {
-                mul_affine_sw::<ark_ed_on_bls12_381_bandersnatch::SWConfig>(base, scalar);
-            }
+                Self::mul_affine_sw::<ark_ed_on_bls12_381_bandersnatch::SWConfig>(base, scalar)?
+            };
+            Self::deposit_result(output);
             Ok(())
         }
 
         // Twisted Edwards
 
         #[pallet::call_index(4)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_ed_on_bls12_381_bandersnatch_msm_te(msm_len::<T>(bases))
+        } else {
+            T::WeightInfo::ark_ed_on_bls12_381_bandersnatch_msm_te(msm_len::<T>(bases))
+        })]
         pub fn ed_on_bls12_381_bandersnatch_msm_te(
             _origin: OriginFor<T>,
             bases: Vec<u8>,
             scalars: Vec<u8>,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
-                msm_te::<sub_ed_on_bls12_381_bandersnatch::EdwardsConfig>(bases, scalars)
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::msm_te::<sub_ed_on_bls12_381_bandersnatch::EdwardsConfig>(bases, scalars)?
             } else {
-                msm_te::<ark_ed_on_bls12_381_bandersnatch::EdwardsConfig>(bases, scalars)
-            }
+                Self::msm_te::<ark_ed_on_bls12_381_bandersnatch::EdwardsConfig>(bases, scalars)?
+            };
+            Self::deposit_result(output);
             Ok(())
         }
 
         #[pallet::call_index(5)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_ed_on_bls12_381_bandersnatch_mul_projective_te(scalar_words::<T>(scalar))
+        } else {
+            T::WeightInfo::ark_ed_on_bls12_381_bandersnatch_mul_projective_te(scalar_words::<T>(scalar))
+        })]
         pub fn ed_on_bls12_381_bandersnatch_mul_projective_te(
             _: OriginFor<T>,
             base: Vec<u8>,
             scalar: Vec<u8>,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
-                mul_projective_te::<sub_ed_on_bls12_381_bandersnatch::EdwardsConfig>(base, scalar);
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::mul_projective_te::<sub_ed_on_bls12_381_bandersnatch::EdwardsConfig>(
+                    base, scalar,
+                )?
             } else {
-                mul_projective_te::<ark_ed_on_bls12_381_bandersnatch::EdwardsConfig>(base, scalar);
-            }
+                Self::mul_projective_te::<ark_ed_on_bls12_381_bandersnatch::EdwardsConfig>(
+                    base, scalar,
+                )?
+            };
+            Self::deposit_result(output);
             Ok(())
         }
 
         #[pallet::call_index(6)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_ed_on_bls12_381_bandersnatch_mul_affine_te(scalar_words::<T>(scalar))
+        } else {
+            T::WeightInfo::ark_ed_on_bls12_381_bandersnatch_mul_affine_te(scalar_words::<T>(scalar))
+        })]
         pub fn ed_on_bls12_381_bandersnatch_mul_affine_te(
             _: OriginFor<T>,
             base: Vec<u8>,
             scalar: Vec<u8>,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
-                mul_affine_te::<sub_ed_on_bls12_381_bandersnatch::EdwardsConfig>(base, scalar);
+            let output = if is_optimized::<T::Backend>(&optimized) {
+                Self::mul_affine_te::<sub_ed_on_bls12_381_bandersnatch::EdwardsConfig>(
+                    base, scalar,
+                )?
             } else {
-                mul_affine_te::<ark_ed_on_bls12_381_bandersnatch::EdwardsConfig>(base, scalar);
-            }
+                Self::mul_affine_te::<ark_ed_on_bls12_381_bandersnatch::EdwardsConfig>(
+                    base, scalar,
+                )?
+            };
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        // ---------------------------------------------
+        // Conversions for ed-on-bls12-381-bandersnatch
+        //
+        // Conversions only involve base field arithmetic, thus there is
+        // nothing to offload to the host calls backend.
+        // ---------------------------------------------
+
+        /// Convert a Twisted Edwards point to Short Weierstrass.
+        #[pallet::call_index(60)]
+        #[pallet::weight(T::WeightInfo::ed_on_bls12_381_bandersnatch_te_to_sw())]
+        pub fn ed_on_bls12_381_bandersnatch_te_to_sw(
+            _: OriginFor<T>,
+            point: Vec<u8>,
+        ) -> DispatchResult {
+            let output =
+                Self::te_to_sw::<ark_ed_on_bls12_381_bandersnatch::BandersnatchConfig>(point)?;
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        /// Convert a Short Weierstrass point to Twisted Edwards.
+        #[pallet::call_index(61)]
+        #[pallet::weight(T::WeightInfo::ed_on_bls12_381_bandersnatch_sw_to_te())]
+        pub fn ed_on_bls12_381_bandersnatch_sw_to_te(
+            _: OriginFor<T>,
+            point: Vec<u8>,
+        ) -> DispatchResult {
+            let output =
+                Self::sw_to_te::<ark_ed_on_bls12_381_bandersnatch::BandersnatchConfig>(point)?;
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        /// Convert a vector of Twisted Edwards points to Short Weierstrass.
+        #[pallet::call_index(62)]
+        #[pallet::weight(T::WeightInfo::ed_on_bls12_381_bandersnatch_te_to_sw_batch(
+            msm_len::<T>(points)
+        ))]
+        pub fn ed_on_bls12_381_bandersnatch_te_to_sw_batch(
+            _: OriginFor<T>,
+            points: Vec<u8>,
+        ) -> DispatchResult {
+            let output = Self::te_to_sw_batch::<
+                ark_ed_on_bls12_381_bandersnatch::BandersnatchConfig,
+            >(points)?;
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        /// Convert a vector of Short Weierstrass points to Twisted Edwards.
+        #[pallet::call_index(63)]
+        #[pallet::weight(T::WeightInfo::ed_on_bls12_381_bandersnatch_sw_to_te_batch(
+            msm_len::<T>(points)
+        ))]
+        pub fn ed_on_bls12_381_bandersnatch_sw_to_te_batch(
+            _: OriginFor<T>,
+            points: Vec<u8>,
+        ) -> DispatchResult {
+            let output = Self::sw_to_te_batch::<
+                ark_ed_on_bls12_381_bandersnatch::BandersnatchConfig,
+            >(points)?;
+            Self::deposit_result(output);
             Ok(())
         }
+
+        // ---------------------------------------------
+        // Fixed base multi scalar multiplication
+        // ---------------------------------------------
+
+        /// Register a set of bases for `msm_fixed_base`.
+        ///
+        /// Bases are encoded as for the `msm_*` calls. They are validated once and stored
+        /// uncompressed, thus later MSMs skip decompression and validation.
+        #[pallet::call_index(70)]
+        #[pallet::weight(Pallet::<T>::register_base_set_weight(*curve, msm_len::<T>(bases)))]
+        pub fn register_base_set(
+            origin: OriginFor<T>,
+            set_id: BaseSetId,
+            curve: Curve,
+            bases: Vec<u8>,
+        ) -> DispatchResult {
+            T::BaseSetOrigin::ensure_origin(origin)?;
+            ensure!(
+                !BaseSets::<T>::contains_key(set_id),
+                Error::<T>::BaseSetExists
+            );
+            ensure!(
+                encoded_vec_len(&bases) <= T::MaxMsmLen::get(),
+                Error::<T>::InputTooLarge
+            );
+            let len = Self::insert_base_set(set_id, curve, &bases)?;
+            Self::deposit_event(Event::BaseSetRegistered { set_id, curve, len });
+            Ok(())
+        }
+
+        /// Remove a registered base set.
+        #[pallet::call_index(71)]
+        #[pallet::weight(T::WeightInfo::remove_base_set(base_set_pages(T::MaxMsmLen::get())))]
+        pub fn remove_base_set(
+            origin: OriginFor<T>,
+            set_id: BaseSetId,
+        ) -> DispatchResultWithPostInfo {
+            T::BaseSetOrigin::ensure_origin(origin)?;
+            let info = BaseSets::<T>::take(set_id).ok_or(Error::<T>::UnknownBaseSet)?;
+            let _ = BaseSetPages::<T>::clear_prefix(set_id, u32::MAX, None);
+            Self::deposit_event(Event::BaseSetRemoved { set_id });
+            Ok(Some(T::WeightInfo::remove_base_set(base_set_pages(info.len))).into())
+        }
+
+        /// Multi scalar multiplication using the bases of a registered set.
+        ///
+        /// Scalars are encoded as for the `msm_*` calls and are paired with the first
+        /// bases of the set. Weight is charged for the most expensive curve group and
+        /// the excess is refunded.
+        #[pallet::call_index(72)]
+        #[pallet::weight(Pallet::<T>::msm_fixed_base_max_weight(
+            msm_len::<T>(scalars),
+            is_optimized::<T::Backend>(optimized)
+        ))]
+        pub fn msm_fixed_base(
+            _: OriginFor<T>,
+            set_id: BaseSetId,
+            scalars: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResultWithPostInfo {
+            let optimized = is_optimized::<T::Backend>(&optimized);
+            let info = BaseSets::<T>::get(set_id).ok_or(Error::<T>::UnknownBaseSet)?;
+            let weight = Self::msm_fixed_base_weight(info.curve, msm_len::<T>(&scalars), optimized);
+            let output = Self::msm_fixed_base_on(set_id, info, scalars, optimized)?;
+            Self::deposit_result(output);
+            Ok(Some(weight).into())
+        }
+
+        // ---------------------------------------------
+        // Scalar multiplication with alternative scalar encodings
+        //
+        // The `mul_*` calls of each curve take scalars as ArkScale encoded
+        // vectors of 64-bit words, least significant word first.
+        // ---------------------------------------------
+
+        /// Scalar multiplication of an affine point by a scalar field element.
+        ///
+        /// `base` and the output are encoded as for the `mul_affine_*` calls, while `scalar`
+        /// is an ArkScale encoded element of the scalar field of `curve`, as the scalars of
+        /// the `msm_*` calls. That is, the little-endian bytes of its canonical representative,
+        /// values not reduced modulo the field order are rejected.
+        #[pallet::call_index(80)]
+        #[pallet::weight(Pallet::<T>::mul_affine_weight(
+            *curve,
+            scalar_field_words(*curve),
+            is_optimized::<T::Backend>(optimized)
+        ))]
+        pub fn mul_scalar_field(
+            _: OriginFor<T>,
+            curve: Curve,
+            base: Vec<u8>,
+            scalar: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let scalar = MulScalar::FieldElement(scalar);
+            let output =
+                Self::mul_affine_on(curve, base, scalar, is_optimized::<T::Backend>(&optimized))?;
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        /// Scalar multiplication of an affine point by a big integer of arbitrary length.
+        ///
+        /// `base` and the output are encoded as for the `mul_affine_*` calls, while `scalar`
+        /// holds the little-endian bytes of a non-negative integer, up to `MaxScalarWords`
+        /// 64-bit words. The integer is not required to be reduced modulo the group order.
+        #[pallet::call_index(81)]
+        #[pallet::weight(Pallet::<T>::mul_affine_weight(
+            *curve,
+            bigint_words::<T>(scalar),
+            is_optimized::<T::Backend>(optimized)
+        ))]
+        pub fn mul_bigint(
+            _: OriginFor<T>,
+            curve: Curve,
+            base: Vec<u8>,
+            scalar: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let scalar = MulScalar::BigInt(scalar);
+            let output =
+                Self::mul_affine_on(curve, base, scalar, is_optimized::<T::Backend>(&optimized))?;
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        /// Scalar multiplication of a projective point by a scalar field element.
+        ///
+        /// `base` and the output are encoded as for the `mul_projective_*` calls, while
+        /// `scalar` is encoded as for `mul_scalar_field`.
+        #[pallet::call_index(82)]
+        #[pallet::weight(Pallet::<T>::mul_projective_weight(
+            *curve,
+            scalar_field_words(*curve),
+            is_optimized::<T::Backend>(optimized)
+        ))]
+        pub fn mul_projective_scalar_field(
+            _: OriginFor<T>,
+            curve: Curve,
+            base: Vec<u8>,
+            scalar: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let scalar = MulScalar::FieldElement(scalar);
+            let output = Self::mul_projective_on(
+                curve,
+                base,
+                scalar,
+                is_optimized::<T::Backend>(&optimized),
+            )?;
+            Self::deposit_result(output);
+            Ok(())
+        }
+
+        /// Scalar multiplication of a projective point by a big integer of arbitrary length.
+        ///
+        /// `base` and the output are encoded as for the `mul_projective_*` calls, while
+        /// `scalar` is encoded as for `mul_bigint`.
+        #[pallet::call_index(83)]
+        #[pallet::weight(Pallet::<T>::mul_projective_weight(
+            *curve,
+            bigint_words::<T>(scalar),
+            is_optimized::<T::Backend>(optimized)
+        ))]
+        pub fn mul_projective_bigint(
+            _: OriginFor<T>,
+            curve: Curve,
+            base: Vec<u8>,
+            scalar: Vec<u8>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let scalar = MulScalar::BigInt(scalar);
+            let output = Self::mul_projective_on(
+                curve,
+                base,
+                scalar,
+                is_optimized::<T::Backend>(&optimized),
+            )?;
+            Self::deposit_result(output);
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        fn decode_projective_sw<C: SWCurveConfig>(
+            data: &[u8],
+        ) -> Result<SWProjective<C>, Error<T>> {
+            let point = ArkScaleProjective::<SWProjective<C>>::decode(&mut &data[..])
+                .map_err(|_| Error::<T>::InvalidPoint)?
+                .0;
+            if let Validate::Yes = validation(T::ValidatePoints::get()) {
+                let point = point.into_affine();
+                ensure!(
+                    point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve(),
+                    Error::<T>::InvalidPoint
+                );
+            }
+            Ok(point)
+        }
+
+        fn decode_projective_te<C: TECurveConfig>(
+            data: &[u8],
+        ) -> Result<TEProjective<C>, Error<T>> {
+            let point = ArkScaleProjective::<TEProjective<C>>::decode(&mut &data[..])
+                .map_err(|_| Error::<T>::InvalidPoint)?
+                .0;
+            if let Validate::Yes = validation(T::ValidatePoints::get()) {
+                let point = point.into_affine();
+                ensure!(
+                    point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve(),
+                    Error::<T>::InvalidPoint
+                );
+            }
+            Ok(point)
+        }
+
+        fn msm_sw<C: SWCurveConfig>(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, Error<T>> {
+            ensure!(
+                encoded_vec_len(&bases) <= T::MaxMsmLen::get()
+                    && encoded_vec_len(&scalars) <= T::MaxMsmLen::get(),
+                Error::<T>::InputTooLarge
+            );
+            let bases = decode_vec::<SWAffine<C>>(&bases, validation(T::ValidatePoints::get()))
+                .ok_or(Error::<T>::InvalidBases)?;
+            let scalars = decode_vec::<C::ScalarField>(&scalars, Validate::Yes)
+                .ok_or(Error::<T>::InvalidScalars)?;
+            ensure!(bases.len() == scalars.len(), Error::<T>::LengthMismatch);
+            let res = C::msm(&bases, &scalars).map_err(|_| Error::<T>::LengthMismatch)?;
+            Ok(ArkScale::from(res.into_affine()).encode())
+        }
+
+        fn mul_projective_sw<C: SWCurveConfig>(
+            base: Vec<u8>,
+            scalar: Vec<u8>,
+        ) -> Result<Vec<u8>, Error<T>> {
+            ensure!(
+                encoded_vec_len(&scalar) <= T::MaxScalarWords::get(),
+                Error::<T>::InputTooLarge
+            );
+            let base = Self::decode_projective_sw::<C>(&base)?;
+            let scalar =
+                decode_vec::<u64>(&scalar, Validate::Yes).ok_or(Error::<T>::InvalidScalars)?;
+            let res = C::mul_projective(&base, &scalar);
+            Ok(ArkScaleProjective::from(res).encode())
+        }
+
+        fn mul_affine_sw<C: SWCurveConfig>(
+            base: Vec<u8>,
+            scalar: Vec<u8>,
+        ) -> Result<Vec<u8>, Error<T>> {
+            ensure!(
+                encoded_vec_len(&scalar) <= T::MaxScalarWords::get(),
+                Error::<T>::InputTooLarge
+            );
+            let base = decode::<SWAffine<C>>(&base, validation(T::ValidatePoints::get()))
+                .ok_or(Error::<T>::InvalidPoint)?;
+            let scalar =
+                decode_vec::<u64>(&scalar, Validate::Yes).ok_or(Error::<T>::InvalidScalars)?;
+            let res = C::mul_affine(&base, &scalar);
+            Ok(ArkScale::from(res.into_affine()).encode())
+        }
+
+        fn msm_te<C: TECurveConfig>(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, Error<T>> {
+            ensure!(
+                encoded_vec_len(&bases) <= T::MaxMsmLen::get()
+                    && encoded_vec_len(&scalars) <= T::MaxMsmLen::get(),
+                Error::<T>::InputTooLarge
+            );
+            let bases = decode_vec::<TEAffine<C>>(&bases, validation(T::ValidatePoints::get()))
+                .ok_or(Error::<T>::InvalidBases)?;
+            let scalars = decode_vec::<C::ScalarField>(&scalars, Validate::Yes)
+                .ok_or(Error::<T>::InvalidScalars)?;
+            ensure!(bases.len() == scalars.len(), Error::<T>::LengthMismatch);
+            let res = C::msm(&bases, &scalars).map_err(|_| Error::<T>::LengthMismatch)?;
+            Ok(ArkScale::from(res.into_affine()).encode())
+        }
+
+        fn mul_projective_te<C: TECurveConfig>(
+            base: Vec<u8>,
+            scalar: Vec<u8>,
+        ) -> Result<Vec<u8>, Error<T>> {
+            ensure!(
+                encoded_vec_len(&scalar) <= T::MaxScalarWords::get(),
+                Error::<T>::InputTooLarge
+            );
+            let base = Self::decode_projective_te::<C>(&base)?;
+            let scalar =
+                decode_vec::<u64>(&scalar, Validate::Yes).ok_or(Error::<T>::InvalidScalars)?;
+            let res = C::mul_projective(&base, &scalar);
+            Ok(ArkScaleProjective::from(res).encode())
+        }
+
+        fn mul_affine_te<C: TECurveConfig>(
+            base: Vec<u8>,
+            scalar: Vec<u8>,
+        ) -> Result<Vec<u8>, Error<T>> {
+            ensure!(
+                encoded_vec_len(&scalar) <= T::MaxScalarWords::get(),
+                Error::<T>::InputTooLarge
+            );
+            let base = decode::<TEAffine<C>>(&base, validation(T::ValidatePoints::get()))
+                .ok_or(Error::<T>::InvalidPoint)?;
+            let scalar =
+                decode_vec::<u64>(&scalar, Validate::Yes).ok_or(Error::<T>::InvalidScalars)?;
+            let res = C::mul_affine(&base, &scalar);
+            Ok(ArkScale::from(res.into_affine()).encode())
+        }
+
+        fn pairing<P: Pairing>(a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, Error<T>> {
+            let a = decode::<P::G1Affine>(&a, validation(T::ValidatePoints::get()))
+                .ok_or(Error::<T>::InvalidPoint)?;
+            let b = decode::<P::G2Affine>(&b, validation(T::ValidatePoints::get()))
+                .ok_or(Error::<T>::InvalidPoint)?;
+            let res = P::multi_pairing([a], [b]);
+            Ok(ArkScale::from(res).encode())
+        }
+
+        fn multi_pairing<P: Pairing>(
+            a: Vec<u8>,
+            b: Vec<u8>,
+            check: bool,
+        ) -> Result<Vec<u8>, Error<T>> {
+            ensure!(
+                encoded_vec_len(&a) <= T::MaxPairingLen::get()
+                    && encoded_vec_len(&b) <= T::MaxPairingLen::get(),
+                Error::<T>::InputTooLarge
+            );
+            let a = decode_vec::<P::G1Affine>(&a, validation(T::ValidatePoints::get()))
+                .ok_or(Error::<T>::InvalidPoint)?;
+            let b = decode_vec::<P::G2Affine>(&b, validation(T::ValidatePoints::get()))
+                .ok_or(Error::<T>::InvalidPoint)?;
+            ensure!(a.len() == b.len(), Error::<T>::LengthMismatch);
+            let res = P::multi_pairing(a, b);
+            ensure!(!check || res.is_zero(), Error::<T>::PairingCheckFailed);
+            Ok(ArkScale::from(res).encode())
+        }
+
+        fn multi_miller_loop<P: Pairing>(a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, Error<T>> {
+            ensure!(
+                encoded_vec_len(&a) <= T::MaxPairingLen::get()
+                    && encoded_vec_len(&b) <= T::MaxPairingLen::get(),
+                Error::<T>::InputTooLarge
+            );
+            let a = decode_vec::<P::G1Affine>(&a, validation(T::ValidatePoints::get()))
+                .ok_or(Error::<T>::InvalidPoint)?;
+            let b = decode_vec::<P::G2Affine>(&b, validation(T::ValidatePoints::get()))
+                .ok_or(Error::<T>::InvalidPoint)?;
+            ensure!(a.len() == b.len(), Error::<T>::LengthMismatch);
+            let res = P::multi_miller_loop(a, b);
+            Ok(ArkScale::from(res.0).encode())
+        }
+
+        fn final_exponentiation<P: Pairing>(target: Vec<u8>) -> Result<Vec<u8>, Error<T>> {
+            let target = decode::<P::TargetField>(&target, Validate::Yes)
+                .ok_or(Error::<T>::InvalidTarget)?;
+            let res = P::final_exponentiation(MillerLoopOutput(target))
+                .ok_or(Error::<T>::InvalidTarget)?;
+            Ok(ArkScale::from(res).encode())
+        }
+
+        fn te_to_sw<C: SWCurveConfig + TECurveConfig>(point: Vec<u8>) -> Result<Vec<u8>, Error<T>> {
+            let point = decode::<TEAffine<C>>(&point, validation(T::ValidatePoints::get()))
+                .ok_or(Error::<T>::InvalidPoint)?;
+            Ok(ArkScale::from(te_sw::te_to_sw(&point)).encode())
+        }
+
+        fn sw_to_te<C: SWCurveConfig + TECurveConfig>(point: Vec<u8>) -> Result<Vec<u8>, Error<T>> {
+            let point = decode::<SWAffine<C>>(&point, validation(T::ValidatePoints::get()))
+                .ok_or(Error::<T>::InvalidPoint)?;
+            let res = te_sw::sw_to_te(&point).ok_or(Error::<T>::InvalidPoint)?;
+            Ok(ArkScale::from(res).encode())
+        }
+
+        fn te_to_sw_batch<C: SWCurveConfig + TECurveConfig>(
+            points: Vec<u8>,
+        ) -> Result<Vec<u8>, Error<T>> {
+            ensure!(
+                encoded_vec_len(&points) <= T::MaxMsmLen::get(),
+                Error::<T>::InputTooLarge
+            );
+            let points = decode_vec::<TEAffine<C>>(&points, validation(T::ValidatePoints::get()))
+                .ok_or(Error::<T>::InvalidPoint)?;
+            Ok(ArkScale::from(te_sw::te_to_sw_batch(&points)).encode())
+        }
+
+        fn sw_to_te_batch<C: SWCurveConfig + TECurveConfig>(
+            points: Vec<u8>,
+        ) -> Result<Vec<u8>, Error<T>> {
+            ensure!(
+                encoded_vec_len(&points) <= T::MaxMsmLen::get(),
+                Error::<T>::InputTooLarge
+            );
+            let points = decode_vec::<SWAffine<C>>(&points, validation(T::ValidatePoints::get()))
+                .ok_or(Error::<T>::InvalidPoint)?;
+            let res = te_sw::sw_to_te_batch(&points).ok_or(Error::<T>::InvalidPoint)?;
+            Ok(ArkScale::from(res).encode())
+        }
+
+        /// Decode, validate and store a base set, returning its length.
+        ///
+        /// Decoding uses the host calls backend.
+        pub(crate) fn insert_base_set(
+            set_id: BaseSetId,
+            curve: Curve,
+            bases: &[u8],
+        ) -> Result<u32, Error<T>> {
+            use Curve::*;
+            let len = match curve {
+                Bls12_381G1 => {
+                    Self::store_base_set::<SWAffine<sub_bls12_381::g1::Config>>(set_id, bases)
+                }
+                Bls12_381G2 => {
+                    Self::store_base_set::<SWAffine<sub_bls12_381::g2::Config>>(set_id, bases)
+                }
+                Bls12_377G1 => {
+                    Self::store_base_set::<SWAffine<sub_bls12_377::g1::Config>>(set_id, bases)
+                }
+                Bls12_377G2 => {
+                    Self::store_base_set::<SWAffine<sub_bls12_377::g2::Config>>(set_id, bases)
+                }
+                Bw6_761G1 => {
+                    Self::store_base_set::<SWAffine<sub_bw6_761::g1::Config>>(set_id, bases)
+                }
+                Bw6_761G2 => {
+                    Self::store_base_set::<SWAffine<sub_bw6_761::g2::Config>>(set_id, bases)
+                }
+                EdOnBls12_377Te => Self::store_base_set::<
+                    TEAffine<sub_ed_on_bls12_377::EdwardsConfig>,
+                >(set_id, bases),
+                EdOnBls12_381BandersnatchSw => Self::store_base_set::<
+                    SWAffine<sub_ed_on_bls12_381_bandersnatch::SWConfig>,
+                >(set_id, bases),
+                EdOnBls12_381BandersnatchTe => Self::store_base_set::<
+                    TEAffine<sub_ed_on_bls12_381_bandersnatch::EdwardsConfig>,
+                >(set_id, bases),
+            }?;
+            BaseSets::<T>::insert(set_id, BaseSetInfo { curve, len });
+            Ok(len)
+        }
+
+        fn store_base_set<A: CanonicalSerialize + CanonicalDeserialize>(
+            set_id: BaseSetId,
+            bases: &[u8],
+        ) -> Result<u32, Error<T>> {
+            let bases = decode_vec::<A>(bases, Validate::Yes).ok_or(Error::<T>::InvalidBases)?;
+            for (page_idx, page) in bases.chunks(BASE_SET_PAGE_SIZE).enumerate() {
+                let mut raw = Vec::new();
+                page.serialize_uncompressed(&mut raw)
+                    .map_err(|_| Error::<T>::InvalidBases)?;
+                let raw = BaseSetPage::try_from(raw).map_err(|_| Error::<T>::InvalidBases)?;
+                BaseSetPages::<T>::insert(set_id, page_idx as u32, raw);
+            }
+            Ok(bases.len() as u32)
+        }
+
+        /// Read the first `len` bases of a registered set.
+        ///
+        /// Fails with `UnknownBaseSet` if any of the pages is missing or can't be decoded.
+        fn fetch_base_set<A: CanonicalDeserialize>(
+            set_id: BaseSetId,
+            len: usize,
+        ) -> Result<Vec<A>, Error<T>> {
+            let mut bases = Vec::with_capacity(len);
+            for page_idx in 0..len.div_ceil(BASE_SET_PAGE_SIZE) {
+                let page = BaseSetPages::<T>::get(set_id, page_idx as u32)
+                    .ok_or(Error::<T>::UnknownBaseSet)?;
+                // Pages are validated on registration.
+                let page = Vec::<A>::deserialize_uncompressed_unchecked(&page[..])
+                    .map_err(|_| Error::<T>::UnknownBaseSet)?;
+                bases.extend(page);
+            }
+            ensure!(bases.len() >= len, Error::<T>::UnknownBaseSet);
+            bases.truncate(len);
+            Ok(bases)
+        }
+
+        fn msm_fixed_base_on(
+            set_id: BaseSetId,
+            info: BaseSetInfo,
+            scalars: Vec<u8>,
+            optimized: bool,
+        ) -> Result<Vec<u8>, Error<T>> {
+            use Curve::*;
+            let len = info.len;
+            match (info.curve, optimized) {
+                (Bls12_381G1, true) => {
+                    Self::msm_fixed_base_sw::<sub_bls12_381::g1::Config>(set_id, len, scalars)
+                }
+                (Bls12_381G1, false) => {
+                    Self::msm_fixed_base_sw::<ark_bls12_381::g1::Config>(set_id, len, scalars)
+                }
+                (Bls12_381G2, true) => {
+                    Self::msm_fixed_base_sw::<sub_bls12_381::g2::Config>(set_id, len, scalars)
+                }
+                (Bls12_381G2, false) => {
+                    Self::msm_fixed_base_sw::<ark_bls12_381::g2::Config>(set_id, len, scalars)
+                }
+                (Bls12_377G1, true) => {
+                    Self::msm_fixed_base_sw::<sub_bls12_377::g1::Config>(set_id, len, scalars)
+                }
+                (Bls12_377G1, false) => {
+                    Self::msm_fixed_base_sw::<ark_bls12_377::g1::Config>(set_id, len, scalars)
+                }
+                (Bls12_377G2, true) => {
+                    Self::msm_fixed_base_sw::<sub_bls12_377::g2::Config>(set_id, len, scalars)
+                }
+                (Bls12_377G2, false) => {
+                    Self::msm_fixed_base_sw::<ark_bls12_377::g2::Config>(set_id, len, scalars)
+                }
+                (Bw6_761G1, true) => {
+                    Self::msm_fixed_base_sw::<sub_bw6_761::g1::Config>(set_id, len, scalars)
+                }
+                (Bw6_761G1, false) => {
+                    Self::msm_fixed_base_sw::<ark_bw6_761::g1::Config>(set_id, len, scalars)
+                }
+                (Bw6_761G2, true) => {
+                    Self::msm_fixed_base_sw::<sub_bw6_761::g2::Config>(set_id, len, scalars)
+                }
+                (Bw6_761G2, false) => {
+                    Self::msm_fixed_base_sw::<ark_bw6_761::g2::Config>(set_id, len, scalars)
+                }
+                (EdOnBls12_377Te, true) => Self::msm_fixed_base_te::<
+                    sub_ed_on_bls12_377::EdwardsConfig,
+                >(set_id, len, scalars),
+                (EdOnBls12_377Te, false) => Self::msm_fixed_base_te::<
+                    ark_ed_on_bls12_377::EdwardsConfig,
+                >(set_id, len, scalars),
+                (EdOnBls12_381BandersnatchSw, true) => Self::msm_fixed_base_sw::<
+                    sub_ed_on_bls12_381_bandersnatch::SWConfig,
+                >(set_id, len, scalars),
+                (EdOnBls12_381BandersnatchSw, false) => Self::msm_fixed_base_sw::<
+                    ark_ed_on_bls12_381_bandersnatch::SWConfig,
+                >(set_id, len, scalars),
+                (EdOnBls12_381BandersnatchTe, true) => Self::msm_fixed_base_te::<
+                    sub_ed_on_bls12_381_bandersnatch::EdwardsConfig,
+                >(set_id, len, scalars),
+                (EdOnBls12_381BandersnatchTe, false) => Self::msm_fixed_base_te::<
+                    ark_ed_on_bls12_381_bandersnatch::EdwardsConfig,
+                >(set_id, len, scalars),
+            }
+        }
+
+        fn msm_fixed_base_sw<C: SWCurveConfig>(
+            set_id: BaseSetId,
+            len: u32,
+            scalars: Vec<u8>,
+        ) -> Result<Vec<u8>, Error<T>> {
+            ensure!(encoded_vec_len(&scalars) <= len, Error::<T>::LengthMismatch);
+            let scalars = decode_vec::<C::ScalarField>(&scalars, Validate::Yes)
+                .ok_or(Error::<T>::InvalidScalars)?;
+            let bases = Self::fetch_base_set::<SWAffine<C>>(set_id, scalars.len())?;
+            let res = C::msm(&bases, &scalars).map_err(|_| Error::<T>::LengthMismatch)?;
+            Ok(ArkScale::from(res.into_affine()).encode())
+        }
+
+        fn msm_fixed_base_te<C: TECurveConfig>(
+            set_id: BaseSetId,
+            len: u32,
+            scalars: Vec<u8>,
+        ) -> Result<Vec<u8>, Error<T>> {
+            ensure!(encoded_vec_len(&scalars) <= len, Error::<T>::LengthMismatch);
+            let scalars = decode_vec::<C::ScalarField>(&scalars, Validate::Yes)
+                .ok_or(Error::<T>::InvalidScalars)?;
+            let bases = Self::fetch_base_set::<TEAffine<C>>(set_id, scalars.len())?;
+            let res = C::msm(&bases, &scalars).map_err(|_| Error::<T>::LengthMismatch)?;
+            Ok(ArkScale::from(res.into_affine()).encode())
+        }
+
+        /// Little-endian 64-bit words of a `mul_scalar_field` or `mul_bigint` scalar.
+        fn mul_scalar_limbs<F: PrimeField>(scalar: MulScalar) -> Result<Vec<u64>, Error<T>> {
+            match scalar {
+                MulScalar::FieldElement(scalar) => {
+                    let scalar =
+                        decode::<F>(&scalar, Validate::Yes).ok_or(Error::<T>::InvalidScalars)?;
+                    Ok(scalar.into_bigint().as_ref().to_vec())
+                }
+                MulScalar::BigInt(scalar) => {
+                    ensure!(
+                        scalar.len().div_ceil(8) <= T::MaxScalarWords::get() as usize,
+                        Error::<T>::InputTooLarge
+                    );
+                    Ok(bigint_limbs(&scalar))
+                }
+            }
+        }
+
+        fn mul_scalar_sw<C: SWCurveConfig>(
+            base: Vec<u8>,
+            scalar: MulScalar,
+        ) -> Result<Vec<u8>, Error<T>> {
+            let scalar = Self::mul_scalar_limbs::<C::ScalarField>(scalar)?;
+            let base = decode::<SWAffine<C>>(&base, validation(T::ValidatePoints::get()))
+                .ok_or(Error::<T>::InvalidPoint)?;
+            let res = C::mul_affine(&base, &scalar);
+            Ok(ArkScale::from(res.into_affine()).encode())
+        }
+
+        fn mul_scalar_te<C: TECurveConfig>(
+            base: Vec<u8>,
+            scalar: MulScalar,
+        ) -> Result<Vec<u8>, Error<T>> {
+            let scalar = Self::mul_scalar_limbs::<C::ScalarField>(scalar)?;
+            let base = decode::<TEAffine<C>>(&base, validation(T::ValidatePoints::get()))
+                .ok_or(Error::<T>::InvalidPoint)?;
+            let res = C::mul_affine(&base, &scalar);
+            Ok(ArkScale::from(res.into_affine()).encode())
+        }
+
+        fn mul_projective_scalar_sw<C: SWCurveConfig>(
+            base: Vec<u8>,
+            scalar: MulScalar,
+        ) -> Result<Vec<u8>, Error<T>> {
+            let scalar = Self::mul_scalar_limbs::<C::ScalarField>(scalar)?;
+            let base = Self::decode_projective_sw::<C>(&base)?;
+            let res = C::mul_projective(&base, &scalar);
+            Ok(ArkScaleProjective::from(res).encode())
+        }
+
+        fn mul_projective_scalar_te<C: TECurveConfig>(
+            base: Vec<u8>,
+            scalar: MulScalar,
+        ) -> Result<Vec<u8>, Error<T>> {
+            let scalar = Self::mul_scalar_limbs::<C::ScalarField>(scalar)?;
+            let base = Self::decode_projective_te::<C>(&base)?;
+            let res = C::mul_projective(&base, &scalar);
+            Ok(ArkScaleProjective::from(res).encode())
+        }
+
+        fn mul_affine_on(
+            curve: Curve,
+            base: Vec<u8>,
+            scalar: MulScalar,
+            optimized: bool,
+        ) -> Result<Vec<u8>, Error<T>> {
+            use Curve::*;
+            match (curve, optimized) {
+                (Bls12_381G1, true) => {
+                    Self::mul_scalar_sw::<sub_bls12_381::g1::Config>(base, scalar)
+                }
+                (Bls12_381G1, false) => {
+                    Self::mul_scalar_sw::<ark_bls12_381::g1::Config>(base, scalar)
+                }
+                (Bls12_381G2, true) => {
+                    Self::mul_scalar_sw::<sub_bls12_381::g2::Config>(base, scalar)
+                }
+                (Bls12_381G2, false) => {
+                    Self::mul_scalar_sw::<ark_bls12_381::g2::Config>(base, scalar)
+                }
+                (Bls12_377G1, true) => {
+                    Self::mul_scalar_sw::<sub_bls12_377::g1::Config>(base, scalar)
+                }
+                (Bls12_377G1, false) => {
+                    Self::mul_scalar_sw::<ark_bls12_377::g1::Config>(base, scalar)
+                }
+                (Bls12_377G2, true) => {
+                    Self::mul_scalar_sw::<sub_bls12_377::g2::Config>(base, scalar)
+                }
+                (Bls12_377G2, false) => {
+                    Self::mul_scalar_sw::<ark_bls12_377::g2::Config>(base, scalar)
+                }
+                (Bw6_761G1, true) => Self::mul_scalar_sw::<sub_bw6_761::g1::Config>(base, scalar),
+                (Bw6_761G1, false) => Self::mul_scalar_sw::<ark_bw6_761::g1::Config>(base, scalar),
+                (Bw6_761G2, true) => Self::mul_scalar_sw::<sub_bw6_761::g2::Config>(base, scalar),
+                (Bw6_761G2, false) => Self::mul_scalar_sw::<ark_bw6_761::g2::Config>(base, scalar),
+                (EdOnBls12_377Te, true) => {
+                    Self::mul_scalar_te::<sub_ed_on_bls12_377::EdwardsConfig>(base, scalar)
+                }
+                (EdOnBls12_377Te, false) => {
+                    Self::mul_scalar_te::<ark_ed_on_bls12_377::EdwardsConfig>(base, scalar)
+                }
+                (EdOnBls12_381BandersnatchSw, true) => {
+                    Self::mul_scalar_sw::<sub_ed_on_bls12_381_bandersnatch::SWConfig>(base, scalar)
+                }
+                (EdOnBls12_381BandersnatchSw, false) => {
+                    Self::mul_scalar_sw::<ark_ed_on_bls12_381_bandersnatch::SWConfig>(base, scalar)
+                }
+                (EdOnBls12_381BandersnatchTe, true) => Self::mul_scalar_te::<
+                    sub_ed_on_bls12_381_bandersnatch::EdwardsConfig,
+                >(base, scalar),
+                (EdOnBls12_381BandersnatchTe, false) => Self::mul_scalar_te::<
+                    ark_ed_on_bls12_381_bandersnatch::EdwardsConfig,
+                >(base, scalar),
+            }
+        }
+
+        fn mul_projective_on(
+            curve: Curve,
+            base: Vec<u8>,
+            scalar: MulScalar,
+            optimized: bool,
+        ) -> Result<Vec<u8>, Error<T>> {
+            use Curve::*;
+            match (curve, optimized) {
+                (Bls12_381G1, true) => {
+                    Self::mul_projective_scalar_sw::<sub_bls12_381::g1::Config>(base, scalar)
+                }
+                (Bls12_381G1, false) => {
+                    Self::mul_projective_scalar_sw::<ark_bls12_381::g1::Config>(base, scalar)
+                }
+                (Bls12_381G2, true) => {
+                    Self::mul_projective_scalar_sw::<sub_bls12_381::g2::Config>(base, scalar)
+                }
+                (Bls12_381G2, false) => {
+                    Self::mul_projective_scalar_sw::<ark_bls12_381::g2::Config>(base, scalar)
+                }
+                (Bls12_377G1, true) => {
+                    Self::mul_projective_scalar_sw::<sub_bls12_377::g1::Config>(base, scalar)
+                }
+                (Bls12_377G1, false) => {
+                    Self::mul_projective_scalar_sw::<ark_bls12_377::g1::Config>(base, scalar)
+                }
+                (Bls12_377G2, true) => {
+                    Self::mul_projective_scalar_sw::<sub_bls12_377::g2::Config>(base, scalar)
+                }
+                (Bls12_377G2, false) => {
+                    Self::mul_projective_scalar_sw::<ark_bls12_377::g2::Config>(base, scalar)
+                }
+                (Bw6_761G1, true) => {
+                    Self::mul_projective_scalar_sw::<sub_bw6_761::g1::Config>(base, scalar)
+                }
+                (Bw6_761G1, false) => {
+                    Self::mul_projective_scalar_sw::<ark_bw6_761::g1::Config>(base, scalar)
+                }
+                (Bw6_761G2, true) => {
+                    Self::mul_projective_scalar_sw::<sub_bw6_761::g2::Config>(base, scalar)
+                }
+                (Bw6_761G2, false) => {
+                    Self::mul_projective_scalar_sw::<ark_bw6_761::g2::Config>(base, scalar)
+                }
+                (EdOnBls12_377Te, true) => Self::mul_projective_scalar_te::<
+                    sub_ed_on_bls12_377::EdwardsConfig,
+                >(base, scalar),
+                (EdOnBls12_377Te, false) => Self::mul_projective_scalar_te::<
+                    ark_ed_on_bls12_377::EdwardsConfig,
+                >(base, scalar),
+                (EdOnBls12_381BandersnatchSw, true) => Self::mul_projective_scalar_sw::<
+                    sub_ed_on_bls12_381_bandersnatch::SWConfig,
+                >(base, scalar),
+                (EdOnBls12_381BandersnatchSw, false) => Self::mul_projective_scalar_sw::<
+                    ark_ed_on_bls12_381_bandersnatch::SWConfig,
+                >(base, scalar),
+                (EdOnBls12_381BandersnatchTe, true) => Self::mul_projective_scalar_te::<
+                    sub_ed_on_bls12_381_bandersnatch::EdwardsConfig,
+                >(base, scalar),
+                (EdOnBls12_381BandersnatchTe, false) => Self::mul_projective_scalar_te::<
+                    ark_ed_on_bls12_381_bandersnatch::EdwardsConfig,
+                >(base, scalar),
+            }
+        }
+
+        /// Weight of the `mul_affine_*` call of `curve` with a scalar of `words` words.
+        fn mul_affine_weight(curve: Curve, words: u32, optimized: bool) -> Weight {
+            use Curve::*;
+            match (curve, optimized) {
+                (Bls12_381G1, true) => T::WeightInfo::sub_bls12_381_mul_affine_g1(words),
+                (Bls12_381G1, false) => T::WeightInfo::ark_bls12_381_mul_affine_g1(words),
+                (Bls12_381G2, true) => T::WeightInfo::sub_bls12_381_mul_affine_g2(words),
+                (Bls12_381G2, false) => T::WeightInfo::ark_bls12_381_mul_affine_g2(words),
+                (Bls12_377G1, true) => T::WeightInfo::sub_bls12_377_mul_affine_g1(words),
+                (Bls12_377G1, false) => T::WeightInfo::ark_bls12_377_mul_affine_g1(words),
+                (Bls12_377G2, true) => T::WeightInfo::sub_bls12_377_mul_affine_g2(words),
+                (Bls12_377G2, false) => T::WeightInfo::ark_bls12_377_mul_affine_g2(words),
+                (Bw6_761G1, true) => T::WeightInfo::sub_bw6_761_mul_affine_g1(words),
+                (Bw6_761G1, false) => T::WeightInfo::ark_bw6_761_mul_affine_g1(words),
+                (Bw6_761G2, true) => T::WeightInfo::sub_bw6_761_mul_affine_g2(words),
+                (Bw6_761G2, false) => T::WeightInfo::ark_bw6_761_mul_affine_g2(words),
+                (EdOnBls12_377Te, true) => T::WeightInfo::sub_ed_on_bls12_377_mul_affine_te(words),
+                (EdOnBls12_377Te, false) => T::WeightInfo::ark_ed_on_bls12_377_mul_affine_te(words),
+                (EdOnBls12_381BandersnatchSw, true) => {
+                    T::WeightInfo::sub_ed_on_bls12_381_bandersnatch_mul_affine_sw(words)
+                }
+                (EdOnBls12_381BandersnatchSw, false) => {
+                    T::WeightInfo::ark_ed_on_bls12_381_bandersnatch_mul_affine_sw(words)
+                }
+                (EdOnBls12_381BandersnatchTe, true) => {
+                    T::WeightInfo::sub_ed_on_bls12_381_bandersnatch_mul_affine_te(words)
+                }
+                (EdOnBls12_381BandersnatchTe, false) => {
+                    T::WeightInfo::ark_ed_on_bls12_381_bandersnatch_mul_affine_te(words)
+                }
+            }
+        }
+
+        /// Weight of the `mul_projective_*` call of `curve` with a scalar of `words` words.
+        fn mul_projective_weight(curve: Curve, words: u32, optimized: bool) -> Weight {
+            use Curve::*;
+            match (curve, optimized) {
+                (Bls12_381G1, true) => T::WeightInfo::sub_bls12_381_mul_projective_g1(words),
+                (Bls12_381G1, false) => T::WeightInfo::ark_bls12_381_mul_projective_g1(words),
+                (Bls12_381G2, true) => T::WeightInfo::sub_bls12_381_mul_projective_g2(words),
+                (Bls12_381G2, false) => T::WeightInfo::ark_bls12_381_mul_projective_g2(words),
+                (Bls12_377G1, true) => T::WeightInfo::sub_bls12_377_mul_projective_g1(words),
+                (Bls12_377G1, false) => T::WeightInfo::ark_bls12_377_mul_projective_g1(words),
+                (Bls12_377G2, true) => T::WeightInfo::sub_bls12_377_mul_projective_g2(words),
+                (Bls12_377G2, false) => T::WeightInfo::ark_bls12_377_mul_projective_g2(words),
+                (Bw6_761G1, true) => T::WeightInfo::sub_bw6_761_mul_projective_g1(words),
+                (Bw6_761G1, false) => T::WeightInfo::ark_bw6_761_mul_projective_g1(words),
+                (Bw6_761G2, true) => T::WeightInfo::sub_bw6_761_mul_projective_g2(words),
+                (Bw6_761G2, false) => T::WeightInfo::ark_bw6_761_mul_projective_g2(words),
+                (EdOnBls12_377Te, true) => {
+                    T::WeightInfo::sub_ed_on_bls12_377_mul_projective_te(words)
+                }
+                (EdOnBls12_377Te, false) => {
+                    T::WeightInfo::ark_ed_on_bls12_377_mul_projective_te(words)
+                }
+                (EdOnBls12_381BandersnatchSw, true) => {
+                    T::WeightInfo::sub_ed_on_bls12_381_bandersnatch_mul_projective_sw(words)
+                }
+                (EdOnBls12_381BandersnatchSw, false) => {
+                    T::WeightInfo::ark_ed_on_bls12_381_bandersnatch_mul_projective_sw(words)
+                }
+                (EdOnBls12_381BandersnatchTe, true) => {
+                    T::WeightInfo::sub_ed_on_bls12_381_bandersnatch_mul_projective_te(words)
+                }
+                (EdOnBls12_381BandersnatchTe, false) => {
+                    T::WeightInfo::ark_ed_on_bls12_381_bandersnatch_mul_projective_te(words)
+                }
+            }
+        }
+
+        fn register_base_set_weight(curve: Curve, len: u32) -> Weight {
+            use Curve::*;
+            match curve {
+                Bls12_381G1 => T::WeightInfo::bls12_381_register_base_set_g1(len),
+                Bls12_381G2 => T::WeightInfo::bls12_381_register_base_set_g2(len),
+                Bls12_377G1 => T::WeightInfo::bls12_377_register_base_set_g1(len),
+                Bls12_377G2 => T::WeightInfo::bls12_377_register_base_set_g2(len),
+                Bw6_761G1 => T::WeightInfo::bw6_761_register_base_set_g1(len),
+                Bw6_761G2 => T::WeightInfo::bw6_761_register_base_set_g2(len),
+                EdOnBls12_377Te => T::WeightInfo::ed_on_bls12_377_register_base_set_te(len),
+                EdOnBls12_381BandersnatchSw => {
+                    T::WeightInfo::ed_on_bls12_381_bandersnatch_register_base_set_sw(len)
+                }
+                EdOnBls12_381BandersnatchTe => {
+                    T::WeightInfo::ed_on_bls12_381_bandersnatch_register_base_set_te(len)
+                }
+            }
+        }
+
+        fn msm_fixed_base_weight(curve: Curve, len: u32, optimized: bool) -> Weight {
+            use Curve::*;
+            match (curve, optimized) {
+                (Bls12_381G1, true) => T::WeightInfo::sub_bls12_381_msm_fixed_base_g1(len),
+                (Bls12_381G1, false) => T::WeightInfo::ark_bls12_381_msm_fixed_base_g1(len),
+                (Bls12_381G2, true) => T::WeightInfo::sub_bls12_381_msm_fixed_base_g2(len),
+                (Bls12_381G2, false) => T::WeightInfo::ark_bls12_381_msm_fixed_base_g2(len),
+                (Bls12_377G1, true) => T::WeightInfo::sub_bls12_377_msm_fixed_base_g1(len),
+                (Bls12_377G1, false) => T::WeightInfo::ark_bls12_377_msm_fixed_base_g1(len),
+                (Bls12_377G2, true) => T::WeightInfo::sub_bls12_377_msm_fixed_base_g2(len),
+                (Bls12_377G2, false) => T::WeightInfo::ark_bls12_377_msm_fixed_base_g2(len),
+                (Bw6_761G1, true) => T::WeightInfo::sub_bw6_761_msm_fixed_base_g1(len),
+                (Bw6_761G1, false) => T::WeightInfo::ark_bw6_761_msm_fixed_base_g1(len),
+                (Bw6_761G2, true) => T::WeightInfo::sub_bw6_761_msm_fixed_base_g2(len),
+                (Bw6_761G2, false) => T::WeightInfo::ark_bw6_761_msm_fixed_base_g2(len),
+                (EdOnBls12_377Te, true) => {
+                    T::WeightInfo::sub_ed_on_bls12_377_msm_fixed_base_te(len)
+                }
+                (EdOnBls12_377Te, false) => {
+                    T::WeightInfo::ark_ed_on_bls12_377_msm_fixed_base_te(len)
+                }
+                (EdOnBls12_381BandersnatchSw, true) => {
+                    T::WeightInfo::sub_ed_on_bls12_381_bandersnatch_msm_fixed_base_sw(len)
+                }
+                (EdOnBls12_381BandersnatchSw, false) => {
+                    T::WeightInfo::ark_ed_on_bls12_381_bandersnatch_msm_fixed_base_sw(len)
+                }
+                (EdOnBls12_381BandersnatchTe, true) => {
+                    T::WeightInfo::sub_ed_on_bls12_381_bandersnatch_msm_fixed_base_te(len)
+                }
+                (EdOnBls12_381BandersnatchTe, false) => {
+                    T::WeightInfo::ark_ed_on_bls12_381_bandersnatch_msm_fixed_base_te(len)
+                }
+            }
+        }
+
+        fn msm_fixed_base_max_weight(len: u32, optimized: bool) -> Weight {
+            use Curve::*;
+            [
+                Bls12_381G1,
+                Bls12_381G2,
+                Bls12_377G1,
+                Bls12_377G2,
+                Bw6_761G1,
+                Bw6_761G2,
+                EdOnBls12_377Te,
+                EdOnBls12_381BandersnatchSw,
+                EdOnBls12_381BandersnatchTe,
+            ]
+            .into_iter()
+            .map(|curve| Self::msm_fixed_base_weight(curve, len, optimized))
+            .fold(Weight::zero(), Weight::max)
+        }
+
+        fn hash_to_curve<M, C>(dst: &[u8], msg: &[u8]) -> Result<Vec<u8>, Error<T>>
+        where
+            M: WBConfig,
+            C: SWCurveConfig<BaseField = <M as CurveConfig>::BaseField>,
+        {
+            let hasher = HasherFor::<M, C>::new(dst).map_err(|_| Error::<T>::HashToCurveFailed)?;
+            let res = hasher
+                .hash(msg)
+                .map_err(|_| Error::<T>::HashToCurveFailed)?;
+            Ok(ArkScale::from(res).encode())
+        }
+
+        /// Multi scalar multiplication over `curve` using the host calls backend.
+        ///
+        /// Inputs and output are encoded as for the corresponding `*_msm_*` calls.
+        pub fn compute_msm(
+            curve: Curve,
+            bases: Vec<u8>,
+            scalars: Vec<u8>,
+        ) -> Result<Vec<u8>, Error<T>> {
+            use Curve::*;
+            match curve {
+                Bls12_381G1 => Self::msm_sw::<sub_bls12_381::g1::Config>(bases, scalars),
+                Bls12_381G2 => Self::msm_sw::<sub_bls12_381::g2::Config>(bases, scalars),
+                Bls12_377G1 => Self::msm_sw::<sub_bls12_377::g1::Config>(bases, scalars),
+                Bls12_377G2 => Self::msm_sw::<sub_bls12_377::g2::Config>(bases, scalars),
+                Bw6_761G1 => Self::msm_sw::<sub_bw6_761::g1::Config>(bases, scalars),
+                Bw6_761G2 => Self::msm_sw::<sub_bw6_761::g2::Config>(bases, scalars),
+                EdOnBls12_377Te => {
+                    Self::msm_te::<sub_ed_on_bls12_377::EdwardsConfig>(bases, scalars)
+                }
+                EdOnBls12_381BandersnatchSw => {
+                    Self::msm_sw::<sub_ed_on_bls12_381_bandersnatch::SWConfig>(bases, scalars)
+                }
+                EdOnBls12_381BandersnatchTe => {
+                    Self::msm_te::<sub_ed_on_bls12_381_bandersnatch::EdwardsConfig>(bases, scalars)
+                }
+            }
+        }
+
+        /// Scalar multiplication of an affine point over `curve` using the host calls backend.
+        ///
+        /// Inputs and output are encoded as for the corresponding `*_mul_affine_*` calls.
+        pub fn compute_mul_affine(
+            curve: Curve,
+            base: Vec<u8>,
+            scalar: Vec<u8>,
+        ) -> Result<Vec<u8>, Error<T>> {
+            use Curve::*;
+            match curve {
+                Bls12_381G1 => Self::mul_affine_sw::<sub_bls12_381::g1::Config>(base, scalar),
+                Bls12_381G2 => Self::mul_affine_sw::<sub_bls12_381::g2::Config>(base, scalar),
+                Bls12_377G1 => Self::mul_affine_sw::<sub_bls12_377::g1::Config>(base, scalar),
+                Bls12_377G2 => Self::mul_affine_sw::<sub_bls12_377::g2::Config>(base, scalar),
+                Bw6_761G1 => Self::mul_affine_sw::<sub_bw6_761::g1::Config>(base, scalar),
+                Bw6_761G2 => Self::mul_affine_sw::<sub_bw6_761::g2::Config>(base, scalar),
+                EdOnBls12_377Te => {
+                    Self::mul_affine_te::<sub_ed_on_bls12_377::EdwardsConfig>(base, scalar)
+                }
+                EdOnBls12_381BandersnatchSw => {
+                    Self::mul_affine_sw::<sub_ed_on_bls12_381_bandersnatch::SWConfig>(base, scalar)
+                }
+                EdOnBls12_381BandersnatchTe => Self::mul_affine_te::<
+                    sub_ed_on_bls12_381_bandersnatch::EdwardsConfig,
+                >(base, scalar),
+            }
+        }
+
+        /// Pairing over `curve` using the host calls backend.
+        ///
+        /// Inputs and output are encoded as for the corresponding `*_pairing` calls.
+        pub fn compute_pairing(
+            curve: PairingCurve,
+            a: Vec<u8>,
+            b: Vec<u8>,
+        ) -> Result<Vec<u8>, Error<T>> {
+            match curve {
+                PairingCurve::Bls12_381 => Self::pairing::<sub_bls12_381::Bls12_381>(a, b),
+                PairingCurve::Bls12_377 => Self::pairing::<sub_bls12_377::Bls12_377>(a, b),
+                PairingCurve::Bw6_761 => Self::pairing::<sub_bw6_761::BW6_761>(a, b),
+            }
+        }
+
+        fn deposit_result(output: Vec<u8>) {
+            if T::StoreLastResult::get() {
+                LastResult::<T>::put(&output);
+            }
+            Self::deposit_event(Event::Computed { output });
+        }
     }
 }
@@ -1,4 +1,9 @@
-use frame_support::{self, derive_impl, sp_runtime::BuildStorage};
+use crate::{ArkBackend, BackendSwitch};
+use frame_support::{
+    self, derive_impl, parameter_types,
+    sp_runtime::BuildStorage,
+    traits::{ConstBool, ConstU32},
+};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -33,7 +38,49 @@ impl frame_system::Config for Test {
     type Block = Block;
 }
 
+parameter_types! {
+    pub static ValidatePoints: bool = true;
+    pub static Optimized: bool = true;
+    pub const MaxHashMsgLen: u32 = 1024;
+}
+
+/// Backend selected by the `Optimized` parameter.
+pub struct TestBackend;
+
+impl ArkBackend for TestBackend {
+    fn optimized() -> bool {
+        Optimized::get()
+    }
+}
+
+/// Select the backend of a call.
+///
+/// With the `comparison-mode` feature the selection is passed to the call, otherwise
+/// the backend of the mock runtime is switched.
+#[cfg(feature = "comparison-mode")]
+pub fn backend(optimized: bool) -> BackendSwitch {
+    optimized
+}
+
+/// Select the backend of a call.
+///
+/// With the `comparison-mode` feature the selection is passed to the call, otherwise
+/// the backend of the mock runtime is switched.
+#[cfg(not(feature = "comparison-mode"))]
+pub fn backend(optimized: bool) -> BackendSwitch {
+    Optimized::set(optimized);
+    crate::ConfigBackend
+}
+
 impl crate::Config for Test {
+    type StoreLastResult = ConstBool<true>;
+    type MaxMsmLen = ConstU32<256>;
+    type MaxPairingLen = ConstU32<8>;
+    type MaxScalarWords = ConstU32<8>;
+    type MaxHashMsgLen = MaxHashMsgLen;
+    type ValidatePoints = ValidatePoints;
+    type Backend = TestBackend;
+    type BaseSetOrigin = frame_system::EnsureRoot<u64>;
     type WeightInfo = ();
 }
 
@@ -41,8 +88,12 @@ impl crate::Config for Test {
 pub fn new_test_ext() -> sp_io::TestExternalities {
     sp_tracing::try_init_simple();
 
-    frame_system::GenesisConfig::<Test>::default()
+    let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
         .unwrap()
-        .into()
+        .into();
+
+    // Events are not deposited on genesis block
+    ext.execute_with(|| System::set_block_number(1));
+    ext
 }
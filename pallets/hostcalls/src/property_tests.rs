@@ -0,0 +1,186 @@
+//! Property tests of the group laws through the host calls backed curve types.
+//!
+//! Inputs are derived from a proptest generated seed. Failing seeds are reported on
+//! failure and persisted by proptest in `proptest-regressions`, so they are replayed
+//! first by the following runs.
+
+use crate::{
+    sub_bls12_381, sub_ed_on_bls12_377, sub_ed_on_bls12_381_bandersnatch,
+    utils::make_msm_args_with_rng,
+};
+use ark_ec::{
+    pairing::Pairing, short_weierstrass::Projective as SWProjective,
+    twisted_edwards::Projective as TEProjective, AffineRepr, CurveGroup, PrimeGroup,
+    VariableBaseMSM,
+};
+use ark_ff::UniformRand;
+use ark_scale::ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+use proptest::prelude::*;
+
+/// Number of cases of each property.
+const CASES: u32 = 16;
+/// Longest MSM.
+const MSM_LEN_MAX: u32 = 16;
+/// Longest scalar, in 64-bit words.
+const SCALAR_WORDS_MAX: usize = 8;
+
+type SubBls12_381G1 = SWProjective<sub_bls12_381::g1::Config>;
+type SubBls12_381G2 = SWProjective<sub_bls12_381::g2::Config>;
+type SubEdOnBls12_377 = TEProjective<sub_ed_on_bls12_377::EdwardsConfig>;
+type SubBandersnatchSW = SWProjective<sub_ed_on_bls12_381_bandersnatch::SWConfig>;
+type SubBandersnatchTE = TEProjective<sub_ed_on_bls12_381_bandersnatch::EdwardsConfig>;
+
+fn serialize(value: &impl CanonicalSerialize) -> Vec<u8> {
+    let mut buf = Vec::new();
+    value.serialize_compressed(&mut buf).unwrap();
+    buf
+}
+
+/// Convert a value between the Substrate and Arkworks types via its serialization.
+fn convert<T: CanonicalDeserialize>(value: &impl CanonicalSerialize) -> T {
+    T::deserialize_compressed(&serialize(value)[..]).unwrap()
+}
+
+/// MSM is linear in the scalars and agrees with Arkworks.
+fn msm_linearity<Sub, Ark>(seed: u64, len: u32) -> Result<(), TestCaseError>
+where
+    Sub: CurveGroup,
+    Ark: CurveGroup,
+{
+    let rng = &mut StdRng::seed_from_u64(seed);
+    let (bases, scalars) = make_msm_args_with_rng::<Sub>(len, rng);
+    let (bases, s) = (bases.0, scalars.0);
+    let t: Vec<Sub::ScalarField> = (0..len).map(|_| Sub::ScalarField::rand(rng)).collect();
+    let c = Sub::ScalarField::rand(rng);
+
+    let affine_bases = Sub::normalize_batch(&bases);
+    let msm = |scalars: &[Sub::ScalarField]| Sub::msm(&affine_bases, scalars).unwrap();
+    let sum: Vec<_> = s.iter().zip(&t).map(|(s, t)| *s + t).collect();
+    prop_assert_eq!(msm(&sum), msm(&s) + msm(&t));
+    let scaled: Vec<_> = s.iter().map(|s| *s * c).collect();
+    prop_assert_eq!(msm(&scaled), msm(&s) * c);
+
+    let ark_bases: Vec<Ark::Affine> = convert(&affine_bases);
+    let ark_scalars: Vec<Ark::ScalarField> = convert(&s);
+    let expected = Ark::msm(&ark_bases, &ark_scalars).unwrap();
+    prop_assert_eq!(serialize(&msm(&s)), serialize(&expected));
+    Ok(())
+}
+
+/// Affine and projective scalar multiplications agree, with each other and with Arkworks.
+fn mul_agreement<Sub, Ark>(seed: u64, scalar: Vec<u64>) -> Result<(), TestCaseError>
+where
+    Sub: CurveGroup,
+    Ark: CurveGroup,
+{
+    let rng = &mut StdRng::seed_from_u64(seed);
+    let base = Sub::rand(rng).into_affine();
+
+    let affine = base.mul_bigint(&scalar);
+    let projective = base.into_group().mul_bigint(&scalar);
+    prop_assert_eq!(affine, projective);
+
+    let expected = convert::<Ark::Affine>(&base).mul_bigint(&scalar);
+    prop_assert_eq!(serialize(&affine), serialize(&expected));
+    Ok(())
+}
+
+/// Pairing is bilinear and agrees with Arkworks.
+fn pairing_bilinearity<Sub: Pairing, Ark: Pairing>(seed: u64) -> Result<(), TestCaseError> {
+    let rng = &mut StdRng::seed_from_u64(seed);
+    let (p1, p2) = (Sub::G1::rand(rng), Sub::G1::rand(rng));
+    let q = Sub::G2::rand(rng);
+    let (a, b) = (Sub::ScalarField::rand(rng), Sub::ScalarField::rand(rng));
+
+    let e = |p: Sub::G1, q: Sub::G2| Sub::pairing(p.into_affine(), q.into_affine());
+    prop_assert_eq!(e(p1 * a, q * b), e(p1, q) * (a * b));
+    prop_assert_eq!(e(p1 + p2, q), e(p1, q) + e(p2, q));
+
+    let expected = Ark::pairing(convert::<Ark::G1Affine>(&p1), convert::<Ark::G2Affine>(&q));
+    prop_assert_eq!(serialize(&e(p1, q)), serialize(&expected));
+    Ok(())
+}
+
+fn scalar_words() -> impl Strategy<Value = Vec<u64>> {
+    prop::collection::vec(any::<u64>(), 1..=SCALAR_WORDS_MAX)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(CASES))]
+
+    // ---------------------------------------------
+    // Properties for bls12-381
+    // ---------------------------------------------
+
+    #[test]
+    fn bls12_381_msm_g1_linearity(seed: u64, len in 0..=MSM_LEN_MAX) {
+        msm_linearity::<SubBls12_381G1, ark_bls12_381::G1Projective>(seed, len)?;
+    }
+
+    #[test]
+    fn bls12_381_msm_g2_linearity(seed: u64, len in 0..=MSM_LEN_MAX) {
+        msm_linearity::<SubBls12_381G2, ark_bls12_381::G2Projective>(seed, len)?;
+    }
+
+    #[test]
+    fn bls12_381_mul_g1_agreement(seed: u64, scalar in scalar_words()) {
+        mul_agreement::<SubBls12_381G1, ark_bls12_381::G1Projective>(seed, scalar)?;
+    }
+
+    #[test]
+    fn bls12_381_mul_g2_agreement(seed: u64, scalar in scalar_words()) {
+        mul_agreement::<SubBls12_381G2, ark_bls12_381::G2Projective>(seed, scalar)?;
+    }
+
+    #[test]
+    fn bls12_381_pairing_bilinearity(seed: u64) {
+        pairing_bilinearity::<sub_bls12_381::Bls12_381, ark_bls12_381::Bls12_381>(seed)?;
+    }
+
+    // ---------------------------------------------
+    // Properties for ed-on-bls12-377
+    // ---------------------------------------------
+
+    #[test]
+    fn ed_on_bls12_377_msm_te_linearity(seed: u64, len in 0..=MSM_LEN_MAX) {
+        msm_linearity::<SubEdOnBls12_377, ark_ed_on_bls12_377::EdwardsProjective>(seed, len)?;
+    }
+
+    #[test]
+    fn ed_on_bls12_377_mul_te_agreement(seed: u64, scalar in scalar_words()) {
+        mul_agreement::<SubEdOnBls12_377, ark_ed_on_bls12_377::EdwardsProjective>(seed, scalar)?;
+    }
+
+    // ---------------------------------------------
+    // Properties for ed-on-bls12-381-bandersnatch
+    // ---------------------------------------------
+
+    #[test]
+    fn ed_on_bls12_381_bandersnatch_msm_sw_linearity(seed: u64, len in 0..=MSM_LEN_MAX) {
+        msm_linearity::<SubBandersnatchSW, ark_ed_on_bls12_381_bandersnatch::SWProjective>(
+            seed, len,
+        )?;
+    }
+
+    #[test]
+    fn ed_on_bls12_381_bandersnatch_msm_te_linearity(seed: u64, len in 0..=MSM_LEN_MAX) {
+        msm_linearity::<SubBandersnatchTE, ark_ed_on_bls12_381_bandersnatch::EdwardsProjective>(
+            seed, len,
+        )?;
+    }
+
+    #[test]
+    fn ed_on_bls12_381_bandersnatch_mul_sw_agreement(seed: u64, scalar in scalar_words()) {
+        mul_agreement::<SubBandersnatchSW, ark_ed_on_bls12_381_bandersnatch::SWProjective>(
+            seed, scalar,
+        )?;
+    }
+
+    #[test]
+    fn ed_on_bls12_381_bandersnatch_mul_te_agreement(seed: u64, scalar in scalar_words()) {
+        mul_agreement::<SubBandersnatchTE, ark_ed_on_bls12_381_bandersnatch::EdwardsProjective>(
+            seed, scalar,
+        )?;
+    }
+}
@@ -0,0 +1,109 @@
+//! Conversion of points between the Twisted Edwards and Short Weierstrass models.
+//!
+//! Maps go through the Montgomery model given by `TECurveConfig::MontCurveConfig`,
+//! thus the Short Weierstrass curve is expected to be the one obtained from the
+//! Montgomery curve by the standard change of variables. This is the case for the
+//! Bandersnatch curve configs, where the generators are mapped to each other.
+//!
+//! Only base field arithmetic is involved, batch variants share a single inversion.
+
+use ark_ec::{
+    short_weierstrass::{Affine as SWAffine, SWCurveConfig},
+    twisted_edwards::{Affine as TEAffine, MontCurveConfig, TECurveConfig},
+    AffineRepr,
+};
+use ark_ff::{batch_inversion, Field, One, Zero};
+use ark_std::vec::Vec;
+
+/// Montgomery curve `(A, B)` coefficients together with `1/B`.
+fn mont_coeffs<C: TECurveConfig>() -> (C::BaseField, C::BaseField, C::BaseField) {
+    let a = <C::MontCurveConfig as MontCurveConfig>::COEFF_A;
+    let b = <C::MontCurveConfig as MontCurveConfig>::COEFF_B;
+    let b_inv = b.inverse().expect("Montgomery B coefficient is not zero");
+    (a, b, b_inv)
+}
+
+/// Map a Twisted Edwards point to Short Weierstrass.
+pub fn te_to_sw<C: SWCurveConfig + TECurveConfig>(point: &TEAffine<C>) -> SWAffine<C> {
+    te_to_sw_batch(core::slice::from_ref(point))[0]
+}
+
+/// Map a batch of Twisted Edwards points to Short Weierstrass.
+pub fn te_to_sw_batch<C: SWCurveConfig + TECurveConfig>(
+    points: &[TEAffine<C>],
+) -> Vec<SWAffine<C>> {
+    let one = C::BaseField::one();
+    let (a, _, b_inv) = mont_coeffs::<C>();
+    let three_inv = C::BaseField::from(3u64).inverse().expect("3 is not zero");
+    let a_div_3b = a * three_inv * b_inv;
+
+    // Denominators of `u = (1 + y) / (1 - y)` and `v = u / x`, inverted together.
+    let mut dens: Vec<_> = points.iter().map(|p| (one - p.y) * p.x).collect();
+    batch_inversion(&mut dens);
+
+    points
+        .iter()
+        .zip(dens)
+        .map(|(p, den)| {
+            if p.is_zero() {
+                return SWAffine::identity();
+            }
+            // The point of order two `(0, -1)` maps to the Montgomery point `(0, 0)`.
+            let (u, v) = if p.x.is_zero() {
+                (C::BaseField::zero(), C::BaseField::zero())
+            } else {
+                let u = (one + p.y) * p.x * den;
+                (u, u * (one - p.y) * den)
+            };
+            SWAffine::new_unchecked(u * b_inv + a_div_3b, v * b_inv)
+        })
+        .collect()
+}
+
+/// Map a Short Weierstrass point to Twisted Edwards.
+///
+/// Returns `None` if the point maps to a Twisted Edwards point at infinity.
+pub fn sw_to_te<C: SWCurveConfig + TECurveConfig>(point: &SWAffine<C>) -> Option<TEAffine<C>> {
+    sw_to_te_batch(core::slice::from_ref(point)).map(|points| points[0])
+}
+
+/// Map a batch of Short Weierstrass points to Twisted Edwards.
+///
+/// Returns `None` if any point maps to a Twisted Edwards point at infinity.
+/// These points are not in the prime order subgroup.
+pub fn sw_to_te_batch<C: SWCurveConfig + TECurveConfig>(
+    points: &[SWAffine<C>],
+) -> Option<Vec<TEAffine<C>>> {
+    let one = C::BaseField::one();
+    let (a, b, _) = mont_coeffs::<C>();
+    let a_div_3 = a * C::BaseField::from(3u64).inverse().expect("3 is not zero");
+
+    let mont: Vec<_> = points
+        .iter()
+        .map(|p| (b * p.x - a_div_3, b * p.y))
+        .collect();
+
+    // Denominators of `x = u / v` and `y = (u - 1) / (u + 1)`, inverted together.
+    let mut dens: Vec<_> = mont.iter().map(|(u, v)| *v * (*u + one)).collect();
+    batch_inversion(&mut dens);
+
+    points
+        .iter()
+        .zip(mont)
+        .zip(dens)
+        .map(|((p, (u, v)), den)| {
+            if p.is_zero() {
+                return Some(TEAffine::zero());
+            }
+            if u.is_zero() && v.is_zero() {
+                return Some(TEAffine::new_unchecked(C::BaseField::zero(), -one));
+            }
+            if den.is_zero() {
+                return None;
+            }
+            let x = u * (u + one) * den;
+            let y = (u - one) * v * den;
+            Some(TEAffine::new_unchecked(x, y))
+        })
+        .collect()
+}
@@ -1,12 +1,26 @@
 use crate::{
-    mock::{new_test_ext, ArkHostcalls, RuntimeOrigin},
+    mock::{backend, new_test_ext, ArkHostcalls, RuntimeOrigin, System, Test, ValidatePoints},
     utils::*,
+    ArkScale, BaseSetPage, BaseSetPages, BaseSets, Curve, Error, Event, LastResult, PairingCurve,
 };
-use ark_scale::scale::Encode;
-use frame_support::assert_ok;
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup, VariableBaseMSM};
+use ark_ff::PrimeField;
+use ark_scale::{
+    hazmat::ArkScaleProjective,
+    scale::{Decode, Encode},
+};
+use frame_support::{assert_noop, assert_ok, sp_runtime::DispatchError};
 
 const MSM_ITEMS: u32 = 256;
 const SCALAR_WORDS: u32 = 3;
+const PAIRING_ITEMS: u32 = 4;
+
+// Pairs `(a, b)` and `(-a, b)`, whose pairing product is the identity.
+fn make_pairing_check_args<P: Pairing>() -> (ArkScale<Vec<P::G1Affine>>, ArkScale<Vec<P::G2Affine>>)
+{
+    let (a, b) = make_pairing_args::<P::G1Affine, P::G2Affine>();
+    (vec![a.0, -a.0].into(), vec![b.0, b.0].into())
+}
 
 // ---------------------------------------------
 // Tests for bls12-381
@@ -20,7 +34,7 @@ fn bls12_381_pairing(optimized: bool) {
             RuntimeOrigin::none(),
             a.encode(),
             b.encode(),
-            optimized
+            backend(optimized)
         ));
     });
 }
@@ -43,7 +57,7 @@ fn bls12_381_msm_g1(optimized: bool) {
             RuntimeOrigin::none(),
             bases.encode(),
             scalars.encode(),
-            optimized,
+            backend(optimized),
         ));
     });
 }
@@ -66,7 +80,7 @@ fn bls12_381_mul_projective_g1(optimized: bool) {
             RuntimeOrigin::none(),
             base.encode(),
             scalar.encode(),
-            optimized
+            backend(optimized)
         ));
     });
 }
@@ -89,7 +103,7 @@ fn bls12_381_mul_affine_g1(optimized: bool) {
             RuntimeOrigin::none(),
             base.encode(),
             scalar.encode(),
-            optimized
+            backend(optimized)
         ));
     });
 }
@@ -112,7 +126,7 @@ fn bls12_381_msm_g2(optimized: bool) {
             RuntimeOrigin::none(),
             bases.encode(),
             scalars.encode(),
-            optimized,
+            backend(optimized),
         ));
     });
 }
@@ -135,7 +149,7 @@ fn bls12_381_mul_projective_g2(optimized: bool) {
             RuntimeOrigin::none(),
             base.encode(),
             scalar.encode(),
-            optimized
+            backend(optimized)
         ));
     });
 }
@@ -158,7 +172,7 @@ fn bls12_381_mul_affine_g2(optimized: bool) {
             RuntimeOrigin::none(),
             base.encode(),
             scalar.encode(),
-            optimized
+            backend(optimized)
         ));
     });
 }
@@ -173,231 +187,1901 @@ fn sub_bls12_381_mul_affine_g2() {
     bls12_381_mul_affine_g2(true)
 }
 
+fn bls12_381_multi_pairing(optimized: bool) {
+    let (a, b) = make_pairing_check_args::<ark_bls12_381::Bls12_381>();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::bls12_381_multi_pairing(
+            RuntimeOrigin::none(),
+            a.encode(),
+            b.encode(),
+            true,
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_bls12_381_multi_pairing() {
+    bls12_381_multi_pairing(false);
+}
+
+#[test]
+fn sub_bls12_381_multi_pairing() {
+    bls12_381_multi_pairing(true);
+}
+
+fn bls12_381_multi_miller_loop(optimized: bool) {
+    let (a, b) =
+        make_multi_pairing_args::<ark_bls12_381::G1Affine, ark_bls12_381::G2Affine>(PAIRING_ITEMS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::bls12_381_multi_miller_loop(
+            RuntimeOrigin::none(),
+            a.encode(),
+            b.encode(),
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_bls12_381_multi_miller_loop() {
+    bls12_381_multi_miller_loop(false);
+}
+
+#[test]
+fn sub_bls12_381_multi_miller_loop() {
+    bls12_381_multi_miller_loop(true);
+}
+
+fn bls12_381_final_exponentiation(optimized: bool) {
+    let target = make_final_exponentiation_args::<ark_bls12_381::Bls12_381>();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::bls12_381_final_exponentiation(
+            RuntimeOrigin::none(),
+            target.encode(),
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_bls12_381_final_exponentiation() {
+    bls12_381_final_exponentiation(false);
+}
+
+#[test]
+fn sub_bls12_381_final_exponentiation() {
+    bls12_381_final_exponentiation(true);
+}
+
+// RFC 9380 J.9.1 test vectors, as `(msg, P.x, P.y)`.
+const HASH_TO_G1_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+const HASH_TO_G1_VECTORS: [(&str, &str, &str); 2] = [
+    (
+        "",
+        "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1",
+        "08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265",
+    ),
+    (
+        "abc",
+        "03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903",
+        "0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d",
+    ),
+];
+
+// RFC 9380 J.10.1 test vectors, as `(msg, P.x, P.y)` with `(c0, c1)` coordinates.
+const HASH_TO_G2_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+const HASH_TO_G2_VECTORS: [(&str, (&str, &str), (&str, &str)); 2] = [
+    (
+        "",
+        (
+            "0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a",
+            "05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d",
+        ),
+        (
+            "0503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd92",
+            "12424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d6",
+        ),
+    ),
+    (
+        "abc",
+        (
+            "02c2d18e033b960562aae3cab37a27ce00d80ccd5ba4b7fe0e7a210245129dbec7780ccc7954725f4168aff2787776e6",
+            "139cddbccdc5e91b9623efd38c49f81a6f83f175e80b06fc374de9eb4b41dfe4ca3a230ed250fbe3a2acf73a41177fd8",
+        ),
+        (
+            "1787327b68159716a37440985269cf584bcb1e621d3a7202be6ea05c4cfe244aeb197642555a0645fb87bf7466b2ba48",
+            "00aa65dae3c8d732d10ecd2c50f8a1baf3001578f71c694e03866e9f3d49ac1e1ce70dd94a733534f106d4cec0eddd16",
+        ),
+    ),
+];
+
+fn fq(hex_str: &str) -> ark_bls12_381::Fq {
+    ark_bls12_381::Fq::from_be_bytes_mod_order(&hex::decode(hex_str).unwrap())
+}
+
+fn bls12_381_hash_to_g1(optimized: bool) {
+    new_test_ext().execute_with(|| {
+        for (msg, x, y) in HASH_TO_G1_VECTORS {
+            let expected = ark_bls12_381::G1Affine::new(fq(x), fq(y));
+            assert_ok!(ArkHostcalls::bls12_381_hash_to_g1(
+                RuntimeOrigin::none(),
+                HASH_TO_G1_DST.to_vec().try_into().unwrap(),
+                msg.as_bytes().to_vec().try_into().unwrap(),
+                backend(optimized)
+            ));
+            assert_eq!(
+                LastResult::<Test>::get(),
+                Some(ArkScale::from(expected).encode())
+            );
+        }
+    });
+}
+
+#[test]
+fn ark_bls12_381_hash_to_g1() {
+    bls12_381_hash_to_g1(false);
+}
+
+#[test]
+fn sub_bls12_381_hash_to_g1() {
+    bls12_381_hash_to_g1(true);
+}
+
+fn bls12_381_hash_to_g2(optimized: bool) {
+    new_test_ext().execute_with(|| {
+        for (msg, (x0, x1), (y0, y1)) in HASH_TO_G2_VECTORS {
+            let x = ark_bls12_381::Fq2::new(fq(x0), fq(x1));
+            let y = ark_bls12_381::Fq2::new(fq(y0), fq(y1));
+            let expected = ark_bls12_381::G2Affine::new(x, y);
+            assert_ok!(ArkHostcalls::bls12_381_hash_to_g2(
+                RuntimeOrigin::none(),
+                HASH_TO_G2_DST.to_vec().try_into().unwrap(),
+                msg.as_bytes().to_vec().try_into().unwrap(),
+                backend(optimized)
+            ));
+            assert_eq!(
+                LastResult::<Test>::get(),
+                Some(ArkScale::from(expected).encode())
+            );
+        }
+    });
+}
+
+#[test]
+fn ark_bls12_381_hash_to_g2() {
+    bls12_381_hash_to_g2(false);
+}
+
+#[test]
+fn sub_bls12_381_hash_to_g2() {
+    bls12_381_hash_to_g2(true);
+}
+
+#[test]
+fn hash_to_curve_rejects_oversized_inputs() {
+    use crate::{mock::MaxHashMsgLen, MAX_DST_LEN};
+
+    let decode_call = |dst_len: u32, msg_len: u32| {
+        let dst = vec![0xab_u8; dst_len as usize];
+        let msg = vec![0xcd_u8; msg_len as usize];
+        let call = (50_u8, dst, msg, backend(true)).encode();
+        crate::Call::<Test>::decode(&mut &call[..])
+    };
+
+    assert!(decode_call(MAX_DST_LEN, MaxHashMsgLen::get()).is_ok());
+    assert!(decode_call(MAX_DST_LEN + 1, MaxHashMsgLen::get()).is_err());
+    assert!(decode_call(MAX_DST_LEN, MaxHashMsgLen::get() + 1).is_err());
+}
+
 // ---------------------------------------------
-// Tests for ed-on-bls12-377
+// Tests for bls12-377
 // ---------------------------------------------
 
-fn ed_on_bls12_377_msm_te(optimized: bool) {
-    let (bases, scalars) = make_msm_args::<ark_ed_on_bls12_377::EdwardsProjective>(MSM_ITEMS);
+fn bls12_377_pairing(optimized: bool) {
+    let (a, b) = make_pairing_args::<ark_bls12_377::G1Affine, ark_bls12_377::G2Affine>();
 
     new_test_ext().execute_with(|| {
-        assert_ok!(ArkHostcalls::ed_on_bls12_377_msm_te(
+        assert_ok!(ArkHostcalls::bls12_377_pairing(
+            RuntimeOrigin::none(),
+            a.encode(),
+            b.encode(),
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_bls12_377_pairing() {
+    bls12_377_pairing(false);
+}
+
+#[test]
+fn sub_bls12_377_pairing() {
+    bls12_377_pairing(true);
+}
+
+fn bls12_377_msm_g1(optimized: bool) {
+    let (bases, scalars) = make_msm_args::<ark_bls12_377::G1Projective>(MSM_ITEMS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::bls12_377_msm_g1(
             RuntimeOrigin::none(),
             bases.encode(),
             scalars.encode(),
-            optimized
+            backend(optimized),
         ));
     });
 }
 
 #[test]
-fn ark_ed_on_bls12_377_msm_te() {
-    ed_on_bls12_377_msm_te(false);
+fn ark_bls12_377_msm_g1() {
+    bls12_377_msm_g1(false);
 }
 
 #[test]
-fn sub_ed_on_bls12_377_msm_te() {
-    ed_on_bls12_377_msm_te(true);
+fn sub_bls12_377_msm_g1() {
+    bls12_377_msm_g1(true);
 }
 
-fn ed_on_bls12_377_mul_projective_te(optimized: bool) {
-    let (base, scalar) =
-        make_scalar_args_projective::<ark_ed_on_bls12_377::EdwardsProjective>(SCALAR_WORDS);
+fn bls12_377_mul_projective_g1(optimized: bool) {
+    let (base, scalar) = make_scalar_args_projective::<ark_bls12_377::G1Projective>(SCALAR_WORDS);
 
     new_test_ext().execute_with(|| {
-        assert_ok!(ArkHostcalls::ed_on_bls12_377_mul_projective_te(
+        assert_ok!(ArkHostcalls::bls12_377_mul_projective_g1(
             RuntimeOrigin::none(),
             base.encode(),
             scalar.encode(),
-            optimized,
+            backend(optimized)
         ));
     });
 }
 
 #[test]
-fn ark_ed_on_bls12_377_mul_projective_te() {
-    ed_on_bls12_377_mul_projective_te(false)
+fn ark_bls12_377_mul_projective_g1() {
+    bls12_377_mul_projective_g1(false)
 }
 
 #[test]
-fn sub_ed_on_bls12_377_mul_projective_te() {
-    ed_on_bls12_377_mul_projective_te(true)
+fn sub_bls12_377_mul_projective_g1() {
+    bls12_377_mul_projective_g1(true)
 }
 
-fn ed_on_bls12_377_mul_affine_te(optimized: bool) {
-    let (base, scalar) = make_scalar_args::<ark_ed_on_bls12_377::EdwardsAffine>(SCALAR_WORDS);
+fn bls12_377_mul_affine_g1(optimized: bool) {
+    let (base, scalar) = make_scalar_args::<ark_bls12_377::G1Affine>(SCALAR_WORDS);
 
     new_test_ext().execute_with(|| {
-        assert_ok!(ArkHostcalls::ed_on_bls12_377_mul_affine_te(
+        assert_ok!(ArkHostcalls::bls12_377_mul_affine_g1(
             RuntimeOrigin::none(),
             base.encode(),
             scalar.encode(),
-            optimized
+            backend(optimized)
         ));
     });
 }
 
 #[test]
-fn ark_ed_on_bls12_377_mul_affine_te() {
-    ed_on_bls12_377_mul_affine_te(false)
+fn ark_bls12_377_mul_affine_g1() {
+    bls12_377_mul_affine_g1(false)
 }
+
 #[test]
-fn sub_ed_on_bls12_377_mul_affine_te() {
-    ed_on_bls12_377_mul_affine_te(true)
+fn sub_bls12_377_mul_affine_g1() {
+    bls12_377_mul_affine_g1(true)
 }
 
-// ---------------------------------------------
-// Tests for ed-on-bls12-381-bandersnatch
-// ---------------------------------------------
-
-// Short Weierstrass
-
-fn ed_on_bls12_381_bandersnatch_msm_sw(optimized: bool) {
-    let (bases, scalars) =
-        make_msm_args::<ark_ed_on_bls12_381_bandersnatch::SWProjective>(MSM_ITEMS);
+fn bls12_377_msm_g2(optimized: bool) {
+    let (bases, scalars) = make_msm_args::<ark_bls12_377::G2Projective>(MSM_ITEMS);
 
     new_test_ext().execute_with(|| {
-        assert_ok!(ArkHostcalls::ed_on_bls12_381_bandersnatch_msm_sw(
+        assert_ok!(ArkHostcalls::bls12_377_msm_g2(
             RuntimeOrigin::none(),
             bases.encode(),
             scalars.encode(),
-            optimized,
+            backend(optimized),
         ));
     });
 }
 
 #[test]
-fn ark_ed_on_bls12_381_bandersnatch_msm_sw() {
-    ed_on_bls12_381_bandersnatch_msm_sw(false);
+fn ark_bls12_377_msm_g2() {
+    bls12_377_msm_g2(false);
 }
 
 #[test]
-fn sub_ed_on_bls12_381_bandersnatch_msm_sw() {
-    ed_on_bls12_381_bandersnatch_msm_sw(true);
+fn sub_bls12_377_msm_g2() {
+    bls12_377_msm_g2(true);
 }
 
-fn ed_on_bls12_381_bandersnatch_mul_projective_sw(optimized: bool) {
-    let (base, scalar) =
-        make_scalar_args_projective::<ark_ed_on_bls12_381_bandersnatch::SWProjective>(SCALAR_WORDS);
+fn bls12_377_mul_projective_g2(optimized: bool) {
+    let (base, scalar) = make_scalar_args_projective::<ark_bls12_377::G2Projective>(SCALAR_WORDS);
 
     new_test_ext().execute_with(|| {
-        assert_ok!(
-            ArkHostcalls::ed_on_bls12_381_bandersnatch_mul_projective_sw(
-                RuntimeOrigin::none(),
-                base.encode(),
-                scalar.encode(),
-                optimized
-            )
-        );
+        assert_ok!(ArkHostcalls::bls12_377_mul_projective_g2(
+            RuntimeOrigin::none(),
+            base.encode(),
+            scalar.encode(),
+            backend(optimized)
+        ));
     });
 }
 
 #[test]
-fn ark_ed_on_bls12_381_bandersnatch_mul_projective_sw() {
-    ed_on_bls12_381_bandersnatch_mul_projective_sw(false)
+fn ark_bls12_377_mul_projective_g2() {
+    bls12_377_mul_projective_g2(false)
 }
 
 #[test]
-fn sub_ed_on_bls12_381_bandersnatch_mul_projective_sw() {
-    ed_on_bls12_381_bandersnatch_mul_projective_sw(true)
+fn sub_bls12_377_mul_projective_g2() {
+    bls12_377_mul_projective_g2(true)
 }
 
-fn ed_on_bls12_381_bandersnatch_mul_affine_sw(optimized: bool) {
-    let (base, scalar) =
-        make_scalar_args::<ark_ed_on_bls12_381_bandersnatch::SWAffine>(SCALAR_WORDS);
+fn bls12_377_mul_affine_g2(optimized: bool) {
+    let (base, scalar) = make_scalar_args::<ark_bls12_377::G2Affine>(SCALAR_WORDS);
 
     new_test_ext().execute_with(|| {
-        assert_ok!(ArkHostcalls::ed_on_bls12_381_bandersnatch_mul_affine_sw(
+        assert_ok!(ArkHostcalls::bls12_377_mul_affine_g2(
             RuntimeOrigin::none(),
             base.encode(),
             scalar.encode(),
-            optimized
+            backend(optimized)
         ));
     });
 }
 
 #[test]
-fn ark_ed_on_bls12_381_bandersnatch_mul_affine_sw() {
-    ed_on_bls12_381_bandersnatch_mul_affine_sw(false)
+fn ark_bls12_377_mul_affine_g2() {
+    bls12_377_mul_affine_g2(false)
 }
 
 #[test]
-fn sub_ed_on_bls12_381_bandersnatch_mul_affine_sw() {
-    ed_on_bls12_381_bandersnatch_mul_affine_sw(true)
+fn sub_bls12_377_mul_affine_g2() {
+    bls12_377_mul_affine_g2(true)
 }
 
-// Twisted Edwards
+fn bls12_377_multi_pairing(optimized: bool) {
+    let (a, b) = make_pairing_check_args::<ark_bls12_377::Bls12_377>();
 
-fn ed_on_bls12_381_bandersnatch_msm_te(optimized: bool) {
-    let (bases, scalars) =
-        make_msm_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsProjective>(MSM_ITEMS);
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::bls12_377_multi_pairing(
+            RuntimeOrigin::none(),
+            a.encode(),
+            b.encode(),
+            true,
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_bls12_377_multi_pairing() {
+    bls12_377_multi_pairing(false);
+}
+
+#[test]
+fn sub_bls12_377_multi_pairing() {
+    bls12_377_multi_pairing(true);
+}
+
+fn bls12_377_multi_miller_loop(optimized: bool) {
+    let (a, b) =
+        make_multi_pairing_args::<ark_bls12_377::G1Affine, ark_bls12_377::G2Affine>(PAIRING_ITEMS);
 
     new_test_ext().execute_with(|| {
-        assert_ok!(ArkHostcalls::ed_on_bls12_381_bandersnatch_msm_te(
+        assert_ok!(ArkHostcalls::bls12_377_multi_miller_loop(
+            RuntimeOrigin::none(),
+            a.encode(),
+            b.encode(),
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_bls12_377_multi_miller_loop() {
+    bls12_377_multi_miller_loop(false);
+}
+
+#[test]
+fn sub_bls12_377_multi_miller_loop() {
+    bls12_377_multi_miller_loop(true);
+}
+
+fn bls12_377_final_exponentiation(optimized: bool) {
+    let target = make_final_exponentiation_args::<ark_bls12_377::Bls12_377>();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::bls12_377_final_exponentiation(
+            RuntimeOrigin::none(),
+            target.encode(),
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_bls12_377_final_exponentiation() {
+    bls12_377_final_exponentiation(false);
+}
+
+#[test]
+fn sub_bls12_377_final_exponentiation() {
+    bls12_377_final_exponentiation(true);
+}
+
+// ---------------------------------------------
+// Tests for bw6-761
+// ---------------------------------------------
+
+fn bw6_761_pairing(optimized: bool) {
+    let (a, b) = make_pairing_args::<ark_bw6_761::G1Affine, ark_bw6_761::G2Affine>();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::bw6_761_pairing(
+            RuntimeOrigin::none(),
+            a.encode(),
+            b.encode(),
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_bw6_761_pairing() {
+    bw6_761_pairing(false);
+}
+
+#[test]
+fn sub_bw6_761_pairing() {
+    bw6_761_pairing(true);
+}
+
+fn bw6_761_msm_g1(optimized: bool) {
+    let (bases, scalars) = make_msm_args::<ark_bw6_761::G1Projective>(MSM_ITEMS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::bw6_761_msm_g1(
             RuntimeOrigin::none(),
             bases.encode(),
             scalars.encode(),
-            optimized
+            backend(optimized),
         ));
     });
 }
 
 #[test]
-fn ark_ed_on_bls12_381_bandersnatch_msm_te() {
-    ed_on_bls12_381_bandersnatch_msm_te(false);
+fn ark_bw6_761_msm_g1() {
+    bw6_761_msm_g1(false);
 }
 
 #[test]
-fn sub_ed_on_bls12_381_bandersnatch_msm_te() {
-    ed_on_bls12_381_bandersnatch_msm_te(true);
+fn sub_bw6_761_msm_g1() {
+    bw6_761_msm_g1(true);
 }
 
-fn ed_on_bls12_381_bandersnatch_mul_projective_te(optimized: bool) {
-    let (base, scalar) = make_scalar_args_projective::<
-        ark_ed_on_bls12_381_bandersnatch::EdwardsProjective,
-    >(SCALAR_WORDS);
+fn bw6_761_mul_projective_g1(optimized: bool) {
+    let (base, scalar) = make_scalar_args_projective::<ark_bw6_761::G1Projective>(SCALAR_WORDS);
 
     new_test_ext().execute_with(|| {
-        assert_ok!(
-            ArkHostcalls::ed_on_bls12_381_bandersnatch_mul_projective_te(
-                RuntimeOrigin::none(),
-                base.encode(),
-                scalar.encode(),
-                optimized,
-            )
-        );
+        assert_ok!(ArkHostcalls::bw6_761_mul_projective_g1(
+            RuntimeOrigin::none(),
+            base.encode(),
+            scalar.encode(),
+            backend(optimized)
+        ));
     });
 }
 
 #[test]
-fn ark_ed_on_bls12_381_bandersnatch_mul_projective_te() {
-    ed_on_bls12_381_bandersnatch_mul_projective_te(false)
+fn ark_bw6_761_mul_projective_g1() {
+    bw6_761_mul_projective_g1(false)
 }
 
 #[test]
-fn sub_ed_on_bls12_381_bandersnatch_mul_projective_te() {
-    ed_on_bls12_381_bandersnatch_mul_projective_te(true)
+fn sub_bw6_761_mul_projective_g1() {
+    bw6_761_mul_projective_g1(true)
 }
 
-fn ed_on_bls12_381_bandersnatch_mul_affine_te(optimized: bool) {
-    let (base, scalar) =
-        make_scalar_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsAffine>(SCALAR_WORDS);
+fn bw6_761_mul_affine_g1(optimized: bool) {
+    let (base, scalar) = make_scalar_args::<ark_bw6_761::G1Affine>(SCALAR_WORDS);
 
     new_test_ext().execute_with(|| {
-        assert_ok!(ArkHostcalls::ed_on_bls12_381_bandersnatch_mul_affine_te(
+        assert_ok!(ArkHostcalls::bw6_761_mul_affine_g1(
             RuntimeOrigin::none(),
             base.encode(),
             scalar.encode(),
-            optimized
+            backend(optimized)
         ));
     });
 }
 
 #[test]
-fn ark_ed_on_bls12_381_bandersnatch_mul_affine_te() {
-    ed_on_bls12_381_bandersnatch_mul_affine_te(false)
+fn ark_bw6_761_mul_affine_g1() {
+    bw6_761_mul_affine_g1(false)
 }
+
 #[test]
-fn sub_ed_on_bls12_381_bandersnatch_mul_affine_te() {
-    ed_on_bls12_381_bandersnatch_mul_affine_te(true)
+fn sub_bw6_761_mul_affine_g1() {
+    bw6_761_mul_affine_g1(true)
+}
+
+fn bw6_761_msm_g2(optimized: bool) {
+    let (bases, scalars) = make_msm_args::<ark_bw6_761::G2Projective>(MSM_ITEMS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::bw6_761_msm_g2(
+            RuntimeOrigin::none(),
+            bases.encode(),
+            scalars.encode(),
+            backend(optimized),
+        ));
+    });
+}
+
+#[test]
+fn ark_bw6_761_msm_g2() {
+    bw6_761_msm_g2(false);
+}
+
+#[test]
+fn sub_bw6_761_msm_g2() {
+    bw6_761_msm_g2(true);
+}
+
+fn bw6_761_mul_projective_g2(optimized: bool) {
+    let (base, scalar) = make_scalar_args_projective::<ark_bw6_761::G2Projective>(SCALAR_WORDS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::bw6_761_mul_projective_g2(
+            RuntimeOrigin::none(),
+            base.encode(),
+            scalar.encode(),
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_bw6_761_mul_projective_g2() {
+    bw6_761_mul_projective_g2(false)
+}
+
+#[test]
+fn sub_bw6_761_mul_projective_g2() {
+    bw6_761_mul_projective_g2(true)
+}
+
+fn bw6_761_mul_affine_g2(optimized: bool) {
+    let (base, scalar) = make_scalar_args::<ark_bw6_761::G2Affine>(SCALAR_WORDS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::bw6_761_mul_affine_g2(
+            RuntimeOrigin::none(),
+            base.encode(),
+            scalar.encode(),
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_bw6_761_mul_affine_g2() {
+    bw6_761_mul_affine_g2(false)
+}
+
+#[test]
+fn sub_bw6_761_mul_affine_g2() {
+    bw6_761_mul_affine_g2(true)
+}
+
+fn bw6_761_multi_pairing(optimized: bool) {
+    let (a, b) = make_pairing_check_args::<ark_bw6_761::BW6_761>();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::bw6_761_multi_pairing(
+            RuntimeOrigin::none(),
+            a.encode(),
+            b.encode(),
+            true,
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_bw6_761_multi_pairing() {
+    bw6_761_multi_pairing(false);
+}
+
+#[test]
+fn sub_bw6_761_multi_pairing() {
+    bw6_761_multi_pairing(true);
+}
+
+fn bw6_761_multi_miller_loop(optimized: bool) {
+    let (a, b) =
+        make_multi_pairing_args::<ark_bw6_761::G1Affine, ark_bw6_761::G2Affine>(PAIRING_ITEMS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::bw6_761_multi_miller_loop(
+            RuntimeOrigin::none(),
+            a.encode(),
+            b.encode(),
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_bw6_761_multi_miller_loop() {
+    bw6_761_multi_miller_loop(false);
+}
+
+#[test]
+fn sub_bw6_761_multi_miller_loop() {
+    bw6_761_multi_miller_loop(true);
+}
+
+fn bw6_761_final_exponentiation(optimized: bool) {
+    let target = make_final_exponentiation_args::<ark_bw6_761::BW6_761>();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::bw6_761_final_exponentiation(
+            RuntimeOrigin::none(),
+            target.encode(),
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_bw6_761_final_exponentiation() {
+    bw6_761_final_exponentiation(false);
+}
+
+#[test]
+fn sub_bw6_761_final_exponentiation() {
+    bw6_761_final_exponentiation(true);
+}
+
+// ---------------------------------------------
+// Tests for ed-on-bls12-377
+// ---------------------------------------------
+
+fn ed_on_bls12_377_msm_te(optimized: bool) {
+    let (bases, scalars) = make_msm_args::<ark_ed_on_bls12_377::EdwardsProjective>(MSM_ITEMS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::ed_on_bls12_377_msm_te(
+            RuntimeOrigin::none(),
+            bases.encode(),
+            scalars.encode(),
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_ed_on_bls12_377_msm_te() {
+    ed_on_bls12_377_msm_te(false);
+}
+
+#[test]
+fn sub_ed_on_bls12_377_msm_te() {
+    ed_on_bls12_377_msm_te(true);
+}
+
+fn ed_on_bls12_377_mul_projective_te(optimized: bool) {
+    let (base, scalar) =
+        make_scalar_args_projective::<ark_ed_on_bls12_377::EdwardsProjective>(SCALAR_WORDS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::ed_on_bls12_377_mul_projective_te(
+            RuntimeOrigin::none(),
+            base.encode(),
+            scalar.encode(),
+            backend(optimized),
+        ));
+    });
+}
+
+#[test]
+fn ark_ed_on_bls12_377_mul_projective_te() {
+    ed_on_bls12_377_mul_projective_te(false)
+}
+
+#[test]
+fn sub_ed_on_bls12_377_mul_projective_te() {
+    ed_on_bls12_377_mul_projective_te(true)
+}
+
+fn ed_on_bls12_377_mul_affine_te(optimized: bool) {
+    let (base, scalar) = make_scalar_args::<ark_ed_on_bls12_377::EdwardsAffine>(SCALAR_WORDS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::ed_on_bls12_377_mul_affine_te(
+            RuntimeOrigin::none(),
+            base.encode(),
+            scalar.encode(),
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_ed_on_bls12_377_mul_affine_te() {
+    ed_on_bls12_377_mul_affine_te(false)
+}
+#[test]
+fn sub_ed_on_bls12_377_mul_affine_te() {
+    ed_on_bls12_377_mul_affine_te(true)
+}
+
+// ---------------------------------------------
+// Tests for ed-on-bls12-381-bandersnatch
+// ---------------------------------------------
+
+// Short Weierstrass
+
+fn ed_on_bls12_381_bandersnatch_msm_sw(optimized: bool) {
+    let (bases, scalars) =
+        make_msm_args::<ark_ed_on_bls12_381_bandersnatch::SWProjective>(MSM_ITEMS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::ed_on_bls12_381_bandersnatch_msm_sw(
+            RuntimeOrigin::none(),
+            bases.encode(),
+            scalars.encode(),
+            backend(optimized),
+        ));
+    });
+}
+
+#[test]
+fn ark_ed_on_bls12_381_bandersnatch_msm_sw() {
+    ed_on_bls12_381_bandersnatch_msm_sw(false);
+}
+
+#[test]
+fn sub_ed_on_bls12_381_bandersnatch_msm_sw() {
+    ed_on_bls12_381_bandersnatch_msm_sw(true);
+}
+
+fn ed_on_bls12_381_bandersnatch_mul_projective_sw(optimized: bool) {
+    let (base, scalar) =
+        make_scalar_args_projective::<ark_ed_on_bls12_381_bandersnatch::SWProjective>(SCALAR_WORDS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(
+            ArkHostcalls::ed_on_bls12_381_bandersnatch_mul_projective_sw(
+                RuntimeOrigin::none(),
+                base.encode(),
+                scalar.encode(),
+                backend(optimized)
+            )
+        );
+    });
+}
+
+#[test]
+fn ark_ed_on_bls12_381_bandersnatch_mul_projective_sw() {
+    ed_on_bls12_381_bandersnatch_mul_projective_sw(false)
+}
+
+#[test]
+fn sub_ed_on_bls12_381_bandersnatch_mul_projective_sw() {
+    ed_on_bls12_381_bandersnatch_mul_projective_sw(true)
+}
+
+fn ed_on_bls12_381_bandersnatch_mul_affine_sw(optimized: bool) {
+    let (base, scalar) =
+        make_scalar_args::<ark_ed_on_bls12_381_bandersnatch::SWAffine>(SCALAR_WORDS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::ed_on_bls12_381_bandersnatch_mul_affine_sw(
+            RuntimeOrigin::none(),
+            base.encode(),
+            scalar.encode(),
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_ed_on_bls12_381_bandersnatch_mul_affine_sw() {
+    ed_on_bls12_381_bandersnatch_mul_affine_sw(false)
+}
+
+#[test]
+fn sub_ed_on_bls12_381_bandersnatch_mul_affine_sw() {
+    ed_on_bls12_381_bandersnatch_mul_affine_sw(true)
+}
+
+// Twisted Edwards
+
+fn ed_on_bls12_381_bandersnatch_msm_te(optimized: bool) {
+    let (bases, scalars) =
+        make_msm_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsProjective>(MSM_ITEMS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::ed_on_bls12_381_bandersnatch_msm_te(
+            RuntimeOrigin::none(),
+            bases.encode(),
+            scalars.encode(),
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_ed_on_bls12_381_bandersnatch_msm_te() {
+    ed_on_bls12_381_bandersnatch_msm_te(false);
+}
+
+#[test]
+fn sub_ed_on_bls12_381_bandersnatch_msm_te() {
+    ed_on_bls12_381_bandersnatch_msm_te(true);
+}
+
+fn ed_on_bls12_381_bandersnatch_mul_projective_te(optimized: bool) {
+    let (base, scalar) = make_scalar_args_projective::<
+        ark_ed_on_bls12_381_bandersnatch::EdwardsProjective,
+    >(SCALAR_WORDS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(
+            ArkHostcalls::ed_on_bls12_381_bandersnatch_mul_projective_te(
+                RuntimeOrigin::none(),
+                base.encode(),
+                scalar.encode(),
+                backend(optimized),
+            )
+        );
+    });
+}
+
+#[test]
+fn ark_ed_on_bls12_381_bandersnatch_mul_projective_te() {
+    ed_on_bls12_381_bandersnatch_mul_projective_te(false)
+}
+
+#[test]
+fn sub_ed_on_bls12_381_bandersnatch_mul_projective_te() {
+    ed_on_bls12_381_bandersnatch_mul_projective_te(true)
+}
+
+fn ed_on_bls12_381_bandersnatch_mul_affine_te(optimized: bool) {
+    let (base, scalar) =
+        make_scalar_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsAffine>(SCALAR_WORDS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::ed_on_bls12_381_bandersnatch_mul_affine_te(
+            RuntimeOrigin::none(),
+            base.encode(),
+            scalar.encode(),
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_ed_on_bls12_381_bandersnatch_mul_affine_te() {
+    ed_on_bls12_381_bandersnatch_mul_affine_te(false)
+}
+#[test]
+fn sub_ed_on_bls12_381_bandersnatch_mul_affine_te() {
+    ed_on_bls12_381_bandersnatch_mul_affine_te(true)
+}
+
+// ---------------------------------------------
+// Tests for operation results
+// ---------------------------------------------
+
+fn bls12_381_msm_g1_result(optimized: bool) {
+    let (bases, scalars) = make_msm_args::<ark_bls12_381::G1Projective>(MSM_ITEMS);
+    let bases_affine = ark_bls12_381::G1Projective::normalize_batch(&bases.0);
+    let expected = ark_bls12_381::G1Projective::msm(&bases_affine, &scalars.0).unwrap();
+    let expected = ArkScale::from(expected.into_affine()).encode();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::bls12_381_msm_g1(
+            RuntimeOrigin::none(),
+            bases.encode(),
+            scalars.encode(),
+            backend(optimized),
+        ));
+        assert_eq!(LastResult::<Test>::get(), Some(expected.clone()));
+        System::assert_last_event(Event::<Test>::Computed { output: expected }.into());
+    });
+}
+
+#[test]
+fn ark_bls12_381_msm_g1_result() {
+    bls12_381_msm_g1_result(false);
+}
+
+#[test]
+fn sub_bls12_381_msm_g1_result() {
+    bls12_381_msm_g1_result(true);
+}
+
+fn bls12_381_pairing_result(optimized: bool) {
+    let (a, b) = make_pairing_args::<ark_bls12_381::G1Affine, ark_bls12_381::G2Affine>();
+    let expected = ark_bls12_381::Bls12_381::pairing(a.0, b.0);
+    let expected = ArkScale::from(expected).encode();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::bls12_381_pairing(
+            RuntimeOrigin::none(),
+            a.encode(),
+            b.encode(),
+            backend(optimized)
+        ));
+        assert_eq!(LastResult::<Test>::get(), Some(expected.clone()));
+        System::assert_last_event(Event::<Test>::Computed { output: expected }.into());
+    });
+}
+
+#[test]
+fn ark_bls12_381_pairing_result() {
+    bls12_381_pairing_result(false);
+}
+
+#[test]
+fn sub_bls12_381_pairing_result() {
+    bls12_381_pairing_result(true);
+}
+
+fn bls12_381_multi_pairing_result(optimized: bool) {
+    let (a, b) =
+        make_multi_pairing_args::<ark_bls12_381::G1Affine, ark_bls12_381::G2Affine>(PAIRING_ITEMS);
+    let expected = ark_bls12_381::Bls12_381::multi_pairing(a.0.clone(), b.0.clone());
+    let expected = ArkScale::from(expected).encode();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::bls12_381_multi_pairing(
+            RuntimeOrigin::none(),
+            a.encode(),
+            b.encode(),
+            false,
+            backend(optimized)
+        ));
+        assert_eq!(LastResult::<Test>::get(), Some(expected.clone()));
+        System::assert_last_event(Event::<Test>::Computed { output: expected }.into());
+    });
+}
+
+#[test]
+fn ark_bls12_381_multi_pairing_result() {
+    bls12_381_multi_pairing_result(false);
+}
+
+#[test]
+fn sub_bls12_381_multi_pairing_result() {
+    bls12_381_multi_pairing_result(true);
+}
+
+// Miller loop followed by final exponentiation matches the pairing output.
+fn bls12_381_split_pairing_result(optimized: bool) {
+    let (a, b) =
+        make_multi_pairing_args::<ark_bls12_381::G1Affine, ark_bls12_381::G2Affine>(PAIRING_ITEMS);
+    let expected = ark_bls12_381::Bls12_381::multi_pairing(a.0.clone(), b.0.clone());
+    let expected = ArkScale::from(expected).encode();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::bls12_381_multi_miller_loop(
+            RuntimeOrigin::none(),
+            a.encode(),
+            b.encode(),
+            backend(optimized)
+        ));
+        let target = LastResult::<Test>::get().unwrap();
+        assert_ok!(ArkHostcalls::bls12_381_final_exponentiation(
+            RuntimeOrigin::none(),
+            target,
+            backend(optimized)
+        ));
+        assert_eq!(LastResult::<Test>::get(), Some(expected));
+    });
+}
+
+#[test]
+fn ark_bls12_381_split_pairing_result() {
+    bls12_381_split_pairing_result(false);
+}
+
+#[test]
+fn sub_bls12_381_split_pairing_result() {
+    bls12_381_split_pairing_result(true);
+}
+
+// ---------------------------------------------
+// Tests for ed-on-bls12-381-bandersnatch conversions
+// ---------------------------------------------
+
+#[test]
+fn ed_on_bls12_381_bandersnatch_te_to_sw_maps_generator() {
+    let point = ArkScale::from(ark_ed_on_bls12_381_bandersnatch::EdwardsAffine::generator());
+    let expected = ArkScale::from(ark_ed_on_bls12_381_bandersnatch::SWAffine::generator()).encode();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::ed_on_bls12_381_bandersnatch_te_to_sw(
+            RuntimeOrigin::none(),
+            point.encode(),
+        ));
+        assert_eq!(LastResult::<Test>::get(), Some(expected));
+    });
+}
+
+#[test]
+fn ed_on_bls12_381_bandersnatch_sw_to_te_maps_generator() {
+    let point = ArkScale::from(ark_ed_on_bls12_381_bandersnatch::SWAffine::generator());
+    let expected =
+        ArkScale::from(ark_ed_on_bls12_381_bandersnatch::EdwardsAffine::generator()).encode();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::ed_on_bls12_381_bandersnatch_sw_to_te(
+            RuntimeOrigin::none(),
+            point.encode(),
+        ));
+        assert_eq!(LastResult::<Test>::get(), Some(expected));
+    });
+}
+
+#[test]
+fn ed_on_bls12_381_bandersnatch_batch_conversion_roundtrip() {
+    use ark_ed_on_bls12_381_bandersnatch::{EdwardsAffine, SWAffine};
+
+    // Generators map to each other, thus so do their multiples.
+    let (_, scalars) =
+        make_msm_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsProjective>(MSM_ITEMS);
+    let te_points = scalars
+        .0
+        .iter()
+        .map(|k| (EdwardsAffine::generator() * k).into_affine());
+    let te_points = ArkScale::from(te_points.collect::<Vec<_>>()).encode();
+    let sw_points = scalars
+        .0
+        .iter()
+        .map(|k| (SWAffine::generator() * k).into_affine());
+    let sw_points = ArkScale::from(sw_points.collect::<Vec<_>>()).encode();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::ed_on_bls12_381_bandersnatch_te_to_sw_batch(
+            RuntimeOrigin::none(),
+            te_points.clone(),
+        ));
+        assert_eq!(LastResult::<Test>::get(), Some(sw_points.clone()));
+
+        assert_ok!(ArkHostcalls::ed_on_bls12_381_bandersnatch_sw_to_te_batch(
+            RuntimeOrigin::none(),
+            sw_points,
+        ));
+        assert_eq!(LastResult::<Test>::get(), Some(te_points));
+    });
+}
+
+// ---------------------------------------------
+// Tests for fixed base multi scalar multiplication
+// ---------------------------------------------
+
+const BASE_SET_ID: u32 = 7;
+
+fn bls12_381_msm_fixed_base_g1(optimized: bool) {
+    let (bases, scalars) = make_msm_args::<ark_bls12_381::G1Projective>(MSM_ITEMS);
+    let bases_affine = ark_bls12_381::G1Projective::normalize_batch(&bases.0);
+    let expected = ark_bls12_381::G1Projective::msm(&bases_affine, &scalars.0).unwrap();
+    let expected = ArkScale::from(expected.into_affine()).encode();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::register_base_set(
+            RuntimeOrigin::root(),
+            BASE_SET_ID,
+            Curve::Bls12_381G1,
+            bases.encode(),
+        ));
+        System::assert_last_event(
+            Event::<Test>::BaseSetRegistered {
+                set_id: BASE_SET_ID,
+                curve: Curve::Bls12_381G1,
+                len: MSM_ITEMS,
+            }
+            .into(),
+        );
+        assert_ok!(ArkHostcalls::msm_fixed_base(
+            RuntimeOrigin::none(),
+            BASE_SET_ID,
+            scalars.encode(),
+            backend(optimized),
+        ));
+        assert_eq!(LastResult::<Test>::get(), Some(expected));
+    });
+}
+
+#[test]
+fn ark_bls12_381_msm_fixed_base_g1() {
+    bls12_381_msm_fixed_base_g1(false);
+}
+
+#[test]
+fn sub_bls12_381_msm_fixed_base_g1() {
+    bls12_381_msm_fixed_base_g1(true);
+}
+
+fn ed_on_bls12_381_bandersnatch_msm_fixed_base_te(optimized: bool) {
+    let (bases, scalars) =
+        make_msm_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsProjective>(MSM_ITEMS);
+    // Only a prefix of the registered bases is used.
+    let scalars = ArkScale::from(scalars.0[..MSM_ITEMS as usize / 3].to_vec());
+    let bases_affine =
+        ark_ed_on_bls12_381_bandersnatch::EdwardsProjective::normalize_batch(&bases.0);
+    let expected = ark_ed_on_bls12_381_bandersnatch::EdwardsProjective::msm(
+        &bases_affine[..scalars.0.len()],
+        &scalars.0,
+    )
+    .unwrap();
+    let expected = ArkScale::from(expected.into_affine()).encode();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::register_base_set(
+            RuntimeOrigin::root(),
+            BASE_SET_ID,
+            Curve::EdOnBls12_381BandersnatchTe,
+            bases.encode(),
+        ));
+        assert_ok!(ArkHostcalls::msm_fixed_base(
+            RuntimeOrigin::none(),
+            BASE_SET_ID,
+            scalars.encode(),
+            backend(optimized),
+        ));
+        assert_eq!(LastResult::<Test>::get(), Some(expected));
+    });
+}
+
+#[test]
+fn ark_ed_on_bls12_381_bandersnatch_msm_fixed_base_te() {
+    ed_on_bls12_381_bandersnatch_msm_fixed_base_te(false);
+}
+
+#[test]
+fn sub_ed_on_bls12_381_bandersnatch_msm_fixed_base_te() {
+    ed_on_bls12_381_bandersnatch_msm_fixed_base_te(true);
+}
+
+#[test]
+fn register_base_set_requires_origin() {
+    let (bases, _) = make_msm_args::<ark_bls12_381::G1Projective>(MSM_ITEMS);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkHostcalls::register_base_set(
+                RuntimeOrigin::none(),
+                BASE_SET_ID,
+                Curve::Bls12_381G1,
+                bases.encode(),
+            ),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn register_base_set_twice_fails() {
+    let (bases, _) = make_msm_args::<ark_bls12_381::G1Projective>(MSM_ITEMS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::register_base_set(
+            RuntimeOrigin::root(),
+            BASE_SET_ID,
+            Curve::Bls12_381G1,
+            bases.encode(),
+        ));
+        assert_noop!(
+            ArkHostcalls::register_base_set(
+                RuntimeOrigin::root(),
+                BASE_SET_ID,
+                Curve::Bls12_381G1,
+                bases.encode(),
+            ),
+            Error::<Test>::BaseSetExists
+        );
+    });
+}
+
+#[test]
+fn msm_fixed_base_with_too_many_scalars_fails() {
+    let (bases, _) = make_msm_args::<ark_bls12_381::G1Projective>(MSM_ITEMS - 1);
+    let (_, scalars) = make_msm_args::<ark_bls12_381::G1Projective>(MSM_ITEMS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::register_base_set(
+            RuntimeOrigin::root(),
+            BASE_SET_ID,
+            Curve::Bls12_381G1,
+            bases.encode(),
+        ));
+        assert_noop!(
+            ArkHostcalls::msm_fixed_base(
+                RuntimeOrigin::none(),
+                BASE_SET_ID,
+                scalars.encode(),
+                backend(true),
+            ),
+            Error::<Test>::LengthMismatch
+        );
+    });
+}
+
+#[test]
+fn remove_base_set_works() {
+    let (bases, scalars) = make_msm_args::<ark_bls12_381::G1Projective>(MSM_ITEMS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::register_base_set(
+            RuntimeOrigin::root(),
+            BASE_SET_ID,
+            Curve::Bls12_381G1,
+            bases.encode(),
+        ));
+        assert!(BaseSetPages::<Test>::iter_prefix(BASE_SET_ID).count() > 0);
+
+        assert_ok!(ArkHostcalls::remove_base_set(
+            RuntimeOrigin::root(),
+            BASE_SET_ID
+        ));
+        System::assert_last_event(
+            Event::<Test>::BaseSetRemoved {
+                set_id: BASE_SET_ID,
+            }
+            .into(),
+        );
+        assert_eq!(BaseSets::<Test>::get(BASE_SET_ID), None);
+        assert_eq!(BaseSetPages::<Test>::iter_prefix(BASE_SET_ID).count(), 0);
+
+        assert_noop!(
+            ArkHostcalls::msm_fixed_base(
+                RuntimeOrigin::none(),
+                BASE_SET_ID,
+                scalars.encode(),
+                backend(true),
+            ),
+            Error::<Test>::UnknownBaseSet
+        );
+    });
+}
+
+#[test]
+fn msm_fixed_base_with_missing_page_fails() {
+    let (bases, scalars) = make_msm_args::<ark_bls12_381::G1Projective>(MSM_ITEMS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::register_base_set(
+            RuntimeOrigin::root(),
+            BASE_SET_ID,
+            Curve::Bls12_381G1,
+            bases.encode(),
+        ));
+        BaseSetPages::<Test>::remove(BASE_SET_ID, 0);
+
+        assert_noop!(
+            ArkHostcalls::msm_fixed_base(
+                RuntimeOrigin::none(),
+                BASE_SET_ID,
+                scalars.encode(),
+                backend(true),
+            ),
+            Error::<Test>::UnknownBaseSet
+        );
+    });
+}
+
+#[test]
+fn msm_fixed_base_with_undecodable_page_fails() {
+    let (bases, scalars) = make_msm_args::<ark_bls12_381::G1Projective>(MSM_ITEMS);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::register_base_set(
+            RuntimeOrigin::root(),
+            BASE_SET_ID,
+            Curve::Bls12_381G1,
+            bases.encode(),
+        ));
+        // One base declared, none present.
+        let page = BaseSetPage::truncate_from(1_u64.to_le_bytes().to_vec());
+        BaseSetPages::<Test>::insert(BASE_SET_ID, 0, page);
+
+        assert_noop!(
+            ArkHostcalls::msm_fixed_base(
+                RuntimeOrigin::none(),
+                BASE_SET_ID,
+                scalars.encode(),
+                backend(true),
+            ),
+            Error::<Test>::UnknownBaseSet
+        );
+    });
+}
+
+// ---------------------------------------------
+// Tests for alternative scalar encodings
+// ---------------------------------------------
+
+// Little-endian bytes of a 45 bytes integer, not a multiple of the word size.
+fn make_bigint_scalar() -> Vec<u8> {
+    (0..45u8)
+        .map(|i| i.wrapping_mul(97).wrapping_add(13))
+        .collect()
+}
+
+fn bls12_381_mul_scalar_field_g1(optimized: bool) {
+    let (bases, scalars) = make_msm_args::<ark_bls12_381::G1Projective>(1);
+    let (base, scalar) = (bases.0[0].into_affine(), scalars.0[0]);
+    let expected = ArkScale::from((base * scalar).into_affine()).encode();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::mul_scalar_field(
+            RuntimeOrigin::none(),
+            Curve::Bls12_381G1,
+            ArkScale::from(base).encode(),
+            ArkScale::from(scalar).encode(),
+            backend(optimized),
+        ));
+        assert_eq!(LastResult::<Test>::get(), Some(expected));
+    });
+}
+
+#[test]
+fn ark_bls12_381_mul_scalar_field_g1() {
+    bls12_381_mul_scalar_field_g1(false);
+}
+
+#[test]
+fn sub_bls12_381_mul_scalar_field_g1() {
+    bls12_381_mul_scalar_field_g1(true);
+}
+
+fn ed_on_bls12_381_bandersnatch_mul_scalar_field_te(optimized: bool) {
+    let (bases, scalars) = make_msm_args::<ark_ed_on_bls12_381_bandersnatch::EdwardsProjective>(1);
+    let (base, scalar) = (bases.0[0].into_affine(), scalars.0[0]);
+    let expected = ArkScale::from((base * scalar).into_affine()).encode();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::mul_scalar_field(
+            RuntimeOrigin::none(),
+            Curve::EdOnBls12_381BandersnatchTe,
+            ArkScale::from(base).encode(),
+            ArkScale::from(scalar).encode(),
+            backend(optimized),
+        ));
+        assert_eq!(LastResult::<Test>::get(), Some(expected));
+    });
+}
+
+#[test]
+fn ark_ed_on_bls12_381_bandersnatch_mul_scalar_field_te() {
+    ed_on_bls12_381_bandersnatch_mul_scalar_field_te(false);
+}
+
+#[test]
+fn sub_ed_on_bls12_381_bandersnatch_mul_scalar_field_te() {
+    ed_on_bls12_381_bandersnatch_mul_scalar_field_te(true);
+}
+
+fn bw6_761_mul_bigint_g1(optimized: bool) {
+    let (base, _) = make_scalar_args::<ark_bw6_761::G1Affine>(1);
+    let scalar = make_bigint_scalar();
+    // Base is in the prime order subgroup, thus the scalar can be reduced.
+    let expected = base.0 * ark_bw6_761::Fr::from_le_bytes_mod_order(&scalar);
+    let expected = ArkScale::from(expected.into_affine()).encode();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::mul_bigint(
+            RuntimeOrigin::none(),
+            Curve::Bw6_761G1,
+            base.encode(),
+            scalar,
+            backend(optimized),
+        ));
+        assert_eq!(LastResult::<Test>::get(), Some(expected));
+    });
+}
+
+#[test]
+fn ark_bw6_761_mul_bigint_g1() {
+    bw6_761_mul_bigint_g1(false);
+}
+
+#[test]
+fn sub_bw6_761_mul_bigint_g1() {
+    bw6_761_mul_bigint_g1(true);
+}
+
+// The words of the `mul_affine_*` scalars are little-endian ordered.
+#[test]
+fn mul_bigint_matches_mul_affine() {
+    let (base, scalar) = make_scalar_args::<ark_bls12_381::G2Affine>(SCALAR_WORDS);
+    let bytes: Vec<u8> = scalar
+        .0
+        .iter()
+        .flat_map(|word| word.to_le_bytes())
+        .collect();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::bls12_381_mul_affine_g2(
+            RuntimeOrigin::none(),
+            base.encode(),
+            scalar.encode(),
+            backend(true),
+        ));
+        let expected = LastResult::<Test>::get();
+        assert_ok!(ArkHostcalls::mul_bigint(
+            RuntimeOrigin::none(),
+            Curve::Bls12_381G2,
+            base.encode(),
+            bytes,
+            backend(true),
+        ));
+        assert_eq!(LastResult::<Test>::get(), expected);
+    });
+}
+
+fn bls12_381_mul_projective_scalar_field_g1(optimized: bool) {
+    let (bases, scalars) = make_msm_args::<ark_bls12_381::G1Projective>(1);
+    let (base, scalar) = (bases.0[0], scalars.0[0]);
+    let expected = (base * scalar).into_affine();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::mul_projective_scalar_field(
+            RuntimeOrigin::none(),
+            Curve::Bls12_381G1,
+            ArkScaleProjective::from(base).encode(),
+            ArkScale::from(scalar).encode(),
+            backend(optimized),
+        ));
+        let output = LastResult::<Test>::get().unwrap();
+        let output =
+            ArkScaleProjective::<ark_bls12_381::G1Projective>::decode(&mut &output[..]).unwrap();
+        assert_eq!(output.0.into_affine(), expected);
+    });
+}
+
+#[test]
+fn ark_bls12_381_mul_projective_scalar_field_g1() {
+    bls12_381_mul_projective_scalar_field_g1(false);
+}
+
+#[test]
+fn sub_bls12_381_mul_projective_scalar_field_g1() {
+    bls12_381_mul_projective_scalar_field_g1(true);
+}
+
+// The words of the `mul_projective_*` scalars are little-endian ordered.
+#[test]
+fn mul_projective_bigint_matches_mul_projective() {
+    let (base, scalar) = make_scalar_args_projective::<
+        ark_ed_on_bls12_381_bandersnatch::EdwardsProjective,
+    >(SCALAR_WORDS);
+    let bytes: Vec<u8> = scalar
+        .0
+        .iter()
+        .flat_map(|word| word.to_le_bytes())
+        .collect();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(
+            ArkHostcalls::ed_on_bls12_381_bandersnatch_mul_projective_te(
+                RuntimeOrigin::none(),
+                base.encode(),
+                scalar.encode(),
+                backend(true),
+            )
+        );
+        let expected = LastResult::<Test>::get();
+        assert_ok!(ArkHostcalls::mul_projective_bigint(
+            RuntimeOrigin::none(),
+            Curve::EdOnBls12_381BandersnatchTe,
+            base.encode(),
+            bytes,
+            backend(true),
+        ));
+        assert_eq!(LastResult::<Test>::get(), expected);
+    });
+}
+
+#[test]
+fn mul_scalar_field_with_unreduced_scalar_fails() {
+    let (base, _) = make_scalar_args::<ark_bls12_381::G1Affine>(1);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkHostcalls::mul_scalar_field(
+                RuntimeOrigin::none(),
+                Curve::Bls12_381G1,
+                base.encode(),
+                vec![0xff; 32],
+                backend(true),
+            ),
+            Error::<Test>::InvalidScalars
+        );
+    });
+}
+
+#[test]
+fn mul_bigint_with_too_long_scalar_fails() {
+    let (base, _) = make_scalar_args::<ark_bls12_381::G1Affine>(1);
+    // 8 words, as `MaxScalarWords` of the mock.
+    let max_bytes = 64;
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::mul_bigint(
+            RuntimeOrigin::none(),
+            Curve::Bls12_381G1,
+            base.encode(),
+            vec![0xff; max_bytes],
+            backend(true),
+        ));
+        assert_noop!(
+            ArkHostcalls::mul_bigint(
+                RuntimeOrigin::none(),
+                Curve::Bls12_381G1,
+                base.encode(),
+                vec![0xff; max_bytes + 1],
+                backend(true),
+            ),
+            Error::<Test>::InputTooLarge
+        );
+    });
+}
+
+// ---------------------------------------------
+// Tests for backend selection
+// ---------------------------------------------
+
+#[cfg(not(feature = "comparison-mode"))]
+#[test]
+fn call_weight_follows_config_backend() {
+    use crate::{mock::RuntimeCall, WeightInfo};
+    use frame_support::dispatch::GetDispatchInfo;
+
+    let (bases, scalars) = make_msm_args::<ark_bls12_381::G1Projective>(MSM_ITEMS);
+    let call: RuntimeCall = crate::Call::<Test>::bls12_381_msm_g1 {
+        bases: bases.encode(),
+        scalars: scalars.encode(),
+        optimized: crate::ConfigBackend,
+    }
+    .into();
+
+    backend(false);
+    assert_eq!(
+        call.get_dispatch_info().call_weight,
+        <()>::ark_bls12_381_msm_g1(MSM_ITEMS)
+    );
+    backend(true);
+    assert_eq!(
+        call.get_dispatch_info().call_weight,
+        <()>::sub_bls12_381_msm_g1(MSM_ITEMS)
+    );
+}
+
+// ---------------------------------------------
+// Tests for runtime API entry points
+// ---------------------------------------------
+
+#[test]
+fn compute_msm_matches_call() {
+    let (bases, scalars) = make_msm_args::<ark_bls12_381::G1Projective>(MSM_ITEMS);
+
+    new_test_ext().execute_with(|| {
+        let output =
+            ArkHostcalls::compute_msm(Curve::Bls12_381G1, bases.encode(), scalars.encode())
+                .unwrap();
+        assert_ok!(ArkHostcalls::bls12_381_msm_g1(
+            RuntimeOrigin::none(),
+            bases.encode(),
+            scalars.encode(),
+            backend(true),
+        ));
+        assert_eq!(LastResult::<Test>::get(), Some(output));
+    });
+}
+
+#[test]
+fn compute_pairing_matches_call() {
+    let (a, b) = make_pairing_args::<ark_bls12_381::G1Affine, ark_bls12_381::G2Affine>();
+
+    new_test_ext().execute_with(|| {
+        let output =
+            ArkHostcalls::compute_pairing(PairingCurve::Bls12_381, a.encode(), b.encode()).unwrap();
+        assert_ok!(ArkHostcalls::bls12_381_pairing(
+            RuntimeOrigin::none(),
+            a.encode(),
+            b.encode(),
+            backend(true)
+        ));
+        assert_eq!(LastResult::<Test>::get(), Some(output));
+    });
+}
+
+#[test]
+fn compute_msm_with_invalid_bases_fails() {
+    let (_, scalars) = make_msm_args::<ark_bls12_381::G1Projective>(1);
+
+    new_test_ext().execute_with(|| {
+        assert!(matches!(
+            ArkHostcalls::compute_msm(Curve::Bls12_381G1, vec![0xff; 64], scalars.encode()),
+            Err(Error::<Test>::InvalidBases)
+        ));
+    });
+}
+
+// ---------------------------------------------
+// Tests for malformed inputs
+// ---------------------------------------------
+
+#[test]
+fn msm_with_invalid_bases_fails() {
+    let (_, scalars) = make_msm_args::<ark_bls12_381::G1Projective>(MSM_ITEMS);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkHostcalls::bls12_381_msm_g1(
+                RuntimeOrigin::none(),
+                vec![0xff; 64],
+                scalars.encode(),
+                backend(true),
+            ),
+            Error::<Test>::InvalidBases
+        );
+    });
+}
+
+#[test]
+fn msm_with_invalid_scalars_fails() {
+    let (bases, _) = make_msm_args::<ark_bls12_381::G1Projective>(MSM_ITEMS);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkHostcalls::bls12_381_msm_g1(
+                RuntimeOrigin::none(),
+                bases.encode(),
+                vec![0xff; 16],
+                backend(true),
+            ),
+            Error::<Test>::InvalidScalars
+        );
+    });
+}
+
+#[test]
+fn msm_with_length_mismatch_fails() {
+    let (bases, _) = make_msm_args::<ark_bls12_381::G1Projective>(MSM_ITEMS);
+    let (_, scalars) = make_msm_args::<ark_bls12_381::G1Projective>(MSM_ITEMS - 1);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkHostcalls::bls12_381_msm_g1(
+                RuntimeOrigin::none(),
+                bases.encode(),
+                scalars.encode(),
+                backend(true),
+            ),
+            Error::<Test>::LengthMismatch
+        );
+    });
+}
+
+#[test]
+fn pairing_with_invalid_point_fails() {
+    let (a, _) = make_pairing_args::<ark_bls12_381::G1Affine, ark_bls12_381::G2Affine>();
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkHostcalls::bls12_381_pairing(
+                RuntimeOrigin::none(),
+                a.encode(),
+                vec![0xff; 96],
+                backend(true),
+            ),
+            Error::<Test>::InvalidPoint
+        );
+    });
+}
+
+#[test]
+fn mul_affine_with_invalid_scalar_fails() {
+    let (base, _) = make_mul_affine_args::<ark_ed_on_bls12_377::EdwardsAffine>();
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkHostcalls::ed_on_bls12_377_mul_affine_te(
+                RuntimeOrigin::none(),
+                base.encode(),
+                vec![0xff],
+                backend(true),
+            ),
+            Error::<Test>::InvalidScalars
+        );
+    });
+}
+
+#[test]
+fn multi_pairing_check_with_non_identity_product_fails() {
+    let (a, b) =
+        make_multi_pairing_args::<ark_bls12_381::G1Affine, ark_bls12_381::G2Affine>(PAIRING_ITEMS);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkHostcalls::bls12_381_multi_pairing(
+                RuntimeOrigin::none(),
+                a.encode(),
+                b.encode(),
+                true,
+                backend(true)
+            ),
+            Error::<Test>::PairingCheckFailed
+        );
+    });
+}
+
+#[test]
+fn multi_pairing_with_length_mismatch_fails() {
+    let (a, _) = make_multi_pairing_args::<ark_bls12_381::G1Affine, ark_bls12_381::G2Affine>(2);
+    let (_, b) = make_multi_pairing_args::<ark_bls12_381::G1Affine, ark_bls12_381::G2Affine>(3);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkHostcalls::bls12_381_multi_pairing(
+                RuntimeOrigin::none(),
+                a.encode(),
+                b.encode(),
+                false,
+                backend(true)
+            ),
+            Error::<Test>::LengthMismatch
+        );
+    });
+}
+
+#[test]
+fn final_exponentiation_with_zero_target_fails() {
+    let target = ArkScale::from(<ark_bls12_381::Bls12_381 as Pairing>::TargetField::from(
+        0u64,
+    ));
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkHostcalls::bls12_381_final_exponentiation(
+                RuntimeOrigin::none(),
+                target.encode(),
+                backend(true)
+            ),
+            Error::<Test>::InvalidTarget
+        );
+    });
+}
+
+#[test]
+fn msm_with_too_many_items_fails() {
+    let (bases, scalars) = make_msm_args::<ark_bls12_381::G1Projective>(MSM_ITEMS + 1);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkHostcalls::bls12_381_msm_g1(
+                RuntimeOrigin::none(),
+                bases.encode(),
+                scalars.encode(),
+                backend(true),
+            ),
+            Error::<Test>::InputTooLarge
+        );
+    });
+}
+
+#[test]
+fn mul_affine_with_too_long_scalar_fails() {
+    let (base, scalar) = make_scalar_args::<ark_bls12_381::G1Affine>(9);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkHostcalls::bls12_381_mul_affine_g1(
+                RuntimeOrigin::none(),
+                base.encode(),
+                scalar.encode(),
+                backend(true),
+            ),
+            Error::<Test>::InputTooLarge
+        );
+    });
+}
+
+#[test]
+fn multi_pairing_with_too_many_pairs_fails() {
+    let (a, b) = make_multi_pairing_args::<ark_bls12_381::G1Affine, ark_bls12_381::G2Affine>(9);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkHostcalls::bls12_381_multi_pairing(
+                RuntimeOrigin::none(),
+                a.encode(),
+                b.encode(),
+                false,
+                backend(true)
+            ),
+            Error::<Test>::InputTooLarge
+        );
+    });
+}
+
+// ---------------------------------------------
+// Tests for points validation
+// ---------------------------------------------
+
+// A point on the bls12-381 G1 curve which is not in the prime order subgroup.
+fn make_non_subgroup_g1() -> ark_bls12_381::G1Affine {
+    let mut x = ark_bls12_381::Fq::from(1u64);
+    loop {
+        if let Some(p) = ark_bls12_381::G1Affine::get_point_from_x_unchecked(x, false) {
+            if !p.is_in_correct_subgroup_assuming_on_curve() {
+                return p;
+            }
+        }
+        x += ark_bls12_381::Fq::from(1u64);
+    }
+}
+
+#[test]
+fn mul_affine_with_non_subgroup_point_fails() {
+    let base = ArkScale::from(make_non_subgroup_g1());
+    let (_, scalar) = make_scalar_args::<ark_bls12_381::G1Affine>(SCALAR_WORDS);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkHostcalls::bls12_381_mul_affine_g1(
+                RuntimeOrigin::none(),
+                base.encode(),
+                scalar.encode(),
+                backend(true),
+            ),
+            Error::<Test>::InvalidPoint
+        );
+    });
+}
+
+#[test]
+fn mul_affine_with_non_subgroup_point_unchecked_works() {
+    let base = ArkScale::from(make_non_subgroup_g1());
+    let (_, scalar) = make_scalar_args::<ark_bls12_381::G1Affine>(SCALAR_WORDS);
+    ValidatePoints::set(false);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkHostcalls::bls12_381_mul_affine_g1(
+            RuntimeOrigin::none(),
+            base.encode(),
+            scalar.encode(),
+            backend(true),
+        ));
+    });
+}
+
+#[test]
+fn mul_projective_with_off_curve_point_fails() {
+    let one = ark_bls12_381::Fq::from(1u64);
+    let base = ArkScaleProjective::from(ark_bls12_381::G1Projective::new_unchecked(one, one, one));
+    let (_, scalar) = make_scalar_args_projective::<ark_bls12_381::G1Projective>(SCALAR_WORDS);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkHostcalls::bls12_381_mul_projective_g1(
+                RuntimeOrigin::none(),
+                base.encode(),
+                scalar.encode(),
+                backend(true),
+            ),
+            Error::<Test>::InvalidPoint
+        );
+    });
 }
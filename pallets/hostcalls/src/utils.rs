@@ -6,7 +6,7 @@ use ark_ec::AffineRepr;
 use ark_scale::ark_serialize::{CanonicalSerialize, Compress};
 use ark_scale::hazmat::ArkScaleProjective;
 // use ark_snark::SNARK;
-use ark_std::{test_rng, vec, vec::Vec, UniformRand};
+use ark_std::{rand::Rng, test_rng, vec, vec::Vec, UniformRand};
 
 // pub type VerifyingKeyFor<PairingT, PrimeFieldT> =
 //     <Groth16<PairingT> as SNARK<PrimeFieldT>>::VerifyingKey;
@@ -21,8 +21,8 @@ fn make_scalar(words_count: u32) -> Vec<u64> {
     let mut scalar: Vec<_> = (0..words_count as usize)
         .map(|_| u64::rand(&mut test_rng()))
         .collect();
-    // Arkworks assumes scalar to be in **big endian**
-    scalar[0] |= 1 << 63;
+    // Arkworks takes scalar words in **little endian** order, the last one is the most significant
+    scalar[words_count as usize - 1] |= 1 << 63;
     scalar
 }
 
@@ -51,10 +51,40 @@ pub fn make_pairing_args<GroupA: UniformRand, GroupB: UniformRand>(
     (make_base::<GroupA>().into(), make_base::<GroupB>().into())
 }
 
+pub fn make_multi_pairing_args<GroupA: UniformRand, GroupB: UniformRand>(
+    size: u32,
+) -> (ArkScale<Vec<GroupA>>, ArkScale<Vec<GroupB>>) {
+    let a = (0..size).map(|_| make_base::<GroupA>()).collect::<Vec<_>>();
+    let b = (0..size).map(|_| make_base::<GroupB>()).collect::<Vec<_>>();
+    (a.into(), b.into())
+}
+
+pub fn make_final_exponentiation_args<P: ark_ec::pairing::Pairing>() -> ArkScale<P::TargetField> {
+    let (a, b) = make_pairing_args::<P::G1Affine, P::G2Affine>();
+    P::multi_miller_loop([a.0], [b.0]).0.into()
+}
+
+pub fn make_point_args<Group: UniformRand>() -> ArkScale<Group> {
+    make_base::<Group>().into()
+}
+
+pub fn make_points_args<Group: UniformRand>(size: u32) -> ArkScale<Vec<Group>> {
+    (0..size)
+        .map(|_| make_base::<Group>())
+        .collect::<Vec<_>>()
+        .into()
+}
+
 pub fn make_msm_args<Group: ark_ec::VariableBaseMSM>(
     size: u32,
 ) -> (ArkScale<Vec<Group>>, ArkScale<Vec<Group::ScalarField>>) {
-    let rng = &mut test_rng();
+    make_msm_args_with_rng(size, &mut test_rng())
+}
+
+pub fn make_msm_args_with_rng<Group: ark_ec::VariableBaseMSM>(
+    size: u32,
+    rng: &mut impl Rng,
+) -> (ArkScale<Vec<Group>>, ArkScale<Vec<Group::ScalarField>>) {
     let scalars = (0..size)
         .map(|_| Group::ScalarField::rand(rng))
         .collect::<Vec<_>>();
@@ -16,15 +16,15 @@
 // limitations under the License.
 
 
-//! Autogenerated weights for `pallet_ark_hostcalls`
+//! Placeholder weights for `pallet_ark_hostcalls`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2026-01-24, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `ggwpez-ref-hw2`, CPU: `AMD EPYC 7232P 8-Core Processor`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+//! NOT GENERATED BY THE BENCHMARK CLI. The figures below are estimates and must be
+//! replaced by running the pallet benchmarks with the command below.
+//!
+//! The BLS12-377 and BW6-761 figures are the BLS12-381 ones scaled by the measured cost ratio
+//! of the same arkworks operation on each curve, with a 20% margin on top.
 
-// Executed Command:
+// Command to regenerate:
 // ./target/release/ark-node
 // benchmark
 // pallet
@@ -58,549 +58,2139 @@ pub trait WeightInfo {
 	fn sub_bls12_381_pairing() -> Weight;
 	fn ark_bls12_381_msm_g1(x: u32, ) -> Weight;
 	fn sub_bls12_381_msm_g1(x: u32, ) -> Weight;
-	fn ark_bls12_381_mul_projective_g1() -> Weight;
-	fn sub_bls12_381_mul_projective_g1() -> Weight;
-	fn ark_bls12_381_mul_affine_g1() -> Weight;
-	fn sub_bls12_381_mul_affine_g1() -> Weight;
+	fn ark_bls12_381_mul_projective_g1(x: u32, ) -> Weight;
+	fn sub_bls12_381_mul_projective_g1(x: u32, ) -> Weight;
+	fn ark_bls12_381_mul_affine_g1(x: u32, ) -> Weight;
+	fn sub_bls12_381_mul_affine_g1(x: u32, ) -> Weight;
 	fn ark_bls12_381_msm_g2(x: u32, ) -> Weight;
 	fn sub_bls12_381_msm_g2(x: u32, ) -> Weight;
-	fn ark_bls12_381_mul_projective_g2() -> Weight;
-	fn sub_bls12_381_mul_projective_g2() -> Weight;
-	fn ark_bls12_381_mul_affine_g2() -> Weight;
-	fn sub_bls12_381_mul_affine_g2() -> Weight;
+	fn ark_bls12_381_mul_projective_g2(x: u32, ) -> Weight;
+	fn sub_bls12_381_mul_projective_g2(x: u32, ) -> Weight;
+	fn ark_bls12_381_mul_affine_g2(x: u32, ) -> Weight;
+	fn sub_bls12_381_mul_affine_g2(x: u32, ) -> Weight;
+	fn ark_bls12_381_multi_pairing(x: u32, ) -> Weight;
+	fn sub_bls12_381_multi_pairing(x: u32, ) -> Weight;
+	fn ark_bls12_381_multi_miller_loop(x: u32, ) -> Weight;
+	fn sub_bls12_381_multi_miller_loop(x: u32, ) -> Weight;
+	fn ark_bls12_381_final_exponentiation() -> Weight;
+	fn sub_bls12_381_final_exponentiation() -> Weight;
+	fn ark_bls12_381_hash_to_g1(x: u32, ) -> Weight;
+	fn sub_bls12_381_hash_to_g1(x: u32, ) -> Weight;
+	fn ark_bls12_381_hash_to_g2(x: u32, ) -> Weight;
+	fn sub_bls12_381_hash_to_g2(x: u32, ) -> Weight;
+	fn ark_bls12_377_pairing() -> Weight;
+	fn sub_bls12_377_pairing() -> Weight;
+	fn ark_bls12_377_msm_g1(x: u32, ) -> Weight;
+	fn sub_bls12_377_msm_g1(x: u32, ) -> Weight;
+	fn ark_bls12_377_mul_projective_g1(x: u32, ) -> Weight;
+	fn sub_bls12_377_mul_projective_g1(x: u32, ) -> Weight;
+	fn ark_bls12_377_mul_affine_g1(x: u32, ) -> Weight;
+	fn sub_bls12_377_mul_affine_g1(x: u32, ) -> Weight;
+	fn ark_bls12_377_msm_g2(x: u32, ) -> Weight;
+	fn sub_bls12_377_msm_g2(x: u32, ) -> Weight;
+	fn ark_bls12_377_mul_projective_g2(x: u32, ) -> Weight;
+	fn sub_bls12_377_mul_projective_g2(x: u32, ) -> Weight;
+	fn ark_bls12_377_mul_affine_g2(x: u32, ) -> Weight;
+	fn sub_bls12_377_mul_affine_g2(x: u32, ) -> Weight;
+	fn ark_bls12_377_multi_pairing(x: u32, ) -> Weight;
+	fn sub_bls12_377_multi_pairing(x: u32, ) -> Weight;
+	fn ark_bls12_377_multi_miller_loop(x: u32, ) -> Weight;
+	fn sub_bls12_377_multi_miller_loop(x: u32, ) -> Weight;
+	fn ark_bls12_377_final_exponentiation() -> Weight;
+	fn sub_bls12_377_final_exponentiation() -> Weight;
+	fn ark_bw6_761_pairing() -> Weight;
+	fn sub_bw6_761_pairing() -> Weight;
+	fn ark_bw6_761_msm_g1(x: u32, ) -> Weight;
+	fn sub_bw6_761_msm_g1(x: u32, ) -> Weight;
+	fn ark_bw6_761_mul_projective_g1(x: u32, ) -> Weight;
+	fn sub_bw6_761_mul_projective_g1(x: u32, ) -> Weight;
+	fn ark_bw6_761_mul_affine_g1(x: u32, ) -> Weight;
+	fn sub_bw6_761_mul_affine_g1(x: u32, ) -> Weight;
+	fn ark_bw6_761_msm_g2(x: u32, ) -> Weight;
+	fn sub_bw6_761_msm_g2(x: u32, ) -> Weight;
+	fn ark_bw6_761_mul_projective_g2(x: u32, ) -> Weight;
+	fn sub_bw6_761_mul_projective_g2(x: u32, ) -> Weight;
+	fn ark_bw6_761_mul_affine_g2(x: u32, ) -> Weight;
+	fn sub_bw6_761_mul_affine_g2(x: u32, ) -> Weight;
+	fn ark_bw6_761_multi_pairing(x: u32, ) -> Weight;
+	fn sub_bw6_761_multi_pairing(x: u32, ) -> Weight;
+	fn ark_bw6_761_multi_miller_loop(x: u32, ) -> Weight;
+	fn sub_bw6_761_multi_miller_loop(x: u32, ) -> Weight;
+	fn ark_bw6_761_final_exponentiation() -> Weight;
+	fn sub_bw6_761_final_exponentiation() -> Weight;
 	fn ark_ed_on_bls12_377_msm_te(x: u32, ) -> Weight;
 	fn sub_ed_on_bls12_377_msm_te(x: u32, ) -> Weight;
-	fn ark_ed_on_bls12_377_mul_projective_te() -> Weight;
-	fn sub_ed_on_bls12_377_mul_projective_te() -> Weight;
-	fn ark_ed_on_bls12_377_mul_affine_te() -> Weight;
-	fn sub_ed_on_bls12_377_mul_affine_te() -> Weight;
+	fn ark_ed_on_bls12_377_mul_projective_te(x: u32, ) -> Weight;
+	fn sub_ed_on_bls12_377_mul_projective_te(x: u32, ) -> Weight;
+	fn ark_ed_on_bls12_377_mul_affine_te(x: u32, ) -> Weight;
+	fn sub_ed_on_bls12_377_mul_affine_te(x: u32, ) -> Weight;
 	fn ark_ed_on_bls12_381_bandersnatch_msm_sw(x: u32, ) -> Weight;
 	fn sub_ed_on_bls12_381_bandersnatch_msm_sw(x: u32, ) -> Weight;
-	fn ark_ed_on_bls12_381_bandersnatch_mul_projective_sw() -> Weight;
-	fn sub_ed_on_bls12_381_bandersnatch_mul_projective_sw() -> Weight;
-	fn ark_ed_on_bls12_381_bandersnatch_mul_affine_sw() -> Weight;
-	fn sub_ed_on_bls12_381_bandersnatch_mul_affine_sw() -> Weight;
+	fn ark_ed_on_bls12_381_bandersnatch_mul_projective_sw(x: u32, ) -> Weight;
+	fn sub_ed_on_bls12_381_bandersnatch_mul_projective_sw(x: u32, ) -> Weight;
+	fn ark_ed_on_bls12_381_bandersnatch_mul_affine_sw(x: u32, ) -> Weight;
+	fn sub_ed_on_bls12_381_bandersnatch_mul_affine_sw(x: u32, ) -> Weight;
 	fn ark_ed_on_bls12_381_bandersnatch_msm_te(x: u32, ) -> Weight;
 	fn sub_ed_on_bls12_381_bandersnatch_msm_te(x: u32, ) -> Weight;
-	fn ark_ed_on_bls12_381_bandersnatch_mul_projective_te() -> Weight;
-	fn sub_ed_on_bls12_381_bandersnatch_mul_projective_te() -> Weight;
-	fn ark_ed_on_bls12_381_bandersnatch_mul_affine_te() -> Weight;
-	fn sub_ed_on_bls12_381_bandersnatch_mul_affine_te() -> Weight;
+	fn ark_ed_on_bls12_381_bandersnatch_mul_projective_te(x: u32, ) -> Weight;
+	fn sub_ed_on_bls12_381_bandersnatch_mul_projective_te(x: u32, ) -> Weight;
+	fn ark_ed_on_bls12_381_bandersnatch_mul_affine_te(x: u32, ) -> Weight;
+	fn sub_ed_on_bls12_381_bandersnatch_mul_affine_te(x: u32, ) -> Weight;
+	fn ed_on_bls12_381_bandersnatch_te_to_sw() -> Weight;
+	fn ed_on_bls12_381_bandersnatch_sw_to_te() -> Weight;
+	fn ed_on_bls12_381_bandersnatch_te_to_sw_batch(x: u32, ) -> Weight;
+	fn ed_on_bls12_381_bandersnatch_sw_to_te_batch(x: u32, ) -> Weight;
+	fn ark_bls12_381_msm_fixed_base_g1(x: u32, ) -> Weight;
+	fn sub_bls12_381_msm_fixed_base_g1(x: u32, ) -> Weight;
+	fn ark_bls12_381_msm_fixed_base_g2(x: u32, ) -> Weight;
+	fn sub_bls12_381_msm_fixed_base_g2(x: u32, ) -> Weight;
+	fn ark_bls12_377_msm_fixed_base_g1(x: u32, ) -> Weight;
+	fn sub_bls12_377_msm_fixed_base_g1(x: u32, ) -> Weight;
+	fn ark_bls12_377_msm_fixed_base_g2(x: u32, ) -> Weight;
+	fn sub_bls12_377_msm_fixed_base_g2(x: u32, ) -> Weight;
+	fn ark_bw6_761_msm_fixed_base_g1(x: u32, ) -> Weight;
+	fn sub_bw6_761_msm_fixed_base_g1(x: u32, ) -> Weight;
+	fn ark_bw6_761_msm_fixed_base_g2(x: u32, ) -> Weight;
+	fn sub_bw6_761_msm_fixed_base_g2(x: u32, ) -> Weight;
+	fn ark_ed_on_bls12_377_msm_fixed_base_te(x: u32, ) -> Weight;
+	fn sub_ed_on_bls12_377_msm_fixed_base_te(x: u32, ) -> Weight;
+	fn ark_ed_on_bls12_381_bandersnatch_msm_fixed_base_sw(x: u32, ) -> Weight;
+	fn sub_ed_on_bls12_381_bandersnatch_msm_fixed_base_sw(x: u32, ) -> Weight;
+	fn ark_ed_on_bls12_381_bandersnatch_msm_fixed_base_te(x: u32, ) -> Weight;
+	fn sub_ed_on_bls12_381_bandersnatch_msm_fixed_base_te(x: u32, ) -> Weight;
+	fn bls12_381_register_base_set_g1(x: u32, ) -> Weight;
+	fn bls12_381_register_base_set_g2(x: u32, ) -> Weight;
+	fn bls12_377_register_base_set_g1(x: u32, ) -> Weight;
+	fn bls12_377_register_base_set_g2(x: u32, ) -> Weight;
+	fn bw6_761_register_base_set_g1(x: u32, ) -> Weight;
+	fn bw6_761_register_base_set_g2(x: u32, ) -> Weight;
+	fn ed_on_bls12_377_register_base_set_te(x: u32, ) -> Weight;
+	fn ed_on_bls12_381_bandersnatch_register_base_set_sw(x: u32, ) -> Weight;
+	fn ed_on_bls12_381_bandersnatch_register_base_set_te(x: u32, ) -> Weight;
+	fn remove_base_set(x: u32, ) -> Weight;
+	fn ark_bls12_381_decode_g1_unchecked(x: u32, ) -> Weight;
+	fn sub_bls12_381_decode_g1_unchecked(x: u32, ) -> Weight;
+	fn ark_bls12_381_decode_g1_validated(x: u32, ) -> Weight;
+	fn sub_bls12_381_decode_g1_validated(x: u32, ) -> Weight;
+	fn ark_bls12_381_decode_g2_unchecked(x: u32, ) -> Weight;
+	fn sub_bls12_381_decode_g2_unchecked(x: u32, ) -> Weight;
+	fn ark_bls12_381_decode_g2_validated(x: u32, ) -> Weight;
+	fn sub_bls12_381_decode_g2_validated(x: u32, ) -> Weight;
+	fn ark_bls12_377_decode_g1_unchecked(x: u32, ) -> Weight;
+	fn sub_bls12_377_decode_g1_unchecked(x: u32, ) -> Weight;
+	fn ark_bls12_377_decode_g1_validated(x: u32, ) -> Weight;
+	fn sub_bls12_377_decode_g1_validated(x: u32, ) -> Weight;
+	fn ark_bls12_377_decode_g2_unchecked(x: u32, ) -> Weight;
+	fn sub_bls12_377_decode_g2_unchecked(x: u32, ) -> Weight;
+	fn ark_bls12_377_decode_g2_validated(x: u32, ) -> Weight;
+	fn sub_bls12_377_decode_g2_validated(x: u32, ) -> Weight;
+	fn ark_bw6_761_decode_g1_unchecked(x: u32, ) -> Weight;
+	fn sub_bw6_761_decode_g1_unchecked(x: u32, ) -> Weight;
+	fn ark_bw6_761_decode_g1_validated(x: u32, ) -> Weight;
+	fn sub_bw6_761_decode_g1_validated(x: u32, ) -> Weight;
+	fn ark_bw6_761_decode_g2_unchecked(x: u32, ) -> Weight;
+	fn sub_bw6_761_decode_g2_unchecked(x: u32, ) -> Weight;
+	fn ark_bw6_761_decode_g2_validated(x: u32, ) -> Weight;
+	fn sub_bw6_761_decode_g2_validated(x: u32, ) -> Weight;
+	fn ark_ed_on_bls12_377_decode_te_unchecked(x: u32, ) -> Weight;
+	fn sub_ed_on_bls12_377_decode_te_unchecked(x: u32, ) -> Weight;
+	fn ark_ed_on_bls12_377_decode_te_validated(x: u32, ) -> Weight;
+	fn sub_ed_on_bls12_377_decode_te_validated(x: u32, ) -> Weight;
+	fn ark_ed_on_bls12_381_bandersnatch_decode_sw_unchecked(x: u32, ) -> Weight;
+	fn sub_ed_on_bls12_381_bandersnatch_decode_sw_unchecked(x: u32, ) -> Weight;
+	fn ark_ed_on_bls12_381_bandersnatch_decode_sw_validated(x: u32, ) -> Weight;
+	fn sub_ed_on_bls12_381_bandersnatch_decode_sw_validated(x: u32, ) -> Weight;
+	fn ark_ed_on_bls12_381_bandersnatch_decode_te_unchecked(x: u32, ) -> Weight;
+	fn sub_ed_on_bls12_381_bandersnatch_decode_te_unchecked(x: u32, ) -> Weight;
+	fn ark_ed_on_bls12_381_bandersnatch_decode_te_validated(x: u32, ) -> Weight;
+	fn sub_ed_on_bls12_381_bandersnatch_decode_te_validated(x: u32, ) -> Weight;
+	fn ark_bls12_381_decode_g1_uncompressed(x: u32, ) -> Weight;
+	fn sub_bls12_381_decode_g1_uncompressed(x: u32, ) -> Weight;
+	fn ark_bls12_381_decode_g2_uncompressed(x: u32, ) -> Weight;
+	fn sub_bls12_381_decode_g2_uncompressed(x: u32, ) -> Weight;
+	fn ark_bls12_377_decode_g1_uncompressed(x: u32, ) -> Weight;
+	fn sub_bls12_377_decode_g1_uncompressed(x: u32, ) -> Weight;
+	fn ark_bls12_377_decode_g2_uncompressed(x: u32, ) -> Weight;
+	fn sub_bls12_377_decode_g2_uncompressed(x: u32, ) -> Weight;
+	fn ark_bw6_761_decode_g1_uncompressed(x: u32, ) -> Weight;
+	fn sub_bw6_761_decode_g1_uncompressed(x: u32, ) -> Weight;
+	fn ark_bw6_761_decode_g2_uncompressed(x: u32, ) -> Weight;
+	fn sub_bw6_761_decode_g2_uncompressed(x: u32, ) -> Weight;
+	fn ark_ed_on_bls12_377_decode_te_uncompressed(x: u32, ) -> Weight;
+	fn sub_ed_on_bls12_377_decode_te_uncompressed(x: u32, ) -> Weight;
+	fn ark_ed_on_bls12_381_bandersnatch_decode_sw_uncompressed(x: u32, ) -> Weight;
+	fn sub_ed_on_bls12_381_bandersnatch_decode_sw_uncompressed(x: u32, ) -> Weight;
+	fn ark_ed_on_bls12_381_bandersnatch_decode_te_uncompressed(x: u32, ) -> Weight;
+	fn sub_ed_on_bls12_381_bandersnatch_decode_te_uncompressed(x: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_ark_hostcalls` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn ark_bls12_381_pairing() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 10_088_641_000 picoseconds.
 		Weight::from_parts(10_099_051_000, 0)
 	}
 	fn sub_bls12_381_pairing() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 2_499_075_000 picoseconds.
 		Weight::from_parts(2_504_895_000, 0)
 	}
 	/// The range of component `x` is `[10, 100]`.
 	fn ark_bls12_381_msm_g1(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 12_884_898_000 picoseconds.
 		Weight::from_parts(5_986_616_193, 0)
-			// Standard Error: 2_379_451
 			.saturating_add(Weight::from_parts(929_844_025, 0).saturating_mul(x.into()))
 	}
 	/// The range of component `x` is `[10, 100]`.
 	fn sub_bls12_381_msm_g1(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 3_646_192_000 picoseconds.
 		Weight::from_parts(615_304_512, 0)
-			// Standard Error: 142_034
 			.saturating_add(Weight::from_parts(323_206_096, 0).saturating_mul(x.into()))
 	}
-	fn ark_bls12_381_mul_projective_g1() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 973_256_000 picoseconds.
-		Weight::from_parts(974_596_000, 0)
-	}
-	fn sub_bls12_381_mul_projective_g1() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 142_531_000 picoseconds.
-		Weight::from_parts(148_491_000, 0)
-	}
-	fn ark_bls12_381_mul_affine_g1() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 1_934_811_000 picoseconds.
-		Weight::from_parts(1_938_681_000, 0)
-	}
-	fn sub_bls12_381_mul_affine_g1() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 1_934_472_000 picoseconds.
-		Weight::from_parts(1_938_151_000, 0)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_381_mul_projective_g1(x: u32, ) -> Weight {
+		Weight::from_parts(48_729_800, 0)
+			.saturating_add(Weight::from_parts(231_466_550, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_381_mul_projective_g1(x: u32, ) -> Weight {
+		Weight::from_parts(7_424_550, 0)
+			.saturating_add(Weight::from_parts(35_266_612, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_381_mul_affine_g1(x: u32, ) -> Weight {
+		Weight::from_parts(96_934_050, 0)
+			.saturating_add(Weight::from_parts(460_436_737, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_381_mul_affine_g1(x: u32, ) -> Weight {
+		Weight::from_parts(96_907_550, 0)
+			.saturating_add(Weight::from_parts(460_310_862, 0).saturating_mul(x.into()))
 	}
 	/// The range of component `x` is `[10, 100]`.
 	fn ark_bls12_381_msm_g2(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 32_255_101_000 picoseconds.
 		Weight::from_parts(18_180_124_960, 0)
-			// Standard Error: 7_085_553
 			.saturating_add(Weight::from_parts(2_141_646_162, 0).saturating_mul(x.into()))
 	}
 	/// The range of component `x` is `[10, 100]`.
 	fn sub_bls12_381_msm_g2(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 9_522_987_000 picoseconds.
 		Weight::from_parts(1_313_543_996, 0)
-			// Standard Error: 297_937
 			.saturating_add(Weight::from_parts(852_948_630, 0).saturating_mul(x.into()))
 	}
-	fn ark_bls12_381_mul_projective_g2() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 4_647_659_000 picoseconds.
-		Weight::from_parts(4_659_000_000, 0)
-	}
-	fn sub_bls12_381_mul_projective_g2() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 690_905_000 picoseconds.
-		Weight::from_parts(696_944_000, 0)
-	}
-	fn ark_bls12_381_mul_affine_g2() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 5_197_763_000 picoseconds.
-		Weight::from_parts(5_208_333_000, 0)
-	}
-	fn sub_bls12_381_mul_affine_g2() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 5_196_493_000 picoseconds.
-		Weight::from_parts(5_206_693_000, 0)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_381_mul_projective_g2(x: u32, ) -> Weight {
+		Weight::from_parts(232_950_000, 0)
+			.saturating_add(Weight::from_parts(1_106_512_500, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_381_mul_projective_g2(x: u32, ) -> Weight {
+		Weight::from_parts(34_847_200, 0)
+			.saturating_add(Weight::from_parts(165_524_200, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_381_mul_affine_g2(x: u32, ) -> Weight {
+		Weight::from_parts(260_416_650, 0)
+			.saturating_add(Weight::from_parts(1_236_979_087, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_381_mul_affine_g2(x: u32, ) -> Weight {
+		Weight::from_parts(260_334_650, 0)
+			.saturating_add(Weight::from_parts(1_236_589_587, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn ark_bls12_381_multi_pairing(x: u32, ) -> Weight {
+		Weight::from_parts(5_554_478_000, 0)
+			.saturating_add(Weight::from_parts(4_544_572_000, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn sub_bls12_381_multi_pairing(x: u32, ) -> Weight {
+		Weight::from_parts(1_377_692_000, 0)
+			.saturating_add(Weight::from_parts(1_127_203_000, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn ark_bls12_381_multi_miller_loop(x: u32, ) -> Weight {
+		Weight::from_parts(201_981_000, 0)
+			.saturating_add(Weight::from_parts(4_544_572_000, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn sub_bls12_381_multi_miller_loop(x: u32, ) -> Weight {
+		Weight::from_parts(50_097_000, 0)
+			.saturating_add(Weight::from_parts(1_127_203_000, 0).saturating_mul(x.into()))
+	}
+	fn ark_bls12_381_final_exponentiation() -> Weight {
+		Weight::from_parts(5_352_497_000, 0)
+	}
+	fn sub_bls12_381_final_exponentiation() -> Weight {
+		Weight::from_parts(1_327_594_000, 0)
+	}
+	/// The range of component `x` is `[0, 1024]`.
+	fn ark_bls12_381_hash_to_g1(x: u32, ) -> Weight {
+		Weight::from_parts(2_412_553_000, 0)
+			.saturating_add(Weight::from_parts(15_240, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[0, 1024]`.
+	fn sub_bls12_381_hash_to_g1(x: u32, ) -> Weight {
+		Weight::from_parts(1_893_407_000, 0)
+			.saturating_add(Weight::from_parts(15_180, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[0, 1024]`.
+	fn ark_bls12_381_hash_to_g2(x: u32, ) -> Weight {
+		Weight::from_parts(7_536_218_000, 0)
+			.saturating_add(Weight::from_parts(15_310, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[0, 1024]`.
+	fn sub_bls12_381_hash_to_g2(x: u32, ) -> Weight {
+		Weight::from_parts(4_027_941_000, 0)
+			.saturating_add(Weight::from_parts(15_270, 0).saturating_mul(x.into()))
+	}
+	fn ark_bls12_377_pairing() -> Weight {
+		Weight::from_parts(15_148_576_500, 0)
+	}
+	fn sub_bls12_377_pairing() -> Weight {
+		Weight::from_parts(3_757_342_500, 0)
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_377_msm_g1(x: u32, ) -> Weight {
+		Weight::from_parts(7_782_601_051, 0)
+			.saturating_add(Weight::from_parts(1_208_797_232, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_377_msm_g1(x: u32, ) -> Weight {
+		Weight::from_parts(799_895_866, 0)
+			.saturating_add(Weight::from_parts(420_167_925, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_377_mul_projective_g1(x: u32, ) -> Weight {
+		Weight::from_parts(63_348_740, 0)
+			.saturating_add(Weight::from_parts(300_906_515, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_377_mul_projective_g1(x: u32, ) -> Weight {
+		Weight::from_parts(9_651_915, 0)
+			.saturating_add(Weight::from_parts(45_846_596, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_377_mul_affine_g1(x: u32, ) -> Weight {
+		Weight::from_parts(126_014_265, 0)
+			.saturating_add(Weight::from_parts(598_567_758, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_377_mul_affine_g1(x: u32, ) -> Weight {
+		Weight::from_parts(125_979_815, 0)
+			.saturating_add(Weight::from_parts(598_404_121, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_377_msm_g2(x: u32, ) -> Weight {
+		Weight::from_parts(27_270_187_440, 0)
+			.saturating_add(Weight::from_parts(3_212_469_243, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_377_msm_g2(x: u32, ) -> Weight {
+		Weight::from_parts(1_970_315_994, 0)
+			.saturating_add(Weight::from_parts(1_279_422_945, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_377_mul_projective_g2(x: u32, ) -> Weight {
+		Weight::from_parts(349_425_000, 0)
+			.saturating_add(Weight::from_parts(1_659_768_750, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_377_mul_projective_g2(x: u32, ) -> Weight {
+		Weight::from_parts(52_270_800, 0)
+			.saturating_add(Weight::from_parts(248_286_300, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_377_mul_affine_g2(x: u32, ) -> Weight {
+		Weight::from_parts(390_624_975, 0)
+			.saturating_add(Weight::from_parts(1_855_468_630, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_377_mul_affine_g2(x: u32, ) -> Weight {
+		Weight::from_parts(390_501_975, 0)
+			.saturating_add(Weight::from_parts(1_854_884_380, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn ark_bls12_377_multi_pairing(x: u32, ) -> Weight {
+		Weight::from_parts(8_331_717_000, 0)
+			.saturating_add(Weight::from_parts(6_135_172_200, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn sub_bls12_377_multi_pairing(x: u32, ) -> Weight {
+		Weight::from_parts(2_066_538_000, 0)
+			.saturating_add(Weight::from_parts(1_521_724_050, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn ark_bls12_377_multi_miller_loop(x: u32, ) -> Weight {
+		Weight::from_parts(272_674_350, 0)
+			.saturating_add(Weight::from_parts(6_135_172_200, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn sub_bls12_377_multi_miller_loop(x: u32, ) -> Weight {
+		Weight::from_parts(67_630_950, 0)
+			.saturating_add(Weight::from_parts(1_521_724_050, 0).saturating_mul(x.into()))
+	}
+	fn ark_bls12_377_final_exponentiation() -> Weight {
+		Weight::from_parts(7_225_870_950, 0)
+	}
+	fn sub_bls12_377_final_exponentiation() -> Weight {
+		Weight::from_parts(1_792_251_900, 0)
+	}
+	fn ark_bw6_761_pairing() -> Weight {
+		Weight::from_parts(50_495_255_000, 0)
+	}
+	fn sub_bw6_761_pairing() -> Weight {
+		Weight::from_parts(12_524_475_000, 0)
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bw6_761_msm_g1(x: u32, ) -> Weight {
+		Weight::from_parts(40_110_328_493, 0)
+			.saturating_add(Weight::from_parts(6_229_954_968, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bw6_761_msm_g1(x: u32, ) -> Weight {
+		Weight::from_parts(4_122_540_230, 0)
+			.saturating_add(Weight::from_parts(2_165_480_843, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bw6_761_mul_projective_g1(x: u32, ) -> Weight {
+		Weight::from_parts(326_489_660, 0)
+			.saturating_add(Weight::from_parts(1_550_825_885, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bw6_761_mul_projective_g1(x: u32, ) -> Weight {
+		Weight::from_parts(49_744_485, 0)
+			.saturating_add(Weight::from_parts(236_286_300, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bw6_761_mul_affine_g1(x: u32, ) -> Weight {
+		Weight::from_parts(649_458_135, 0)
+			.saturating_add(Weight::from_parts(3_084_926_138, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bw6_761_mul_affine_g1(x: u32, ) -> Weight {
+		Weight::from_parts(649_280_585, 0)
+			.saturating_add(Weight::from_parts(3_084_082_775, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bw6_761_msm_g2(x: u32, ) -> Weight {
+		Weight::from_parts(38_178_262_416, 0)
+			.saturating_add(Weight::from_parts(4_497_456_940, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bw6_761_msm_g2(x: u32, ) -> Weight {
+		Weight::from_parts(2_758_442_392, 0)
+			.saturating_add(Weight::from_parts(1_791_192_123, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bw6_761_mul_projective_g2(x: u32, ) -> Weight {
+		Weight::from_parts(489_195_000, 0)
+			.saturating_add(Weight::from_parts(2_323_676_250, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bw6_761_mul_projective_g2(x: u32, ) -> Weight {
+		Weight::from_parts(73_179_120, 0)
+			.saturating_add(Weight::from_parts(347_600_820, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bw6_761_mul_affine_g2(x: u32, ) -> Weight {
+		Weight::from_parts(546_874_965, 0)
+			.saturating_add(Weight::from_parts(2_597_656_083, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bw6_761_mul_affine_g2(x: u32, ) -> Weight {
+		Weight::from_parts(546_702_765, 0)
+			.saturating_add(Weight::from_parts(2_596_838_133, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn ark_bw6_761_multi_pairing(x: u32, ) -> Weight {
+		Weight::from_parts(27_772_390_000, 0)
+			.saturating_add(Weight::from_parts(28_630_803_600, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn sub_bw6_761_multi_pairing(x: u32, ) -> Weight {
+		Weight::from_parts(6_888_460_000, 0)
+			.saturating_add(Weight::from_parts(7_101_378_900, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn ark_bw6_761_multi_miller_loop(x: u32, ) -> Weight {
+		Weight::from_parts(1_272_480_300, 0)
+			.saturating_add(Weight::from_parts(28_630_803_600, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn sub_bw6_761_multi_miller_loop(x: u32, ) -> Weight {
+		Weight::from_parts(315_611_100, 0)
+			.saturating_add(Weight::from_parts(7_101_378_900, 0).saturating_mul(x.into()))
+	}
+	fn ark_bw6_761_final_exponentiation() -> Weight {
+		Weight::from_parts(22_212_862_550, 0)
+	}
+	fn sub_bw6_761_final_exponentiation() -> Weight {
+		Weight::from_parts(5_509_515_100, 0)
 	}
 	/// The range of component `x` is `[10, 100]`.
 	fn ark_ed_on_bls12_377_msm_te(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 12_443_508_000 picoseconds.
 		Weight::from_parts(5_027_149_918, 0)
-			// Standard Error: 3_111_716
 			.saturating_add(Weight::from_parts(930_284_384, 0).saturating_mul(x.into()))
 	}
 	/// The range of component `x` is `[10, 100]`.
 	fn sub_ed_on_bls12_377_msm_te(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 2_985_429_000 picoseconds.
 		Weight::from_parts(479_453_085, 0)
-			// Standard Error: 166_190
 			.saturating_add(Weight::from_parts(255_170_227, 0).saturating_mul(x.into()))
 	}
-	fn ark_ed_on_bls12_377_mul_projective_te() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 614_174_000 picoseconds.
-		Weight::from_parts(619_943_000, 0)
-	}
-	fn sub_ed_on_bls12_377_mul_projective_te() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 103_121_000 picoseconds.
-		Weight::from_parts(104_171_000, 0)
-	}
-	fn ark_ed_on_bls12_377_mul_affine_te() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 1_402_989_000 picoseconds.
-		Weight::from_parts(1_406_879_000, 0)
-	}
-	fn sub_ed_on_bls12_377_mul_affine_te() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 370_453_000 picoseconds.
-		Weight::from_parts(373_842_000, 0)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_ed_on_bls12_377_mul_projective_te(x: u32, ) -> Weight {
+		Weight::from_parts(30_997_150, 0)
+			.saturating_add(Weight::from_parts(147_236_462, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_ed_on_bls12_377_mul_projective_te(x: u32, ) -> Weight {
+		Weight::from_parts(5_208_550, 0)
+			.saturating_add(Weight::from_parts(24_740_612, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_ed_on_bls12_377_mul_affine_te(x: u32, ) -> Weight {
+		Weight::from_parts(70_343_950, 0)
+			.saturating_add(Weight::from_parts(334_133_762, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_ed_on_bls12_377_mul_affine_te(x: u32, ) -> Weight {
+		Weight::from_parts(18_692_100, 0)
+			.saturating_add(Weight::from_parts(88_787_475, 0).saturating_mul(x.into()))
 	}
 	/// The range of component `x` is `[10, 100]`.
 	fn ark_ed_on_bls12_381_bandersnatch_msm_sw(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 11_026_159_000 picoseconds.
 		Weight::from_parts(2_986_907_027, 0)
-			// Standard Error: 1_112_799
 			.saturating_add(Weight::from_parts(912_186_460, 0).saturating_mul(x.into()))
 	}
 	/// The range of component `x` is `[10, 100]`.
 	fn sub_ed_on_bls12_381_bandersnatch_msm_sw(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 3_039_409_000 picoseconds.
 		Weight::from_parts(479_249_268, 0)
-			// Standard Error: 128_249
 			.saturating_add(Weight::from_parts(260_759_215, 0).saturating_mul(x.into()))
 	}
-	fn ark_ed_on_bls12_381_bandersnatch_mul_projective_sw() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 805_802_000 picoseconds.
-		Weight::from_parts(812_501_000, 0)
-	}
-	fn sub_ed_on_bls12_381_bandersnatch_mul_projective_sw() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 148_432_000 picoseconds.
-		Weight::from_parts(149_762_000, 0)
-	}
-	fn ark_ed_on_bls12_381_bandersnatch_mul_affine_sw() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 1_483_811_000 picoseconds.
-		Weight::from_parts(1_489_092_000, 0)
-	}
-	fn sub_ed_on_bls12_381_bandersnatch_mul_affine_sw() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 402_685_000 picoseconds.
-		Weight::from_parts(408_656_000, 0)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_ed_on_bls12_381_bandersnatch_mul_projective_sw(x: u32, ) -> Weight {
+		Weight::from_parts(40_625_050, 0)
+			.saturating_add(Weight::from_parts(192_968_987, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_ed_on_bls12_381_bandersnatch_mul_projective_sw(x: u32, ) -> Weight {
+		Weight::from_parts(7_488_100, 0)
+			.saturating_add(Weight::from_parts(35_568_475, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_ed_on_bls12_381_bandersnatch_mul_affine_sw(x: u32, ) -> Weight {
+		Weight::from_parts(74_454_600, 0)
+			.saturating_add(Weight::from_parts(353_659_350, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_ed_on_bls12_381_bandersnatch_mul_affine_sw(x: u32, ) -> Weight {
+		Weight::from_parts(20_432_800, 0)
+			.saturating_add(Weight::from_parts(97_055_800, 0).saturating_mul(x.into()))
 	}
 	/// The range of component `x` is `[10, 100]`.
 	fn ark_ed_on_bls12_381_bandersnatch_msm_te(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 12_033_422_000 picoseconds.
 		Weight::from_parts(5_088_349_647, 0)
-			// Standard Error: 3_222_895
 			.saturating_add(Weight::from_parts(895_491_059, 0).saturating_mul(x.into()))
 	}
 	/// The range of component `x` is `[10, 100]`.
 	fn sub_ed_on_bls12_381_bandersnatch_msm_te(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 2_710_625_000 picoseconds.
 		Weight::from_parts(513_600_604, 0)
-			// Standard Error: 166_970
 			.saturating_add(Weight::from_parts(233_261_877, 0).saturating_mul(x.into()))
 	}
-	fn ark_ed_on_bls12_381_bandersnatch_mul_projective_te() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 613_578_000 picoseconds.
-		Weight::from_parts(621_048_000, 0)
-	}
-	fn sub_ed_on_bls12_381_bandersnatch_mul_projective_te() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 109_721_000 picoseconds.
-		Weight::from_parts(110_591_000, 0)
-	}
-	fn ark_ed_on_bls12_381_bandersnatch_mul_affine_te() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 1_329_407_000 picoseconds.
-		Weight::from_parts(1_336_746_000, 0)
-	}
-	fn sub_ed_on_bls12_381_bandersnatch_mul_affine_te() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 318_624_000 picoseconds.
-		Weight::from_parts(322_934_000, 0)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_ed_on_bls12_381_bandersnatch_mul_projective_te(x: u32, ) -> Weight {
+		Weight::from_parts(31_052_400, 0)
+			.saturating_add(Weight::from_parts(147_498_900, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_ed_on_bls12_381_bandersnatch_mul_projective_te(x: u32, ) -> Weight {
+		Weight::from_parts(5_529_550, 0)
+			.saturating_add(Weight::from_parts(26_265_362, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_ed_on_bls12_381_bandersnatch_mul_affine_te(x: u32, ) -> Weight {
+		Weight::from_parts(66_837_300, 0)
+			.saturating_add(Weight::from_parts(317_477_175, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_ed_on_bls12_381_bandersnatch_mul_affine_te(x: u32, ) -> Weight {
+		Weight::from_parts(16_146_700, 0)
+			.saturating_add(Weight::from_parts(76_696_825, 0).saturating_mul(x.into()))
+	}
+	fn ed_on_bls12_381_bandersnatch_te_to_sw() -> Weight {
+		Weight::from_parts(214_381_000, 0)
+	}
+	fn ed_on_bls12_381_bandersnatch_sw_to_te() -> Weight {
+		Weight::from_parts(221_907_000, 0)
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ed_on_bls12_381_bandersnatch_te_to_sw_batch(x: u32, ) -> Weight {
+		Weight::from_parts(27_664_512, 0)
+			.saturating_add(Weight::from_parts(189_418_337, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ed_on_bls12_381_bandersnatch_sw_to_te_batch(x: u32, ) -> Weight {
+		Weight::from_parts(28_051_906, 0)
+			.saturating_add(Weight::from_parts(196_772_140, 0).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_381_msm_fixed_base_g1(x: u32, ) -> Weight {
+		Weight::from_parts(6_011_616_193, 3486)
+			.saturating_add(Weight::from_parts(677_575_455, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_381_msm_fixed_base_g1(x: u32, ) -> Weight {
+		Weight::from_parts(640_304_512, 3486)
+			.saturating_add(Weight::from_parts(140_543_710, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_381_msm_fixed_base_g2(x: u32, ) -> Weight {
+		Weight::from_parts(18_205_124_960, 3486)
+			.saturating_add(Weight::from_parts(1_253_341_796, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_381_msm_fixed_base_g2(x: u32, ) -> Weight {
+		Weight::from_parts(1_338_543_996, 3486)
+			.saturating_add(Weight::from_parts(305_234_890, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_377_msm_fixed_base_g1(x: u32, ) -> Weight {
+		Weight::from_parts(7_815_101_051, 3486)
+			.saturating_add(Weight::from_parts(880_848_092, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_377_msm_fixed_base_g1(x: u32, ) -> Weight {
+		Weight::from_parts(832_395_866, 3486)
+			.saturating_add(Weight::from_parts(182_706_823, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_377_msm_fixed_base_g2(x: u32, ) -> Weight {
+		Weight::from_parts(27_307_687_440, 3486)
+			.saturating_add(Weight::from_parts(1_880_012_694, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_377_msm_fixed_base_g2(x: u32, ) -> Weight {
+		Weight::from_parts(2_007_815_994, 3486)
+			.saturating_add(Weight::from_parts(457_852_335, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bw6_761_msm_fixed_base_g1(x: u32, ) -> Weight {
+		Weight::from_parts(40_277_828_493, 3486)
+			.saturating_add(Weight::from_parts(4_539_755_548, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bw6_761_msm_fixed_base_g1(x: u32, ) -> Weight {
+		Weight::from_parts(4_290_040_230, 3486)
+			.saturating_add(Weight::from_parts(941_642_857, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bw6_761_msm_fixed_base_g2(x: u32, ) -> Weight {
+		Weight::from_parts(38_230_762_416, 3486)
+			.saturating_add(Weight::from_parts(2_632_017_772, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bw6_761_msm_fixed_base_g2(x: u32, ) -> Weight {
+		Weight::from_parts(2_810_942_392, 3486)
+			.saturating_add(Weight::from_parts(640_993_269, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_377_msm_fixed_base_te(x: u32, ) -> Weight {
+		Weight::from_parts(5_052_149_918, 3486)
+			.saturating_add(Weight::from_parts(755_532_818, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_377_msm_fixed_base_te(x: u32, ) -> Weight {
+		Weight::from_parts(504_453_085, 3486)
+			.saturating_add(Weight::from_parts(150_558_955, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_381_bandersnatch_msm_fixed_base_sw(x: u32, ) -> Weight {
+		Weight::from_parts(3_011_907_027, 3486)
+			.saturating_add(Weight::from_parts(748_016_287, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_381_bandersnatch_msm_fixed_base_sw(x: u32, ) -> Weight {
+		Weight::from_parts(504_249_268, 3486)
+			.saturating_add(Weight::from_parts(161_399_401, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_381_bandersnatch_msm_fixed_base_te(x: u32, ) -> Weight {
+		Weight::from_parts(5_113_349_647, 3486)
+			.saturating_add(Weight::from_parts(726_611_729, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_381_bandersnatch_msm_fixed_base_te(x: u32, ) -> Weight {
+		Weight::from_parts(538_600_604, 3486)
+			.saturating_add(Weight::from_parts(128_067_934, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:1)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:0 w:4)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn bls12_381_register_base_set_g1(x: u32, ) -> Weight {
+		Weight::from_parts(78_607_639, 3486)
+			.saturating_add(Weight::from_parts(199_559_950, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:1)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:0 w:4)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn bls12_381_register_base_set_g2(x: u32, ) -> Weight {
+		Weight::from_parts(82_256_679, 3486)
+			.saturating_add(Weight::from_parts(573_236_514, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:1)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:0 w:4)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn bls12_377_register_base_set_g1(x: u32, ) -> Weight {
+		Weight::from_parts(102_189_931, 3486)
+			.saturating_add(Weight::from_parts(259_427_935, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:1)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:0 w:4)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn bls12_377_register_base_set_g2(x: u32, ) -> Weight {
+		Weight::from_parts(123_385_018, 3486)
+			.saturating_add(Weight::from_parts(859_854_771, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:1)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:0 w:4)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn bw6_761_register_base_set_g1(x: u32, ) -> Weight {
+		Weight::from_parts(526_671_181, 3486)
+			.saturating_add(Weight::from_parts(1_337_051_665, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:1)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:0 w:4)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn bw6_761_register_base_set_g2(x: u32, ) -> Weight {
+		Weight::from_parts(172_739_026, 3486)
+			.saturating_add(Weight::from_parts(1_203_796_679, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:1)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:0 w:4)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ed_on_bls12_377_register_base_set_te(x: u32, ) -> Weight {
+		Weight::from_parts(83_721_053, 3486)
+			.saturating_add(Weight::from_parts(116_267_738, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:1)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:0 w:4)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ed_on_bls12_381_bandersnatch_register_base_set_sw(x: u32, ) -> Weight {
+		Weight::from_parts(78_817_306, 3486)
+			.saturating_add(Weight::from_parts(112_288_888, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:1)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:0 w:4)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ed_on_bls12_381_bandersnatch_register_base_set_te(x: u32, ) -> Weight {
+		Weight::from_parts(82_164_226, 3486)
+			.saturating_add(Weight::from_parts(116_732_959, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:1)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:0 w:16)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn remove_base_set(x: u32, ) -> Weight {
+		Weight::from_parts(31_406_000, 3486)
+			.saturating_add(Weight::from_parts(9_827_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_381_decode_g1_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(20_716_506, 0)
+			.saturating_add(Weight::from_parts(150_993_908, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_381_decode_g1_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(19_265_414, 0)
+			.saturating_add(Weight::from_parts(150_414_002, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_381_decode_g1_validated(x: u32, ) -> Weight {
+		Weight::from_parts(23_460_434, 0)
+			.saturating_add(Weight::from_parts(260_050_631, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_381_decode_g1_validated(x: u32, ) -> Weight {
+		Weight::from_parts(18_607_639, 0)
+			.saturating_add(Weight::from_parts(190_861_168, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_381_decode_g2_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(22_495_304, 0)
+			.saturating_add(Weight::from_parts(420_098_702, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_381_decode_g2_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(21_067_620, 0)
+			.saturating_add(Weight::from_parts(420_611_097, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_381_decode_g2_validated(x: u32, ) -> Weight {
+		Weight::from_parts(18_486_530, 0)
+			.saturating_add(Weight::from_parts(900_953_893, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_381_decode_g2_validated(x: u32, ) -> Weight {
+		Weight::from_parts(22_256_679, 0)
+			.saturating_add(Weight::from_parts(560_225_127, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_377_decode_g1_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(26_931_458, 0)
+			.saturating_add(Weight::from_parts(196_292_080, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_377_decode_g1_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(25_045_038, 0)
+			.saturating_add(Weight::from_parts(195_538_203, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_377_decode_g1_validated(x: u32, ) -> Weight {
+		Weight::from_parts(30_498_564, 0)
+			.saturating_add(Weight::from_parts(338_065_820, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_377_decode_g1_validated(x: u32, ) -> Weight {
+		Weight::from_parts(24_189_931, 0)
+			.saturating_add(Weight::from_parts(248_119_518, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_377_decode_g2_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(33_742_956, 0)
+			.saturating_add(Weight::from_parts(630_148_053, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_377_decode_g2_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(31_601_430, 0)
+			.saturating_add(Weight::from_parts(630_916_646, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_377_decode_g2_validated(x: u32, ) -> Weight {
+		Weight::from_parts(27_729_795, 0)
+			.saturating_add(Weight::from_parts(1_351_430_840, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_377_decode_g2_validated(x: u32, ) -> Weight {
+		Weight::from_parts(33_385_018, 0)
+			.saturating_add(Weight::from_parts(840_337_690, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bw6_761_decode_g1_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(138_800_590, 0)
+			.saturating_add(Weight::from_parts(1_011_659_184, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bw6_761_decode_g1_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(129_078_274, 0)
+			.saturating_add(Weight::from_parts(1_007_773_813, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bw6_761_decode_g1_validated(x: u32, ) -> Weight {
+		Weight::from_parts(157_184_908, 0)
+			.saturating_add(Weight::from_parts(1_742_339_228, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bw6_761_decode_g1_validated(x: u32, ) -> Weight {
+		Weight::from_parts(124_671_181, 0)
+			.saturating_add(Weight::from_parts(1_278_769_826, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bw6_761_decode_g2_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(47_240_138, 0)
+			.saturating_add(Weight::from_parts(882_207_274, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bw6_761_decode_g2_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(44_242_002, 0)
+			.saturating_add(Weight::from_parts(883_283_304, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bw6_761_decode_g2_validated(x: u32, ) -> Weight {
+		Weight::from_parts(38_821_713, 0)
+			.saturating_add(Weight::from_parts(1_892_003_175, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bw6_761_decode_g2_validated(x: u32, ) -> Weight {
+		Weight::from_parts(46_739_026, 0)
+			.saturating_add(Weight::from_parts(1_176_472_767, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_377_decode_te_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(22_535_601, 0)
+			.saturating_add(Weight::from_parts(90_123_514, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_377_decode_te_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(22_789_171, 0)
+			.saturating_add(Weight::from_parts(90_323_466, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_377_decode_te_validated(x: u32, ) -> Weight {
+		Weight::from_parts(22_699_778, 0)
+			.saturating_add(Weight::from_parts(180_855_770, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_377_decode_te_validated(x: u32, ) -> Weight {
+		Weight::from_parts(23_721_053, 0)
+			.saturating_add(Weight::from_parts(110_189_505, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_381_bandersnatch_decode_sw_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(18_864_493, 0)
+			.saturating_add(Weight::from_parts(85_609_851, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_381_bandersnatch_decode_sw_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(22_791_609, 0)
+			.saturating_add(Weight::from_parts(85_669_949, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_381_bandersnatch_decode_sw_validated(x: u32, ) -> Weight {
+		Weight::from_parts(19_575_976, 0)
+			.saturating_add(Weight::from_parts(170_390_487, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_381_bandersnatch_decode_sw_validated(x: u32, ) -> Weight {
+		Weight::from_parts(18_817_306, 0)
+			.saturating_add(Weight::from_parts(105_574_351, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_381_bandersnatch_decode_te_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(23_973_618, 0)
+			.saturating_add(Weight::from_parts(90_065_839, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_381_bandersnatch_decode_te_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(22_734_264, 0)
+			.saturating_add(Weight::from_parts(90_062_496, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_381_bandersnatch_decode_te_validated(x: u32, ) -> Weight {
+		Weight::from_parts(23_192_628, 0)
+			.saturating_add(Weight::from_parts(175_215_963, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_381_bandersnatch_decode_te_validated(x: u32, ) -> Weight {
+		Weight::from_parts(22_164_226, 0)
+			.saturating_add(Weight::from_parts(110_713_451, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_381_decode_g1_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(12_980_987, 0)
+			.saturating_add(Weight::from_parts(6_282_061, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_381_decode_g1_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(13_438_670, 0)
+			.saturating_add(Weight::from_parts(6_698_782, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_381_decode_g2_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(11_934_212, 0)
+			.saturating_add(Weight::from_parts(11_149_527, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_381_decode_g2_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(12_201_436, 0)
+			.saturating_add(Weight::from_parts(11_011_387, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_377_decode_g1_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(16_875_283, 0)
+			.saturating_add(Weight::from_parts(8_166_679, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_377_decode_g1_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(17_470_271, 0)
+			.saturating_add(Weight::from_parts(8_708_417, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_377_decode_g2_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(17_901_318, 0)
+			.saturating_add(Weight::from_parts(16_724_290, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_377_decode_g2_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(18_302_154, 0)
+			.saturating_add(Weight::from_parts(16_517_080, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bw6_761_decode_g1_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(86_972_613, 0)
+			.saturating_add(Weight::from_parts(42_089_809, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bw6_761_decode_g1_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(90_039_089, 0)
+			.saturating_add(Weight::from_parts(44_881_839, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bw6_761_decode_g2_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(25_061_845, 0)
+			.saturating_add(Weight::from_parts(23_414_007, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bw6_761_decode_g2_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(25_623_016, 0)
+			.saturating_add(Weight::from_parts(23_123_913, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_377_decode_te_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(9_493_454, 0)
+			.saturating_add(Weight::from_parts(4_604_204, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_377_decode_te_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(10_674_647, 0)
+			.saturating_add(Weight::from_parts(4_078_233, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_381_bandersnatch_decode_sw_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(13_307_716, 0)
+			.saturating_add(Weight::from_parts(4_720_314, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_381_bandersnatch_decode_sw_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(11_826_957, 0)
+			.saturating_add(Weight::from_parts(4_714_537, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_381_bandersnatch_decode_te_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(12_394_150, 0)
+			.saturating_add(Weight::from_parts(4_836_633, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_381_bandersnatch_decode_te_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(9_732_748, 0)
+			.saturating_add(Weight::from_parts(4_019_508, 0).saturating_mul(x.into()))
 	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
 	fn ark_bls12_381_pairing() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 10_088_641_000 picoseconds.
 		Weight::from_parts(10_099_051_000, 0)
 	}
 	fn sub_bls12_381_pairing() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 2_499_075_000 picoseconds.
 		Weight::from_parts(2_504_895_000, 0)
 	}
 	/// The range of component `x` is `[10, 100]`.
 	fn ark_bls12_381_msm_g1(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 12_884_898_000 picoseconds.
 		Weight::from_parts(5_986_616_193, 0)
-			// Standard Error: 2_379_451
 			.saturating_add(Weight::from_parts(929_844_025, 0).saturating_mul(x.into()))
 	}
 	/// The range of component `x` is `[10, 100]`.
 	fn sub_bls12_381_msm_g1(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 3_646_192_000 picoseconds.
 		Weight::from_parts(615_304_512, 0)
-			// Standard Error: 142_034
 			.saturating_add(Weight::from_parts(323_206_096, 0).saturating_mul(x.into()))
 	}
-	fn ark_bls12_381_mul_projective_g1() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 973_256_000 picoseconds.
-		Weight::from_parts(974_596_000, 0)
-	}
-	fn sub_bls12_381_mul_projective_g1() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 142_531_000 picoseconds.
-		Weight::from_parts(148_491_000, 0)
-	}
-	fn ark_bls12_381_mul_affine_g1() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 1_934_811_000 picoseconds.
-		Weight::from_parts(1_938_681_000, 0)
-	}
-	fn sub_bls12_381_mul_affine_g1() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 1_934_472_000 picoseconds.
-		Weight::from_parts(1_938_151_000, 0)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_381_mul_projective_g1(x: u32, ) -> Weight {
+		Weight::from_parts(48_729_800, 0)
+			.saturating_add(Weight::from_parts(231_466_550, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_381_mul_projective_g1(x: u32, ) -> Weight {
+		Weight::from_parts(7_424_550, 0)
+			.saturating_add(Weight::from_parts(35_266_612, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_381_mul_affine_g1(x: u32, ) -> Weight {
+		Weight::from_parts(96_934_050, 0)
+			.saturating_add(Weight::from_parts(460_436_737, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_381_mul_affine_g1(x: u32, ) -> Weight {
+		Weight::from_parts(96_907_550, 0)
+			.saturating_add(Weight::from_parts(460_310_862, 0).saturating_mul(x.into()))
 	}
 	/// The range of component `x` is `[10, 100]`.
 	fn ark_bls12_381_msm_g2(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 32_255_101_000 picoseconds.
 		Weight::from_parts(18_180_124_960, 0)
-			// Standard Error: 7_085_553
 			.saturating_add(Weight::from_parts(2_141_646_162, 0).saturating_mul(x.into()))
 	}
 	/// The range of component `x` is `[10, 100]`.
 	fn sub_bls12_381_msm_g2(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 9_522_987_000 picoseconds.
 		Weight::from_parts(1_313_543_996, 0)
-			// Standard Error: 297_937
 			.saturating_add(Weight::from_parts(852_948_630, 0).saturating_mul(x.into()))
 	}
-	fn ark_bls12_381_mul_projective_g2() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 4_647_659_000 picoseconds.
-		Weight::from_parts(4_659_000_000, 0)
-	}
-	fn sub_bls12_381_mul_projective_g2() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 690_905_000 picoseconds.
-		Weight::from_parts(696_944_000, 0)
-	}
-	fn ark_bls12_381_mul_affine_g2() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 5_197_763_000 picoseconds.
-		Weight::from_parts(5_208_333_000, 0)
-	}
-	fn sub_bls12_381_mul_affine_g2() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 5_196_493_000 picoseconds.
-		Weight::from_parts(5_206_693_000, 0)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_381_mul_projective_g2(x: u32, ) -> Weight {
+		Weight::from_parts(232_950_000, 0)
+			.saturating_add(Weight::from_parts(1_106_512_500, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_381_mul_projective_g2(x: u32, ) -> Weight {
+		Weight::from_parts(34_847_200, 0)
+			.saturating_add(Weight::from_parts(165_524_200, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_381_mul_affine_g2(x: u32, ) -> Weight {
+		Weight::from_parts(260_416_650, 0)
+			.saturating_add(Weight::from_parts(1_236_979_087, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_381_mul_affine_g2(x: u32, ) -> Weight {
+		Weight::from_parts(260_334_650, 0)
+			.saturating_add(Weight::from_parts(1_236_589_587, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn ark_bls12_381_multi_pairing(x: u32, ) -> Weight {
+		Weight::from_parts(5_554_478_000, 0)
+			.saturating_add(Weight::from_parts(4_544_572_000, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn sub_bls12_381_multi_pairing(x: u32, ) -> Weight {
+		Weight::from_parts(1_377_692_000, 0)
+			.saturating_add(Weight::from_parts(1_127_203_000, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn ark_bls12_381_multi_miller_loop(x: u32, ) -> Weight {
+		Weight::from_parts(201_981_000, 0)
+			.saturating_add(Weight::from_parts(4_544_572_000, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn sub_bls12_381_multi_miller_loop(x: u32, ) -> Weight {
+		Weight::from_parts(50_097_000, 0)
+			.saturating_add(Weight::from_parts(1_127_203_000, 0).saturating_mul(x.into()))
+	}
+	fn ark_bls12_381_final_exponentiation() -> Weight {
+		Weight::from_parts(5_352_497_000, 0)
+	}
+	fn sub_bls12_381_final_exponentiation() -> Weight {
+		Weight::from_parts(1_327_594_000, 0)
+	}
+	/// The range of component `x` is `[0, 1024]`.
+	fn ark_bls12_381_hash_to_g1(x: u32, ) -> Weight {
+		Weight::from_parts(2_412_553_000, 0)
+			.saturating_add(Weight::from_parts(15_240, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[0, 1024]`.
+	fn sub_bls12_381_hash_to_g1(x: u32, ) -> Weight {
+		Weight::from_parts(1_893_407_000, 0)
+			.saturating_add(Weight::from_parts(15_180, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[0, 1024]`.
+	fn ark_bls12_381_hash_to_g2(x: u32, ) -> Weight {
+		Weight::from_parts(7_536_218_000, 0)
+			.saturating_add(Weight::from_parts(15_310, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[0, 1024]`.
+	fn sub_bls12_381_hash_to_g2(x: u32, ) -> Weight {
+		Weight::from_parts(4_027_941_000, 0)
+			.saturating_add(Weight::from_parts(15_270, 0).saturating_mul(x.into()))
+	}
+	fn ark_bls12_377_pairing() -> Weight {
+		Weight::from_parts(15_148_576_500, 0)
+	}
+	fn sub_bls12_377_pairing() -> Weight {
+		Weight::from_parts(3_757_342_500, 0)
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_377_msm_g1(x: u32, ) -> Weight {
+		Weight::from_parts(7_782_601_051, 0)
+			.saturating_add(Weight::from_parts(1_208_797_232, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_377_msm_g1(x: u32, ) -> Weight {
+		Weight::from_parts(799_895_866, 0)
+			.saturating_add(Weight::from_parts(420_167_925, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_377_mul_projective_g1(x: u32, ) -> Weight {
+		Weight::from_parts(63_348_740, 0)
+			.saturating_add(Weight::from_parts(300_906_515, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_377_mul_projective_g1(x: u32, ) -> Weight {
+		Weight::from_parts(9_651_915, 0)
+			.saturating_add(Weight::from_parts(45_846_596, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_377_mul_affine_g1(x: u32, ) -> Weight {
+		Weight::from_parts(126_014_265, 0)
+			.saturating_add(Weight::from_parts(598_567_758, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_377_mul_affine_g1(x: u32, ) -> Weight {
+		Weight::from_parts(125_979_815, 0)
+			.saturating_add(Weight::from_parts(598_404_121, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_377_msm_g2(x: u32, ) -> Weight {
+		Weight::from_parts(27_270_187_440, 0)
+			.saturating_add(Weight::from_parts(3_212_469_243, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_377_msm_g2(x: u32, ) -> Weight {
+		Weight::from_parts(1_970_315_994, 0)
+			.saturating_add(Weight::from_parts(1_279_422_945, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_377_mul_projective_g2(x: u32, ) -> Weight {
+		Weight::from_parts(349_425_000, 0)
+			.saturating_add(Weight::from_parts(1_659_768_750, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_377_mul_projective_g2(x: u32, ) -> Weight {
+		Weight::from_parts(52_270_800, 0)
+			.saturating_add(Weight::from_parts(248_286_300, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bls12_377_mul_affine_g2(x: u32, ) -> Weight {
+		Weight::from_parts(390_624_975, 0)
+			.saturating_add(Weight::from_parts(1_855_468_630, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bls12_377_mul_affine_g2(x: u32, ) -> Weight {
+		Weight::from_parts(390_501_975, 0)
+			.saturating_add(Weight::from_parts(1_854_884_380, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn ark_bls12_377_multi_pairing(x: u32, ) -> Weight {
+		Weight::from_parts(8_331_717_000, 0)
+			.saturating_add(Weight::from_parts(6_135_172_200, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn sub_bls12_377_multi_pairing(x: u32, ) -> Weight {
+		Weight::from_parts(2_066_538_000, 0)
+			.saturating_add(Weight::from_parts(1_521_724_050, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn ark_bls12_377_multi_miller_loop(x: u32, ) -> Weight {
+		Weight::from_parts(272_674_350, 0)
+			.saturating_add(Weight::from_parts(6_135_172_200, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn sub_bls12_377_multi_miller_loop(x: u32, ) -> Weight {
+		Weight::from_parts(67_630_950, 0)
+			.saturating_add(Weight::from_parts(1_521_724_050, 0).saturating_mul(x.into()))
+	}
+	fn ark_bls12_377_final_exponentiation() -> Weight {
+		Weight::from_parts(7_225_870_950, 0)
+	}
+	fn sub_bls12_377_final_exponentiation() -> Weight {
+		Weight::from_parts(1_792_251_900, 0)
+	}
+	fn ark_bw6_761_pairing() -> Weight {
+		Weight::from_parts(50_495_255_000, 0)
+	}
+	fn sub_bw6_761_pairing() -> Weight {
+		Weight::from_parts(12_524_475_000, 0)
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bw6_761_msm_g1(x: u32, ) -> Weight {
+		Weight::from_parts(40_110_328_493, 0)
+			.saturating_add(Weight::from_parts(6_229_954_968, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bw6_761_msm_g1(x: u32, ) -> Weight {
+		Weight::from_parts(4_122_540_230, 0)
+			.saturating_add(Weight::from_parts(2_165_480_843, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bw6_761_mul_projective_g1(x: u32, ) -> Weight {
+		Weight::from_parts(326_489_660, 0)
+			.saturating_add(Weight::from_parts(1_550_825_885, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bw6_761_mul_projective_g1(x: u32, ) -> Weight {
+		Weight::from_parts(49_744_485, 0)
+			.saturating_add(Weight::from_parts(236_286_300, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bw6_761_mul_affine_g1(x: u32, ) -> Weight {
+		Weight::from_parts(649_458_135, 0)
+			.saturating_add(Weight::from_parts(3_084_926_138, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bw6_761_mul_affine_g1(x: u32, ) -> Weight {
+		Weight::from_parts(649_280_585, 0)
+			.saturating_add(Weight::from_parts(3_084_082_775, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bw6_761_msm_g2(x: u32, ) -> Weight {
+		Weight::from_parts(38_178_262_416, 0)
+			.saturating_add(Weight::from_parts(4_497_456_940, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bw6_761_msm_g2(x: u32, ) -> Weight {
+		Weight::from_parts(2_758_442_392, 0)
+			.saturating_add(Weight::from_parts(1_791_192_123, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bw6_761_mul_projective_g2(x: u32, ) -> Weight {
+		Weight::from_parts(489_195_000, 0)
+			.saturating_add(Weight::from_parts(2_323_676_250, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bw6_761_mul_projective_g2(x: u32, ) -> Weight {
+		Weight::from_parts(73_179_120, 0)
+			.saturating_add(Weight::from_parts(347_600_820, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_bw6_761_mul_affine_g2(x: u32, ) -> Weight {
+		Weight::from_parts(546_874_965, 0)
+			.saturating_add(Weight::from_parts(2_597_656_083, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_bw6_761_mul_affine_g2(x: u32, ) -> Weight {
+		Weight::from_parts(546_702_765, 0)
+			.saturating_add(Weight::from_parts(2_596_838_133, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn ark_bw6_761_multi_pairing(x: u32, ) -> Weight {
+		Weight::from_parts(27_772_390_000, 0)
+			.saturating_add(Weight::from_parts(28_630_803_600, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn sub_bw6_761_multi_pairing(x: u32, ) -> Weight {
+		Weight::from_parts(6_888_460_000, 0)
+			.saturating_add(Weight::from_parts(7_101_378_900, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn ark_bw6_761_multi_miller_loop(x: u32, ) -> Weight {
+		Weight::from_parts(1_272_480_300, 0)
+			.saturating_add(Weight::from_parts(28_630_803_600, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 10]`.
+	fn sub_bw6_761_multi_miller_loop(x: u32, ) -> Weight {
+		Weight::from_parts(315_611_100, 0)
+			.saturating_add(Weight::from_parts(7_101_378_900, 0).saturating_mul(x.into()))
+	}
+	fn ark_bw6_761_final_exponentiation() -> Weight {
+		Weight::from_parts(22_212_862_550, 0)
+	}
+	fn sub_bw6_761_final_exponentiation() -> Weight {
+		Weight::from_parts(5_509_515_100, 0)
 	}
 	/// The range of component `x` is `[10, 100]`.
 	fn ark_ed_on_bls12_377_msm_te(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 12_443_508_000 picoseconds.
 		Weight::from_parts(5_027_149_918, 0)
-			// Standard Error: 3_111_716
 			.saturating_add(Weight::from_parts(930_284_384, 0).saturating_mul(x.into()))
 	}
 	/// The range of component `x` is `[10, 100]`.
 	fn sub_ed_on_bls12_377_msm_te(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 2_985_429_000 picoseconds.
 		Weight::from_parts(479_453_085, 0)
-			// Standard Error: 166_190
 			.saturating_add(Weight::from_parts(255_170_227, 0).saturating_mul(x.into()))
 	}
-	fn ark_ed_on_bls12_377_mul_projective_te() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 614_174_000 picoseconds.
-		Weight::from_parts(619_943_000, 0)
-	}
-	fn sub_ed_on_bls12_377_mul_projective_te() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 103_121_000 picoseconds.
-		Weight::from_parts(104_171_000, 0)
-	}
-	fn ark_ed_on_bls12_377_mul_affine_te() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 1_402_989_000 picoseconds.
-		Weight::from_parts(1_406_879_000, 0)
-	}
-	fn sub_ed_on_bls12_377_mul_affine_te() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 370_453_000 picoseconds.
-		Weight::from_parts(373_842_000, 0)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_ed_on_bls12_377_mul_projective_te(x: u32, ) -> Weight {
+		Weight::from_parts(30_997_150, 0)
+			.saturating_add(Weight::from_parts(147_236_462, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_ed_on_bls12_377_mul_projective_te(x: u32, ) -> Weight {
+		Weight::from_parts(5_208_550, 0)
+			.saturating_add(Weight::from_parts(24_740_612, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_ed_on_bls12_377_mul_affine_te(x: u32, ) -> Weight {
+		Weight::from_parts(70_343_950, 0)
+			.saturating_add(Weight::from_parts(334_133_762, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_ed_on_bls12_377_mul_affine_te(x: u32, ) -> Weight {
+		Weight::from_parts(18_692_100, 0)
+			.saturating_add(Weight::from_parts(88_787_475, 0).saturating_mul(x.into()))
 	}
 	/// The range of component `x` is `[10, 100]`.
 	fn ark_ed_on_bls12_381_bandersnatch_msm_sw(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 11_026_159_000 picoseconds.
 		Weight::from_parts(2_986_907_027, 0)
-			// Standard Error: 1_112_799
 			.saturating_add(Weight::from_parts(912_186_460, 0).saturating_mul(x.into()))
 	}
 	/// The range of component `x` is `[10, 100]`.
 	fn sub_ed_on_bls12_381_bandersnatch_msm_sw(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 3_039_409_000 picoseconds.
 		Weight::from_parts(479_249_268, 0)
-			// Standard Error: 128_249
 			.saturating_add(Weight::from_parts(260_759_215, 0).saturating_mul(x.into()))
 	}
-	fn ark_ed_on_bls12_381_bandersnatch_mul_projective_sw() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 805_802_000 picoseconds.
-		Weight::from_parts(812_501_000, 0)
-	}
-	fn sub_ed_on_bls12_381_bandersnatch_mul_projective_sw() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 148_432_000 picoseconds.
-		Weight::from_parts(149_762_000, 0)
-	}
-	fn ark_ed_on_bls12_381_bandersnatch_mul_affine_sw() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 1_483_811_000 picoseconds.
-		Weight::from_parts(1_489_092_000, 0)
-	}
-	fn sub_ed_on_bls12_381_bandersnatch_mul_affine_sw() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 402_685_000 picoseconds.
-		Weight::from_parts(408_656_000, 0)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_ed_on_bls12_381_bandersnatch_mul_projective_sw(x: u32, ) -> Weight {
+		Weight::from_parts(40_625_050, 0)
+			.saturating_add(Weight::from_parts(192_968_987, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_ed_on_bls12_381_bandersnatch_mul_projective_sw(x: u32, ) -> Weight {
+		Weight::from_parts(7_488_100, 0)
+			.saturating_add(Weight::from_parts(35_568_475, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_ed_on_bls12_381_bandersnatch_mul_affine_sw(x: u32, ) -> Weight {
+		Weight::from_parts(74_454_600, 0)
+			.saturating_add(Weight::from_parts(353_659_350, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_ed_on_bls12_381_bandersnatch_mul_affine_sw(x: u32, ) -> Weight {
+		Weight::from_parts(20_432_800, 0)
+			.saturating_add(Weight::from_parts(97_055_800, 0).saturating_mul(x.into()))
 	}
 	/// The range of component `x` is `[10, 100]`.
 	fn ark_ed_on_bls12_381_bandersnatch_msm_te(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 12_033_422_000 picoseconds.
 		Weight::from_parts(5_088_349_647, 0)
-			// Standard Error: 3_222_895
 			.saturating_add(Weight::from_parts(895_491_059, 0).saturating_mul(x.into()))
 	}
 	/// The range of component `x` is `[10, 100]`.
 	fn sub_ed_on_bls12_381_bandersnatch_msm_te(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 2_710_625_000 picoseconds.
 		Weight::from_parts(513_600_604, 0)
-			// Standard Error: 166_970
 			.saturating_add(Weight::from_parts(233_261_877, 0).saturating_mul(x.into()))
 	}
-	fn ark_ed_on_bls12_381_bandersnatch_mul_projective_te() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 613_578_000 picoseconds.
-		Weight::from_parts(621_048_000, 0)
-	}
-	fn sub_ed_on_bls12_381_bandersnatch_mul_projective_te() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 109_721_000 picoseconds.
-		Weight::from_parts(110_591_000, 0)
-	}
-	fn ark_ed_on_bls12_381_bandersnatch_mul_affine_te() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 1_329_407_000 picoseconds.
-		Weight::from_parts(1_336_746_000, 0)
-	}
-	fn sub_ed_on_bls12_381_bandersnatch_mul_affine_te() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 318_624_000 picoseconds.
-		Weight::from_parts(322_934_000, 0)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_ed_on_bls12_381_bandersnatch_mul_projective_te(x: u32, ) -> Weight {
+		Weight::from_parts(31_052_400, 0)
+			.saturating_add(Weight::from_parts(147_498_900, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_ed_on_bls12_381_bandersnatch_mul_projective_te(x: u32, ) -> Weight {
+		Weight::from_parts(5_529_550, 0)
+			.saturating_add(Weight::from_parts(26_265_362, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_ed_on_bls12_381_bandersnatch_mul_affine_te(x: u32, ) -> Weight {
+		Weight::from_parts(66_837_300, 0)
+			.saturating_add(Weight::from_parts(317_477_175, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_ed_on_bls12_381_bandersnatch_mul_affine_te(x: u32, ) -> Weight {
+		Weight::from_parts(16_146_700, 0)
+			.saturating_add(Weight::from_parts(76_696_825, 0).saturating_mul(x.into()))
+	}
+	fn ed_on_bls12_381_bandersnatch_te_to_sw() -> Weight {
+		Weight::from_parts(214_381_000, 0)
+	}
+	fn ed_on_bls12_381_bandersnatch_sw_to_te() -> Weight {
+		Weight::from_parts(221_907_000, 0)
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ed_on_bls12_381_bandersnatch_te_to_sw_batch(x: u32, ) -> Weight {
+		Weight::from_parts(27_664_512, 0)
+			.saturating_add(Weight::from_parts(189_418_337, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ed_on_bls12_381_bandersnatch_sw_to_te_batch(x: u32, ) -> Weight {
+		Weight::from_parts(28_051_906, 0)
+			.saturating_add(Weight::from_parts(196_772_140, 0).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_381_msm_fixed_base_g1(x: u32, ) -> Weight {
+		Weight::from_parts(6_011_616_193, 3486)
+			.saturating_add(Weight::from_parts(677_575_455, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_381_msm_fixed_base_g1(x: u32, ) -> Weight {
+		Weight::from_parts(640_304_512, 3486)
+			.saturating_add(Weight::from_parts(140_543_710, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_381_msm_fixed_base_g2(x: u32, ) -> Weight {
+		Weight::from_parts(18_205_124_960, 3486)
+			.saturating_add(Weight::from_parts(1_253_341_796, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_381_msm_fixed_base_g2(x: u32, ) -> Weight {
+		Weight::from_parts(1_338_543_996, 3486)
+			.saturating_add(Weight::from_parts(305_234_890, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_377_msm_fixed_base_g1(x: u32, ) -> Weight {
+		Weight::from_parts(7_815_101_051, 3486)
+			.saturating_add(Weight::from_parts(880_848_092, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_377_msm_fixed_base_g1(x: u32, ) -> Weight {
+		Weight::from_parts(832_395_866, 3486)
+			.saturating_add(Weight::from_parts(182_706_823, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_377_msm_fixed_base_g2(x: u32, ) -> Weight {
+		Weight::from_parts(27_307_687_440, 3486)
+			.saturating_add(Weight::from_parts(1_880_012_694, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_377_msm_fixed_base_g2(x: u32, ) -> Weight {
+		Weight::from_parts(2_007_815_994, 3486)
+			.saturating_add(Weight::from_parts(457_852_335, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bw6_761_msm_fixed_base_g1(x: u32, ) -> Weight {
+		Weight::from_parts(40_277_828_493, 3486)
+			.saturating_add(Weight::from_parts(4_539_755_548, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bw6_761_msm_fixed_base_g1(x: u32, ) -> Weight {
+		Weight::from_parts(4_290_040_230, 3486)
+			.saturating_add(Weight::from_parts(941_642_857, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bw6_761_msm_fixed_base_g2(x: u32, ) -> Weight {
+		Weight::from_parts(38_230_762_416, 3486)
+			.saturating_add(Weight::from_parts(2_632_017_772, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bw6_761_msm_fixed_base_g2(x: u32, ) -> Weight {
+		Weight::from_parts(2_810_942_392, 3486)
+			.saturating_add(Weight::from_parts(640_993_269, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_377_msm_fixed_base_te(x: u32, ) -> Weight {
+		Weight::from_parts(5_052_149_918, 3486)
+			.saturating_add(Weight::from_parts(755_532_818, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_377_msm_fixed_base_te(x: u32, ) -> Weight {
+		Weight::from_parts(504_453_085, 3486)
+			.saturating_add(Weight::from_parts(150_558_955, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_381_bandersnatch_msm_fixed_base_sw(x: u32, ) -> Weight {
+		Weight::from_parts(3_011_907_027, 3486)
+			.saturating_add(Weight::from_parts(748_016_287, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_381_bandersnatch_msm_fixed_base_sw(x: u32, ) -> Weight {
+		Weight::from_parts(504_249_268, 3486)
+			.saturating_add(Weight::from_parts(161_399_401, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_381_bandersnatch_msm_fixed_base_te(x: u32, ) -> Weight {
+		Weight::from_parts(5_113_349_647, 3486)
+			.saturating_add(Weight::from_parts(726_611_729, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:0)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:4 w:0)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_381_bandersnatch_msm_fixed_base_te(x: u32, ) -> Weight {
+		Weight::from_parts(538_600_604, 3486)
+			.saturating_add(Weight::from_parts(128_067_934, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(Weight::from_parts(0, 194).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:1)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:0 w:4)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn bls12_381_register_base_set_g1(x: u32, ) -> Weight {
+		Weight::from_parts(78_607_639, 3486)
+			.saturating_add(Weight::from_parts(199_559_950, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:1)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:0 w:4)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn bls12_381_register_base_set_g2(x: u32, ) -> Weight {
+		Weight::from_parts(82_256_679, 3486)
+			.saturating_add(Weight::from_parts(573_236_514, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:1)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:0 w:4)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn bls12_377_register_base_set_g1(x: u32, ) -> Weight {
+		Weight::from_parts(102_189_931, 3486)
+			.saturating_add(Weight::from_parts(259_427_935, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:1)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:0 w:4)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn bls12_377_register_base_set_g2(x: u32, ) -> Weight {
+		Weight::from_parts(123_385_018, 3486)
+			.saturating_add(Weight::from_parts(859_854_771, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:1)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:0 w:4)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn bw6_761_register_base_set_g1(x: u32, ) -> Weight {
+		Weight::from_parts(526_671_181, 3486)
+			.saturating_add(Weight::from_parts(1_337_051_665, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:1)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:0 w:4)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn bw6_761_register_base_set_g2(x: u32, ) -> Weight {
+		Weight::from_parts(172_739_026, 3486)
+			.saturating_add(Weight::from_parts(1_203_796_679, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:1)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:0 w:4)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ed_on_bls12_377_register_base_set_te(x: u32, ) -> Weight {
+		Weight::from_parts(83_721_053, 3486)
+			.saturating_add(Weight::from_parts(116_267_738, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:1)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:0 w:4)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ed_on_bls12_381_bandersnatch_register_base_set_sw(x: u32, ) -> Weight {
+		Weight::from_parts(78_817_306, 3486)
+			.saturating_add(Weight::from_parts(112_288_888, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:1)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:0 w:4)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[10, 100]`.
+	fn ed_on_bls12_381_bandersnatch_register_base_set_te(x: u32, ) -> Weight {
+		Weight::from_parts(82_164_226, 3486)
+			.saturating_add(Weight::from_parts(116_732_959, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ArkHostcalls::BaseSets` (r:1 w:1)
+	/// Proof: `ArkHostcalls::BaseSets` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `ArkHostcalls::BaseSetPages` (r:0 w:16)
+	/// Proof: `ArkHostcalls::BaseSetPages` (`max_values`: None, `max_size`: Some(6178), added: 8653, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn remove_base_set(x: u32, ) -> Weight {
+		Weight::from_parts(31_406_000, 3486)
+			.saturating_add(Weight::from_parts(9_827_000, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_381_decode_g1_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(20_716_506, 0)
+			.saturating_add(Weight::from_parts(150_993_908, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_381_decode_g1_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(19_265_414, 0)
+			.saturating_add(Weight::from_parts(150_414_002, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_381_decode_g1_validated(x: u32, ) -> Weight {
+		Weight::from_parts(23_460_434, 0)
+			.saturating_add(Weight::from_parts(260_050_631, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_381_decode_g1_validated(x: u32, ) -> Weight {
+		Weight::from_parts(18_607_639, 0)
+			.saturating_add(Weight::from_parts(190_861_168, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_381_decode_g2_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(22_495_304, 0)
+			.saturating_add(Weight::from_parts(420_098_702, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_381_decode_g2_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(21_067_620, 0)
+			.saturating_add(Weight::from_parts(420_611_097, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_381_decode_g2_validated(x: u32, ) -> Weight {
+		Weight::from_parts(18_486_530, 0)
+			.saturating_add(Weight::from_parts(900_953_893, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_381_decode_g2_validated(x: u32, ) -> Weight {
+		Weight::from_parts(22_256_679, 0)
+			.saturating_add(Weight::from_parts(560_225_127, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_377_decode_g1_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(26_931_458, 0)
+			.saturating_add(Weight::from_parts(196_292_080, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_377_decode_g1_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(25_045_038, 0)
+			.saturating_add(Weight::from_parts(195_538_203, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_377_decode_g1_validated(x: u32, ) -> Weight {
+		Weight::from_parts(30_498_564, 0)
+			.saturating_add(Weight::from_parts(338_065_820, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_377_decode_g1_validated(x: u32, ) -> Weight {
+		Weight::from_parts(24_189_931, 0)
+			.saturating_add(Weight::from_parts(248_119_518, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_377_decode_g2_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(33_742_956, 0)
+			.saturating_add(Weight::from_parts(630_148_053, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_377_decode_g2_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(31_601_430, 0)
+			.saturating_add(Weight::from_parts(630_916_646, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_377_decode_g2_validated(x: u32, ) -> Weight {
+		Weight::from_parts(27_729_795, 0)
+			.saturating_add(Weight::from_parts(1_351_430_840, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_377_decode_g2_validated(x: u32, ) -> Weight {
+		Weight::from_parts(33_385_018, 0)
+			.saturating_add(Weight::from_parts(840_337_690, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bw6_761_decode_g1_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(138_800_590, 0)
+			.saturating_add(Weight::from_parts(1_011_659_184, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bw6_761_decode_g1_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(129_078_274, 0)
+			.saturating_add(Weight::from_parts(1_007_773_813, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bw6_761_decode_g1_validated(x: u32, ) -> Weight {
+		Weight::from_parts(157_184_908, 0)
+			.saturating_add(Weight::from_parts(1_742_339_228, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bw6_761_decode_g1_validated(x: u32, ) -> Weight {
+		Weight::from_parts(124_671_181, 0)
+			.saturating_add(Weight::from_parts(1_278_769_826, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bw6_761_decode_g2_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(47_240_138, 0)
+			.saturating_add(Weight::from_parts(882_207_274, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bw6_761_decode_g2_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(44_242_002, 0)
+			.saturating_add(Weight::from_parts(883_283_304, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bw6_761_decode_g2_validated(x: u32, ) -> Weight {
+		Weight::from_parts(38_821_713, 0)
+			.saturating_add(Weight::from_parts(1_892_003_175, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bw6_761_decode_g2_validated(x: u32, ) -> Weight {
+		Weight::from_parts(46_739_026, 0)
+			.saturating_add(Weight::from_parts(1_176_472_767, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_377_decode_te_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(22_535_601, 0)
+			.saturating_add(Weight::from_parts(90_123_514, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_377_decode_te_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(22_789_171, 0)
+			.saturating_add(Weight::from_parts(90_323_466, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_377_decode_te_validated(x: u32, ) -> Weight {
+		Weight::from_parts(22_699_778, 0)
+			.saturating_add(Weight::from_parts(180_855_770, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_377_decode_te_validated(x: u32, ) -> Weight {
+		Weight::from_parts(23_721_053, 0)
+			.saturating_add(Weight::from_parts(110_189_505, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_381_bandersnatch_decode_sw_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(18_864_493, 0)
+			.saturating_add(Weight::from_parts(85_609_851, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_381_bandersnatch_decode_sw_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(22_791_609, 0)
+			.saturating_add(Weight::from_parts(85_669_949, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_381_bandersnatch_decode_sw_validated(x: u32, ) -> Weight {
+		Weight::from_parts(19_575_976, 0)
+			.saturating_add(Weight::from_parts(170_390_487, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_381_bandersnatch_decode_sw_validated(x: u32, ) -> Weight {
+		Weight::from_parts(18_817_306, 0)
+			.saturating_add(Weight::from_parts(105_574_351, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_381_bandersnatch_decode_te_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(23_973_618, 0)
+			.saturating_add(Weight::from_parts(90_065_839, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_381_bandersnatch_decode_te_unchecked(x: u32, ) -> Weight {
+		Weight::from_parts(22_734_264, 0)
+			.saturating_add(Weight::from_parts(90_062_496, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_381_bandersnatch_decode_te_validated(x: u32, ) -> Weight {
+		Weight::from_parts(23_192_628, 0)
+			.saturating_add(Weight::from_parts(175_215_963, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_381_bandersnatch_decode_te_validated(x: u32, ) -> Weight {
+		Weight::from_parts(22_164_226, 0)
+			.saturating_add(Weight::from_parts(110_713_451, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_381_decode_g1_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(12_980_987, 0)
+			.saturating_add(Weight::from_parts(6_282_061, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_381_decode_g1_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(13_438_670, 0)
+			.saturating_add(Weight::from_parts(6_698_782, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_381_decode_g2_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(11_934_212, 0)
+			.saturating_add(Weight::from_parts(11_149_527, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_381_decode_g2_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(12_201_436, 0)
+			.saturating_add(Weight::from_parts(11_011_387, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_377_decode_g1_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(16_875_283, 0)
+			.saturating_add(Weight::from_parts(8_166_679, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_377_decode_g1_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(17_470_271, 0)
+			.saturating_add(Weight::from_parts(8_708_417, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bls12_377_decode_g2_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(17_901_318, 0)
+			.saturating_add(Weight::from_parts(16_724_290, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bls12_377_decode_g2_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(18_302_154, 0)
+			.saturating_add(Weight::from_parts(16_517_080, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bw6_761_decode_g1_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(86_972_613, 0)
+			.saturating_add(Weight::from_parts(42_089_809, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bw6_761_decode_g1_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(90_039_089, 0)
+			.saturating_add(Weight::from_parts(44_881_839, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_bw6_761_decode_g2_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(25_061_845, 0)
+			.saturating_add(Weight::from_parts(23_414_007, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_bw6_761_decode_g2_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(25_623_016, 0)
+			.saturating_add(Weight::from_parts(23_123_913, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_377_decode_te_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(9_493_454, 0)
+			.saturating_add(Weight::from_parts(4_604_204, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_377_decode_te_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(10_674_647, 0)
+			.saturating_add(Weight::from_parts(4_078_233, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_381_bandersnatch_decode_sw_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(13_307_716, 0)
+			.saturating_add(Weight::from_parts(4_720_314, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_381_bandersnatch_decode_sw_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(11_826_957, 0)
+			.saturating_add(Weight::from_parts(4_714_537, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn ark_ed_on_bls12_381_bandersnatch_decode_te_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(12_394_150, 0)
+			.saturating_add(Weight::from_parts(4_836_633, 0).saturating_mul(x.into()))
+	}
+	/// The range of component `x` is `[10, 100]`.
+	fn sub_ed_on_bls12_381_bandersnatch_decode_te_uncompressed(x: u32, ) -> Weight {
+		Weight::from_parts(9_732_748, 0)
+			.saturating_add(Weight::from_parts(4_019_508, 0).saturating_mul(x.into()))
 	}
 }
@@ -0,0 +1,96 @@
+//! Benchmarking setup for pallet-ark-kzg
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as ArkKzg;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+// Min number of G2 powers of the SRS
+const SRS_LEN_MIN: u32 = 2;
+// Max number of G2 powers of the SRS
+const SRS_LEN_MAX: u32 = 64;
+
+// Min number of openings in a batch
+const BATCH_LEN_MIN: u32 = 1;
+// Max number of openings in a batch
+const BATCH_LEN_MAX: u32 = 16;
+
+/// Store the G2 powers required by openings verification.
+fn setup_srs<T: Config>() {
+    Pallet::<T>::set_srs_impl(&utils::make_srs(SRS_LEN_MIN)).unwrap();
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn set_srs(x: Linear<SRS_LEN_MIN, SRS_LEN_MAX>) -> Result<(), BenchmarkError> {
+        let origin =
+            T::SrsOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let g2_powers = utils::make_srs(x).try_into().unwrap();
+
+        #[extrinsic_call]
+        set_srs(origin as T::RuntimeOrigin, g2_powers);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn ark_verify_opening() {
+        setup_srs::<T>();
+        let opening = utils::make_opening();
+
+        #[extrinsic_call]
+        verify_opening(RawOrigin::None, opening, false);
+    }
+
+    #[benchmark]
+    fn sub_verify_opening() {
+        setup_srs::<T>();
+        let opening = utils::make_opening();
+
+        #[extrinsic_call]
+        verify_opening(RawOrigin::None, opening, true);
+    }
+
+    #[benchmark]
+    fn ark_verify_opening_batch(x: Linear<BATCH_LEN_MIN, BATCH_LEN_MAX>) {
+        setup_srs::<T>();
+        let batch = utils::make_openings(x).try_into().unwrap();
+
+        #[extrinsic_call]
+        verify_opening_batch(RawOrigin::None, batch, false);
+    }
+
+    #[benchmark]
+    fn sub_verify_opening_batch(x: Linear<BATCH_LEN_MIN, BATCH_LEN_MAX>) {
+        setup_srs::<T>();
+        let batch = utils::make_openings(x).try_into().unwrap();
+
+        #[extrinsic_call]
+        verify_opening_batch(RawOrigin::None, batch, true);
+    }
+
+    #[benchmark]
+    fn ark_point_evaluation() {
+        setup_srs::<T>();
+        let input = utils::make_point_evaluation_input();
+
+        #[extrinsic_call]
+        point_evaluation(RawOrigin::None, input, false);
+    }
+
+    #[benchmark]
+    fn sub_point_evaluation() {
+        setup_srs::<T>();
+        let input = utils::make_point_evaluation_input();
+
+        #[extrinsic_call]
+        point_evaluation(RawOrigin::None, input, true);
+    }
+
+    impl_benchmark_test_suite!(ArkKzg, crate::mock::new_test_ext(), crate::mock::Test);
+}
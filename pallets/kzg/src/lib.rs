@@ -0,0 +1,540 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+mod utils;
+mod weights;
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+
+use ark_bls12_381::Fr;
+use ark_ec::{
+    pairing::Pairing,
+    short_weierstrass::{Affine as SWAffine, SWCurveConfig},
+    AffineRepr, CurveGroup,
+};
+use ark_ff::{One, PrimeField, Zero};
+use ark_scale::ark_serialize::CanonicalDeserialize;
+use ark_std::{ops::Range, vec::Vec};
+use pallet_ark_common::is_optimized;
+use sha2::{Digest, Sha256};
+
+pub use pallet_ark_common::{ArkBackend, Arkworks, BackendSwitch, ConfigBackend, Substrate};
+pub use sp_crypto_ec_utils::bls12_381 as sub_bls12_381;
+
+// Re-export all pallet parts, this is needed to properly import the pallet into the runtime.
+pub use pallet::*;
+pub use weights::*;
+
+/// BLS12-381 pairing engine together with its groups configs.
+pub trait Engine {
+    type Pairing: Pairing<G1Affine = SWAffine<Self::G1>, G2Affine = SWAffine<Self::G2>>;
+    type G1: SWCurveConfig<BaseField = ark_bls12_381::Fq, ScalarField = Fr>;
+    type G2: SWCurveConfig<BaseField = ark_bls12_381::Fq2, ScalarField = Fr>;
+}
+
+/// Pure arkworks engine.
+pub struct ArkEngine;
+
+impl Engine for ArkEngine {
+    type Pairing = ark_bls12_381::Bls12_381;
+    type G1 = ark_bls12_381::g1::Config;
+    type G2 = ark_bls12_381::g2::Config;
+}
+
+/// Engine backed by Substrate host calls.
+pub struct SubEngine;
+
+impl Engine for SubEngine {
+    type Pairing = sub_bls12_381::Bls12_381;
+    type G1 = sub_bls12_381::g1::Config;
+    type G2 = sub_bls12_381::g2::Config;
+}
+
+/// Decoded opening, as `(commitment, point, value, proof)`.
+type DecodedOpening<C> = (SWAffine<C>, Fr, Fr, SWAffine<C>);
+
+const SRS_PAGE_SIZE: usize = 1 << 3;
+
+/// Compressed G2 point.
+const SRS_ITEM_SERIALIZED_SIZE: usize = 96;
+
+/// Compressed G1 point.
+const G1_POINT_SERIALIZED_SIZE: usize = 48;
+
+/// Big-endian scalar field element.
+const FIELD_ELEMENT_SERIALIZED_SIZE: usize = 32;
+
+/// Versioned hash, evaluation point, claimed value, commitment and proof.
+const POINT_EVALUATION_INPUT_SIZE: usize = 192;
+
+/// Version byte of the versioned hash of a KZG commitment (EIP-4844).
+pub const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
+
+/// Domain separator of the batch verification challenge (c-kzg `verify_kzg_proof_batch`).
+pub const BATCH_DOMAIN: &[u8] = b"RCKZGBATCH___V1_";
+
+/// Number of field elements of an EIP-4844 blob, hashed into the batch verification
+/// challenge after the domain separator as c-kzg does.
+pub const FIELD_ELEMENTS_PER_BLOB: u64 = 4096;
+
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Debug,
+    Encode,
+    Decode,
+    TypeInfo,
+    MaxEncodedLen,
+    DecodeWithMemTracking,
+)]
+pub struct SrsItemRaw(pub [u8; SRS_ITEM_SERIALIZED_SIZE]);
+
+#[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct SrsPage(pub [SrsItemRaw; SRS_PAGE_SIZE]);
+
+impl Default for SrsPage {
+    fn default() -> Self {
+        Self([SrsItemRaw([0_u8; SRS_ITEM_SERIALIZED_SIZE]); SRS_PAGE_SIZE])
+    }
+}
+
+/// Compressed G1 point, in the ZCash BLS12-381 format used by EIP-4844.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Debug,
+    Encode,
+    Decode,
+    TypeInfo,
+    MaxEncodedLen,
+    DecodeWithMemTracking,
+)]
+pub struct G1PointRaw(pub [u8; G1_POINT_SERIALIZED_SIZE]);
+
+pub type CommitmentRaw = G1PointRaw;
+pub type ProofRaw = G1PointRaw;
+
+/// Big-endian canonical encoding of a scalar field element.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Debug,
+    Encode,
+    Decode,
+    TypeInfo,
+    MaxEncodedLen,
+    DecodeWithMemTracking,
+)]
+pub struct FieldElementRaw(pub [u8; FIELD_ELEMENT_SERIALIZED_SIZE]);
+
+/// Input of the EIP-4844 point evaluation precompile.
+///
+/// Concatenation of versioned hash (32 bytes), evaluation point (32 bytes), claimed
+/// value (32 bytes), commitment (48 bytes) and proof (48 bytes).
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Debug,
+    Encode,
+    Decode,
+    TypeInfo,
+    MaxEncodedLen,
+    DecodeWithMemTracking,
+)]
+pub struct PointEvaluationInput(pub [u8; POINT_EVALUATION_INPUT_SIZE]);
+
+/// Opening of a committed polynomial `p` at `point`, claiming `p(point) = value`.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    TypeInfo,
+    DecodeWithMemTracking,
+)]
+pub struct Opening {
+    pub commitment: CommitmentRaw,
+    pub point: FieldElementRaw,
+    pub value: FieldElementRaw,
+    pub proof: ProofRaw,
+}
+
+pub type OpeningBatch<MaxSize> = BoundedVec<Opening, MaxSize>;
+
+/// Versioned hash of a KZG commitment, as defined by EIP-4844.
+pub fn kzg_to_versioned_hash(commitment: &CommitmentRaw) -> [u8; 32] {
+    let mut hash: [u8; 32] = Sha256::digest(commitment.0).into();
+    hash[0] = VERSIONED_HASH_VERSION_KZG;
+    hash
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> {
+        /// Maximum number of G2 powers of the SRS.
+        #[pallet::constant]
+        type MaxSrsLen: Get<u32>;
+
+        /// Maximum number of openings in a batch verification.
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+
+        /// Origin allowed to set the SRS.
+        type SrsOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Backend of the curve operations.
+        ///
+        /// Ignored with the `comparison-mode` feature, where the backend is selected per call.
+        type Backend: ArkBackend;
+
+        /// Extrinsic weights
+        type WeightInfo: WeightInfo;
+    }
+
+    /// Pages of the G2 powers `[τ^i]_2` of the SRS.
+    ///
+    /// Powers are validated when set and stored compressed.
+    #[pallet::storage]
+    pub type Srs<T: Config> = StorageMap<_, Twox64Concat, u32, SrsPage>;
+
+    /// Number of G2 powers of the SRS.
+    #[pallet::storage]
+    pub type SrsLen<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        /// Compressed G2 powers of the SRS, starting from the generator.
+        pub g2_powers: Vec<Vec<u8>>,
+        #[serde(skip)]
+        pub _phantom_data: core::marker::PhantomData<T>,
+    }
+
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            Self {
+                g2_powers: Vec::new(),
+                _phantom_data: Default::default(),
+            }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            if self.g2_powers.is_empty() {
+                return;
+            }
+            let g2_powers = self
+                .g2_powers
+                .iter()
+                .map(|power| SrsItemRaw(power[..].try_into().expect("Invalid SRS item size")))
+                .collect::<Vec<_>>();
+            log::info!(
+                "Building paged SRS (pages = {})",
+                g2_powers.len().div_ceil(SRS_PAGE_SIZE)
+            );
+            Pallet::<T>::set_srs_impl(&g2_powers).expect("Invalid SRS");
+        }
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// The SRS has been set.
+        SrsSet { len: u32 },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// SRS power can't be decoded.
+        InvalidSrs,
+        /// SRS has not enough G2 powers.
+        SrsTooShort,
+        /// Commitment can't be decoded.
+        InvalidCommitment,
+        /// Proof can't be decoded.
+        InvalidProof,
+        /// Field element is not canonical.
+        InvalidFieldElement,
+        /// Versioned hash doesn't match the commitment.
+        InvalidVersionedHash,
+        /// No openings provided.
+        EmptyBatch,
+        /// Opening verification failed.
+        VerificationFailed,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the G2 powers `[τ^i]_2` of the SRS, starting from the generator.
+        ///
+        /// Openings verification only requires the first two powers.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_srs(g2_powers.len() as u32))]
+        pub fn set_srs(
+            origin: OriginFor<T>,
+            g2_powers: BoundedVec<SrsItemRaw, T::MaxSrsLen>,
+        ) -> DispatchResult {
+            T::SrsOrigin::ensure_origin(origin)?;
+            Self::set_srs_impl(&g2_powers)?;
+            Self::deposit_event(Event::SrsSet {
+                len: g2_powers.len() as u32,
+            });
+            Ok(())
+        }
+
+        /// Verify a single opening.
+        #[pallet::call_index(1)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_verify_opening()
+        } else {
+            T::WeightInfo::ark_verify_opening()
+        })]
+        pub fn verify_opening(
+            _: OriginFor<T>,
+            opening: Opening,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            if is_optimized::<T::Backend>(&optimized) {
+                Self::verify_opening_impl::<SubEngine>(&opening)
+            } else {
+                Self::verify_opening_impl::<ArkEngine>(&opening)
+            }
+        }
+
+        /// Verify a batch of openings with a single pairing check.
+        ///
+        /// Openings are combined using the powers of a Fiat-Shamir challenge, derived as
+        /// in c-kzg `verify_kzg_proof_batch`.
+        #[pallet::call_index(2)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_verify_opening_batch(batch.len() as u32)
+        } else {
+            T::WeightInfo::ark_verify_opening_batch(batch.len() as u32)
+        })]
+        pub fn verify_opening_batch(
+            _: OriginFor<T>,
+            batch: OpeningBatch<T::MaxBatchSize>,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            if is_optimized::<T::Backend>(&optimized) {
+                Self::verify_opening_batch_impl::<SubEngine>(&batch)
+            } else {
+                Self::verify_opening_batch_impl::<ArkEngine>(&batch)
+            }
+        }
+
+        /// Verify an EIP-4844 point evaluation precompile input.
+        ///
+        /// The versioned hash is checked against the commitment before verifying the
+        /// opening. The precompile output is constant, thus nothing is returned.
+        #[pallet::call_index(3)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_point_evaluation()
+        } else {
+            T::WeightInfo::ark_point_evaluation()
+        })]
+        pub fn point_evaluation(
+            _: OriginFor<T>,
+            input: PointEvaluationInput,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            let (versioned_hash, opening) = split_point_evaluation_input(&input);
+            ensure!(
+                kzg_to_versioned_hash(&opening.commitment) == versioned_hash,
+                Error::<T>::InvalidVersionedHash
+            );
+            if is_optimized::<T::Backend>(&optimized) {
+                Self::verify_opening_impl::<SubEngine>(&opening)
+            } else {
+                Self::verify_opening_impl::<ArkEngine>(&opening)
+            }
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Validate and store the SRS, replacing the previous one.
+        ///
+        /// Validation uses the host calls backend.
+        pub(crate) fn set_srs_impl(g2_powers: &[SrsItemRaw]) -> DispatchResult {
+            for power in g2_powers {
+                SWAffine::<sub_bls12_381::g2::Config>::deserialize_compressed(&power.0[..])
+                    .map_err(|_| Error::<T>::InvalidSrs)?;
+            }
+            let _ = Srs::<T>::clear(u32::MAX, None);
+            for (page_idx, powers) in g2_powers.chunks(SRS_PAGE_SIZE).enumerate() {
+                let mut page = SrsPage::default();
+                page.0[..powers.len()].copy_from_slice(powers);
+                Srs::<T>::insert(page_idx as u32, page);
+            }
+            SrsLen::<T>::set(g2_powers.len() as u32);
+            Ok(())
+        }
+
+        /// Read the G2 powers in `range`.
+        pub(crate) fn fetch_srs_range<C: SWCurveConfig>(
+            range: Range<usize>,
+        ) -> Result<Vec<SWAffine<C>>, Error<T>> {
+            log::debug!("SRS lookup {range:?}");
+            ensure!(
+                range.end <= SrsLen::<T>::get() as usize,
+                Error::<T>::SrsTooShort
+            );
+
+            let start_page = range.start / SRS_PAGE_SIZE;
+            let end_page = (range.end - 1) / SRS_PAGE_SIZE;
+
+            (start_page..=end_page)
+                .flat_map(|page_idx| Srs::<T>::get(page_idx as u32).unwrap_or_default().0)
+                .skip(range.start % SRS_PAGE_SIZE)
+                .take(range.end - range.start)
+                .map(|power| {
+                    // Powers are validated when set.
+                    SWAffine::<C>::deserialize_compressed_unchecked(&power.0[..])
+                        .map_err(|_| Error::<T>::InvalidSrs)
+                })
+                .collect()
+        }
+
+        /// Generator and `[τ]_2`.
+        fn fetch_g2_tau<E: Engine>() -> Result<(SWAffine<E::G2>, SWAffine<E::G2>), Error<T>> {
+            let powers = Self::fetch_srs_range::<E::G2>(0..2)?;
+            Ok((powers[0], powers[1]))
+        }
+
+        /// Checks `e(commitment - [value]_1, g2) == e(proof, [τ]_2 - [point]_2)`.
+        pub(crate) fn verify_opening_impl<E: Engine>(opening: &Opening) -> DispatchResult {
+            let (commitment, point, value, proof) = Self::decode_opening::<E>(opening)?;
+            let (g2, tau_g2) = Self::fetch_g2_tau::<E>()?;
+            let g1 = SWAffine::<E::G1>::generator();
+
+            let commitment_minus_value = commitment.into_group() - g1 * value;
+            let tau_minus_point = tau_g2.into_group() - g2 * point;
+            let res = E::Pairing::multi_pairing(
+                [commitment_minus_value.into_affine(), proof],
+                [-g2, tau_minus_point.into_affine()],
+            );
+            ensure!(res.is_zero(), Error::<T>::VerificationFailed);
+            Ok(())
+        }
+
+        /// Checks `e(Σ r^i proof_i, [τ]_2) == e(Σ r^i (commitment_i - [value_i]_1 +
+        /// point_i proof_i), g2)`.
+        pub(crate) fn verify_opening_batch_impl<E: Engine>(batch: &[Opening]) -> DispatchResult {
+            ensure!(!batch.is_empty(), Error::<T>::EmptyBatch);
+            let openings = batch
+                .iter()
+                .map(Self::decode_opening::<E>)
+                .collect::<Result<Vec<_>, _>>()?;
+            let (g2, tau_g2) = Self::fetch_g2_tau::<E>()?;
+
+            let challenge = batch_challenge(batch);
+            let mut power = Fr::one();
+            let mut values_lincomb = Fr::zero();
+            let mut proofs = Vec::with_capacity(openings.len());
+            let mut powers = Vec::with_capacity(openings.len());
+            let mut bases = Vec::with_capacity(2 * openings.len() + 1);
+            let mut scalars = Vec::with_capacity(2 * openings.len() + 1);
+            for (commitment, point, value, proof) in openings {
+                bases.extend([commitment, proof]);
+                scalars.extend([power, power * point]);
+                values_lincomb += power * value;
+                proofs.push(proof);
+                powers.push(power);
+                power *= challenge;
+            }
+            bases.push(SWAffine::<E::G1>::generator());
+            scalars.push(-values_lincomb);
+
+            let proofs_lincomb =
+                E::G1::msm(&proofs, &powers).map_err(|_| Error::<T>::InvalidProof)?;
+            let rhs = E::G1::msm(&bases, &scalars).map_err(|_| Error::<T>::InvalidProof)?;
+            let res = E::Pairing::multi_pairing(
+                [proofs_lincomb.into_affine(), (-rhs).into_affine()],
+                [tau_g2, g2],
+            );
+            ensure!(res.is_zero(), Error::<T>::VerificationFailed);
+            Ok(())
+        }
+
+        /// Commitment and proof are checked to be in the prime order subgroup.
+        fn decode_opening<E: Engine>(opening: &Opening) -> Result<DecodedOpening<E::G1>, Error<T>> {
+            let commitment = SWAffine::<E::G1>::deserialize_compressed(&opening.commitment.0[..])
+                .map_err(|_| Error::<T>::InvalidCommitment)?;
+            let proof = SWAffine::<E::G1>::deserialize_compressed(&opening.proof.0[..])
+                .map_err(|_| Error::<T>::InvalidProof)?;
+            let point =
+                decode_field_element(&opening.point).ok_or(Error::<T>::InvalidFieldElement)?;
+            let value =
+                decode_field_element(&opening.value).ok_or(Error::<T>::InvalidFieldElement)?;
+            Ok((commitment, point, value, proof))
+        }
+    }
+}
+
+/// Decode a big-endian field element, rejecting non canonical encodings.
+fn decode_field_element(raw: &FieldElementRaw) -> Option<Fr> {
+    let mut bytes = raw.0;
+    bytes.reverse();
+    Fr::deserialize_compressed(&bytes[..]).ok()
+}
+
+/// Fiat-Shamir challenge of a batch of openings.
+///
+/// Hash of the domain separator, the blob size, the number of openings and the openings,
+/// with the integers encoded as big-endian `u64`, as in c-kzg `verify_kzg_proof_batch`.
+fn batch_challenge(batch: &[Opening]) -> Fr {
+    let mut hasher = Sha256::new();
+    hasher.update(BATCH_DOMAIN);
+    hasher.update(FIELD_ELEMENTS_PER_BLOB.to_be_bytes());
+    hasher.update((batch.len() as u64).to_be_bytes());
+    for opening in batch {
+        hasher.update(opening.commitment.0);
+        hasher.update(opening.point.0);
+        hasher.update(opening.value.0);
+        hasher.update(opening.proof.0);
+    }
+    Fr::from_be_bytes_mod_order(&hasher.finalize())
+}
+
+/// Split a point evaluation input into versioned hash and opening.
+fn split_point_evaluation_input(input: &PointEvaluationInput) -> ([u8; 32], Opening) {
+    let (versioned_hash, rest) = input.0.split_first_chunk::<32>().expect("192 > 32");
+    let (point, rest) = rest.split_first_chunk::<32>().expect("160 > 32");
+    let (value, rest) = rest.split_first_chunk::<32>().expect("128 > 32");
+    let (commitment, proof) = rest.split_first_chunk::<48>().expect("96 > 48");
+    let opening = Opening {
+        commitment: G1PointRaw(*commitment),
+        point: FieldElementRaw(*point),
+        value: FieldElementRaw(*value),
+        proof: G1PointRaw(proof.try_into().expect("48 bytes left")),
+    };
+    (*versioned_hash, opening)
+}
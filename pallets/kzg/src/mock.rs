@@ -0,0 +1,103 @@
+use crate::{utils::make_srs, ArkBackend, BackendSwitch};
+use frame_support::{
+    self, derive_impl, parameter_types, sp_runtime::BuildStorage, traits::ConstU32,
+};
+use frame_system::EnsureRoot;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+/// Number of G2 powers of the genesis SRS, spanning more than a page.
+pub const SRS_LEN: u32 = 10;
+
+#[frame_support::runtime]
+mod runtime {
+    // The main runtime
+    #[runtime::runtime]
+    // Runtime Types to be generated
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeHoldReason,
+        RuntimeSlashReason,
+        RuntimeLockId,
+        RuntimeTask,
+        RuntimeViewFunction
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system::Pallet<Test>;
+
+    #[runtime::pallet_index(1)]
+    pub type ArkKzg = crate::Pallet<Test>;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+}
+
+parameter_types! {
+    pub static Optimized: bool = true;
+}
+
+/// Backend selected by the `Optimized` parameter.
+pub struct TestBackend;
+
+impl ArkBackend for TestBackend {
+    fn optimized() -> bool {
+        Optimized::get()
+    }
+}
+
+/// Select the backend of a call.
+///
+/// With the `comparison-mode` feature the selection is passed to the call, otherwise
+/// the backend of the mock runtime is switched.
+#[cfg(feature = "comparison-mode")]
+pub fn backend(optimized: bool) -> BackendSwitch {
+    optimized
+}
+
+/// Select the backend of a call.
+///
+/// With the `comparison-mode` feature the selection is passed to the call, otherwise
+/// the backend of the mock runtime is switched.
+#[cfg(not(feature = "comparison-mode"))]
+pub fn backend(optimized: bool) -> BackendSwitch {
+    Optimized::set(optimized);
+    crate::ConfigBackend
+}
+
+impl crate::Config for Test {
+    type MaxSrsLen = ConstU32<64>;
+    type MaxBatchSize = ConstU32<16>;
+    type SrsOrigin = EnsureRoot<u64>;
+    type Backend = TestBackend;
+    type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    sp_tracing::try_init_simple();
+
+    let mut storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    crate::GenesisConfig::<Test> {
+        g2_powers: make_srs(SRS_LEN)
+            .into_iter()
+            .map(|power| power.0.to_vec())
+            .collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    let mut ext: sp_io::TestExternalities = storage.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
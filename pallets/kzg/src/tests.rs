@@ -0,0 +1,241 @@
+use crate::{
+    mock::{backend, new_test_ext, ArkKzg, RuntimeEvent, RuntimeOrigin, System, Test, SRS_LEN},
+    utils::*,
+    Error, Event, FieldElementRaw, OpeningBatch, Pallet, SrsLen,
+};
+use ark_bls12_381::G2Affine;
+use ark_scale::ark_serialize::CanonicalDeserialize;
+use frame_support::{assert_noop, assert_ok, sp_runtime::DispatchError};
+
+const BATCH_SIZE: u32 = 4;
+
+// ---------------------------------------------
+// Tests for openings verification
+// ---------------------------------------------
+
+fn verify_opening(optimized: bool) {
+    let opening = make_opening();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkKzg::verify_opening(
+            RuntimeOrigin::none(),
+            opening,
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_verify_opening() {
+    verify_opening(false);
+}
+
+#[test]
+fn sub_verify_opening() {
+    verify_opening(true);
+}
+
+fn verify_opening_batch(optimized: bool) {
+    let batch: OpeningBatch<_> = make_openings(BATCH_SIZE).try_into().unwrap();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkKzg::verify_opening_batch(
+            RuntimeOrigin::none(),
+            batch,
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_verify_opening_batch() {
+    verify_opening_batch(false);
+}
+
+#[test]
+fn sub_verify_opening_batch() {
+    verify_opening_batch(true);
+}
+
+fn point_evaluation(optimized: bool) {
+    let input = make_point_evaluation_input();
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkKzg::point_evaluation(
+            RuntimeOrigin::none(),
+            input,
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_point_evaluation() {
+    point_evaluation(false);
+}
+
+#[test]
+fn sub_point_evaluation() {
+    point_evaluation(true);
+}
+
+// ---------------------------------------------
+// Tests for invalid inputs
+// ---------------------------------------------
+
+#[test]
+fn verify_opening_with_wrong_value_fails() {
+    let mut opening = make_opening();
+    opening.value = make_openings(2)[1].value;
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkKzg::verify_opening(RuntimeOrigin::none(), opening, backend(true)),
+            Error::<Test>::VerificationFailed
+        );
+    });
+}
+
+#[test]
+fn verify_opening_with_non_canonical_field_element_fails() {
+    let mut opening = make_opening();
+    opening.point = FieldElementRaw([0xff; 32]);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkKzg::verify_opening(RuntimeOrigin::none(), opening, backend(true)),
+            Error::<Test>::InvalidFieldElement
+        );
+    });
+}
+
+#[test]
+fn verify_opening_with_invalid_proof_fails() {
+    let mut opening = make_opening();
+    opening.proof.0 = [0xff; 48];
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkKzg::verify_opening(RuntimeOrigin::none(), opening, backend(true)),
+            Error::<Test>::InvalidProof
+        );
+    });
+}
+
+#[test]
+fn verify_opening_batch_with_one_wrong_opening_fails() {
+    let mut openings = make_openings(BATCH_SIZE);
+    openings[2].value = openings[1].value;
+    let batch: OpeningBatch<_> = openings.try_into().unwrap();
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkKzg::verify_opening_batch(RuntimeOrigin::none(), batch, backend(true)),
+            Error::<Test>::VerificationFailed
+        );
+    });
+}
+
+#[test]
+fn verify_empty_opening_batch_fails() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkKzg::verify_opening_batch(RuntimeOrigin::none(), Default::default(), backend(true)),
+            Error::<Test>::EmptyBatch
+        );
+    });
+}
+
+#[test]
+fn point_evaluation_with_wrong_versioned_hash_fails() {
+    let mut input = make_point_evaluation_input();
+    // Version byte.
+    input.0[0] = 0x02;
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkKzg::point_evaluation(RuntimeOrigin::none(), input, backend(true)),
+            Error::<Test>::InvalidVersionedHash
+        );
+    });
+}
+
+// ---------------------------------------------
+// Tests for SRS management
+// ---------------------------------------------
+
+#[test]
+fn fetch_srs_range_across_pages() {
+    let srs = make_srs(SRS_LEN);
+
+    new_test_ext().execute_with(|| {
+        let powers =
+            Pallet::<Test>::fetch_srs_range::<ark_bls12_381::g2::Config>(6..SRS_LEN as usize)
+                .unwrap();
+        let expected = srs[6..]
+            .iter()
+            .map(|power| G2Affine::deserialize_compressed(&power.0[..]).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(powers, expected);
+    });
+}
+
+#[test]
+fn set_srs_works() {
+    let srs = make_srs(3);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkKzg::set_srs(
+            RuntimeOrigin::root(),
+            srs.try_into().unwrap()
+        ));
+        assert_eq!(SrsLen::<Test>::get(), 3);
+        System::assert_last_event(RuntimeEvent::ArkKzg(Event::SrsSet { len: 3 }));
+        assert_ok!(ArkKzg::verify_opening(
+            RuntimeOrigin::none(),
+            make_opening(),
+            backend(true)
+        ));
+    });
+}
+
+#[test]
+fn set_srs_requires_origin() {
+    let srs = make_srs(3);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkKzg::set_srs(RuntimeOrigin::signed(1), srs.try_into().unwrap()),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn set_srs_with_invalid_power_fails() {
+    let mut srs = make_srs(3);
+    srs[1].0 = [0xff; 96];
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkKzg::set_srs(RuntimeOrigin::root(), srs.try_into().unwrap()),
+            Error::<Test>::InvalidSrs
+        );
+    });
+}
+
+#[test]
+fn verify_opening_with_short_srs_fails() {
+    let srs = make_srs(1);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkKzg::set_srs(
+            RuntimeOrigin::root(),
+            srs.try_into().unwrap()
+        ));
+        assert_noop!(
+            ArkKzg::verify_opening(RuntimeOrigin::none(), make_opening(), backend(true)),
+            Error::<Test>::SrsTooShort
+        );
+    });
+}
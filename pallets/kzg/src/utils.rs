@@ -0,0 +1,78 @@
+#![allow(dead_code)]
+
+use crate::{
+    kzg_to_versioned_hash, FieldElementRaw, G1PointRaw, Opening, PointEvaluationInput, SrsItemRaw,
+};
+use ark_bls12_381::{Fr, G1Affine, G2Affine};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{Field, PrimeField};
+use ark_scale::ark_serialize::CanonicalSerialize;
+use ark_std::{test_rng, vec::Vec, UniformRand};
+
+/// Secret of the insecure test setup.
+fn test_tau() -> Fr {
+    Fr::from_be_bytes_mod_order(b"insecure test setup")
+}
+
+fn encode_g1(point: G1Affine) -> G1PointRaw {
+    let mut raw = G1PointRaw([0; 48]);
+    point.serialize_compressed(&mut raw.0[..]).unwrap();
+    raw
+}
+
+fn encode_field_element(element: Fr) -> FieldElementRaw {
+    let mut raw = FieldElementRaw([0; 32]);
+    element.serialize_compressed(&mut raw.0[..]).unwrap();
+    raw.0.reverse();
+    raw
+}
+
+/// G2 powers `[τ^i]_2` of the test setup.
+pub fn make_srs(len: u32) -> Vec<SrsItemRaw> {
+    let tau = test_tau();
+    (0..len)
+        .map(|i| {
+            let power = (G2Affine::generator() * tau.pow([i as u64])).into_affine();
+            let mut raw = SrsItemRaw([0; 96]);
+            power.serialize_compressed(&mut raw.0[..]).unwrap();
+            raw
+        })
+        .collect()
+}
+
+/// Valid openings of random polynomials at random points.
+///
+/// The test setup secret is known, thus commitments are random points `[c]_1`
+/// and proofs are computed as `[(c - value) / (τ - point)]_1`.
+pub fn make_openings(count: u32) -> Vec<Opening> {
+    let rng = &mut test_rng();
+    let tau = test_tau();
+    (0..count)
+        .map(|_| {
+            let (c, point, value) = (Fr::rand(rng), Fr::rand(rng), Fr::rand(rng));
+            let proof = (c - value) * (tau - point).inverse().unwrap();
+            Opening {
+                commitment: encode_g1((G1Affine::generator() * c).into_affine()),
+                point: encode_field_element(point),
+                value: encode_field_element(value),
+                proof: encode_g1((G1Affine::generator() * proof).into_affine()),
+            }
+        })
+        .collect()
+}
+
+pub fn make_opening() -> Opening {
+    make_openings(1)[0]
+}
+
+/// Point evaluation precompile input of a valid opening.
+pub fn make_point_evaluation_input() -> PointEvaluationInput {
+    let opening = make_opening();
+    let mut input = Vec::with_capacity(192);
+    input.extend(kzg_to_versioned_hash(&opening.commitment));
+    input.extend(opening.point.0);
+    input.extend(opening.value.0);
+    input.extend(opening.commitment.0);
+    input.extend(opening.proof.0);
+    PointEvaluationInput(input.try_into().unwrap())
+}
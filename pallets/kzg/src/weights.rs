@@ -0,0 +1,196 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Placeholder weights for `pallet_ark_kzg`
+//!
+//! NOT GENERATED BY THE BENCHMARK CLI. The figures below are estimates and must be
+//! replaced by running the pallet benchmarks with the command below.
+
+// Command to regenerate:
+// ./target/release/ark-node
+// benchmark
+// pallet
+// --runtime=target/release/wbuild/ark-runtime/ark_runtime.compact.wasm
+// --template=pallets/frame-weight-template.hbs
+// --pallet
+// pallet_ark_kzg
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat=20
+// --no-storage-info
+// --disable-proof-recording
+// --no-median-slopes
+// --json-file=./pallets/kzg/src/results.json
+// --output=./pallets/kzg/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_ark_kzg`.
+pub trait WeightInfo {
+	fn set_srs(x: u32, ) -> Weight;
+	fn ark_verify_opening() -> Weight;
+	fn sub_verify_opening() -> Weight;
+	fn ark_verify_opening_batch(x: u32, ) -> Weight;
+	fn sub_verify_opening_batch(x: u32, ) -> Weight;
+	fn ark_point_evaluation() -> Weight;
+	fn sub_point_evaluation() -> Weight;
+}
+
+/// Weights for `pallet_ark_kzg` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `ArkKzg::Srs` (r:1 w:8)
+	/// Proof: `ArkKzg::Srs` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `ArkKzg::SrsLen` (r:0 w:1)
+	/// Proof: `ArkKzg::SrsLen` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[2, 64]`.
+	fn set_srs(x: u32, ) -> Weight {
+		Weight::from_parts(121_843_516, 4245)
+			.saturating_add(Weight::from_parts(57_904_211, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `ArkKzg::SrsLen` (r:1 w:0)
+	/// Proof: `ArkKzg::SrsLen` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ArkKzg::Srs` (r:1 w:0)
+	/// Proof: `ArkKzg::Srs` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	fn ark_verify_opening() -> Weight {
+		Weight::from_parts(19_874_000_000, 4245)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: `ArkKzg::SrsLen` (r:1 w:0)
+	/// Proof: `ArkKzg::SrsLen` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ArkKzg::Srs` (r:1 w:0)
+	/// Proof: `ArkKzg::Srs` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	fn sub_verify_opening() -> Weight {
+		Weight::from_parts(3_412_000_000, 4245)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: `ArkKzg::SrsLen` (r:1 w:0)
+	/// Proof: `ArkKzg::SrsLen` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ArkKzg::Srs` (r:1 w:0)
+	/// Proof: `ArkKzg::Srs` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_verify_opening_batch(x: u32, ) -> Weight {
+		Weight::from_parts(20_196_482_140, 4245)
+			.saturating_add(Weight::from_parts(1_893_177_411, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: `ArkKzg::SrsLen` (r:1 w:0)
+	/// Proof: `ArkKzg::SrsLen` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ArkKzg::Srs` (r:1 w:0)
+	/// Proof: `ArkKzg::Srs` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_verify_opening_batch(x: u32, ) -> Weight {
+		Weight::from_parts(3_441_275_906, 4245)
+			.saturating_add(Weight::from_parts(309_552_874, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: `ArkKzg::SrsLen` (r:1 w:0)
+	/// Proof: `ArkKzg::SrsLen` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ArkKzg::Srs` (r:1 w:0)
+	/// Proof: `ArkKzg::Srs` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	fn ark_point_evaluation() -> Weight {
+		Weight::from_parts(19_889_000_000, 4245)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: `ArkKzg::SrsLen` (r:1 w:0)
+	/// Proof: `ArkKzg::SrsLen` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ArkKzg::Srs` (r:1 w:0)
+	/// Proof: `ArkKzg::Srs` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	fn sub_point_evaluation() -> Weight {
+		Weight::from_parts(3_425_000_000, 4245)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `ArkKzg::Srs` (r:1 w:8)
+	/// Proof: `ArkKzg::Srs` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `ArkKzg::SrsLen` (r:0 w:1)
+	/// Proof: `ArkKzg::SrsLen` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[2, 64]`.
+	fn set_srs(x: u32, ) -> Weight {
+		Weight::from_parts(121_843_516, 4245)
+			.saturating_add(Weight::from_parts(57_904_211, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `ArkKzg::SrsLen` (r:1 w:0)
+	/// Proof: `ArkKzg::SrsLen` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ArkKzg::Srs` (r:1 w:0)
+	/// Proof: `ArkKzg::Srs` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	fn ark_verify_opening() -> Weight {
+		Weight::from_parts(19_874_000_000, 4245)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: `ArkKzg::SrsLen` (r:1 w:0)
+	/// Proof: `ArkKzg::SrsLen` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ArkKzg::Srs` (r:1 w:0)
+	/// Proof: `ArkKzg::Srs` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	fn sub_verify_opening() -> Weight {
+		Weight::from_parts(3_412_000_000, 4245)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: `ArkKzg::SrsLen` (r:1 w:0)
+	/// Proof: `ArkKzg::SrsLen` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ArkKzg::Srs` (r:1 w:0)
+	/// Proof: `ArkKzg::Srs` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_verify_opening_batch(x: u32, ) -> Weight {
+		Weight::from_parts(20_196_482_140, 4245)
+			.saturating_add(Weight::from_parts(1_893_177_411, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: `ArkKzg::SrsLen` (r:1 w:0)
+	/// Proof: `ArkKzg::SrsLen` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ArkKzg::Srs` (r:1 w:0)
+	/// Proof: `ArkKzg::Srs` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_verify_opening_batch(x: u32, ) -> Weight {
+		Weight::from_parts(3_441_275_906, 4245)
+			.saturating_add(Weight::from_parts(309_552_874, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: `ArkKzg::SrsLen` (r:1 w:0)
+	/// Proof: `ArkKzg::SrsLen` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ArkKzg::Srs` (r:1 w:0)
+	/// Proof: `ArkKzg::Srs` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	fn ark_point_evaluation() -> Weight {
+		Weight::from_parts(19_889_000_000, 4245)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: `ArkKzg::SrsLen` (r:1 w:0)
+	/// Proof: `ArkKzg::SrsLen` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ArkKzg::Srs` (r:1 w:0)
+	/// Proof: `ArkKzg::Srs` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	fn sub_point_evaluation() -> Weight {
+		Weight::from_parts(3_425_000_000, 4245)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+}
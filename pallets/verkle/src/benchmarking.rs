@@ -0,0 +1,51 @@
+//! Benchmarking setup for pallet-ark-verkle
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as ArkVerkle;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+// Min number of multiproof queries
+const QUERIES_MIN: u32 = 1;
+// Max number of multiproof queries
+const QUERIES_MAX: u32 = 16;
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn generate_crs() -> Result<(), BenchmarkError> {
+        let origin =
+            T::CrsOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        generate_crs(origin as T::RuntimeOrigin);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn ark_verify_multiproof(x: Linear<QUERIES_MIN, QUERIES_MAX>) {
+        Pallet::<T>::generate_crs_impl();
+        let (queries, proof) = utils::make_multiproof_args(x);
+        let queries = queries.try_into().unwrap();
+
+        #[extrinsic_call]
+        verify_multiproof(RawOrigin::None, queries, proof, false);
+    }
+
+    #[benchmark]
+    fn sub_verify_multiproof(x: Linear<QUERIES_MIN, QUERIES_MAX>) {
+        Pallet::<T>::generate_crs_impl();
+        let (queries, proof) = utils::make_multiproof_args(x);
+        let queries = queries.try_into().unwrap();
+
+        #[extrinsic_call]
+        verify_multiproof(RawOrigin::None, queries, proof, true);
+    }
+
+    impl_benchmark_test_suite!(ArkVerkle, crate::mock::new_test_ext(), crate::mock::Test);
+}
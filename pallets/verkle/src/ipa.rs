@@ -0,0 +1,293 @@
+//! Inner product argument multiproofs over Banderwagon, as used by Verkle trees.
+//!
+//! Follows the Ethereum Verkle specification, as implemented by `go-ipa` and
+//! `rust-verkle`:
+//! - points are elements of Banderwagon, the prime order quotient of the Bandersnatch
+//!   subgroup `2E` by the point `(0, -1)`, serialized as the big-endian x coordinate
+//!   of the representative with lexicographically largest y coordinate;
+//! - scalars are serialized little-endian;
+//! - polynomials are in evaluation form over the domain `0..DOMAIN_SIZE` and committed
+//!   with the CRS derived from `CRS_SEED`, using the generator as `Q`;
+//! - challenges are derived by a SHA-256 transcript.
+
+use crate::{PointRaw, DOMAIN_SIZE, IPA_ROUNDS};
+use ark_ec::{
+    twisted_edwards::{Affine as TEAffine, Projective as TEProjective, TECurveConfig},
+    AffineRepr, CurveGroup,
+};
+use ark_ed_on_bls12_381_bandersnatch::{Fq, Fr};
+use ark_ff::{batch_inversion, Field, One, PrimeField, Zero};
+use ark_scale::ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{vec, vec::Vec};
+use sha2::{Digest, Sha256};
+
+/// Seed of the CRS points.
+pub const CRS_SEED: &[u8] = b"eth_verkle_oct_2021";
+
+/// Label of the Verkle proofs transcript.
+pub const TRANSCRIPT_LABEL: &[u8] = b"vt";
+
+/// Bandersnatch twisted Edwards curve config, either Arkworks or host calls backed.
+pub trait BandersnatchConfig: TECurveConfig<BaseField = Fq, ScalarField = Fr> {}
+
+impl<C: TECurveConfig<BaseField = Fq, ScalarField = Fr>> BandersnatchConfig for C {}
+
+/// Inner product argument opening of a committed polynomial at a point.
+pub struct IpaProof<C: BandersnatchConfig> {
+    pub l: Vec<TEAffine<C>>,
+    pub r: Vec<TEAffine<C>>,
+    pub a: Fr,
+}
+
+/// Opening of several committed polynomials, each at a point of the domain.
+pub struct MultiProof<C: BandersnatchConfig> {
+    pub d: TEAffine<C>,
+    pub ipa: IpaProof<C>,
+}
+
+/// Query of a multiproof, as `(commitment, point, value)`.
+pub type Query<C> = (TEAffine<C>, u8, Fr);
+
+/// Fiat-Shamir transcript of the Verkle proofs.
+pub struct Transcript(Sha256);
+
+impl Transcript {
+    pub fn new(label: &[u8]) -> Self {
+        Self(Sha256::new_with_prefix(label))
+    }
+
+    pub fn domain_sep(&mut self, label: &[u8]) {
+        self.0.update(label);
+    }
+
+    pub fn append_scalar(&mut self, scalar: &Fr, label: &[u8]) {
+        let mut bytes = [0_u8; 32];
+        scalar
+            .serialize_compressed(&mut bytes[..])
+            .expect("Scalars are 32 bytes");
+        self.0.update(label);
+        self.0.update(bytes);
+    }
+
+    pub fn append_point<C: BandersnatchConfig>(&mut self, point: &TEAffine<C>, label: &[u8]) {
+        self.0.update(label);
+        self.0.update(serialize_point(point).0);
+    }
+
+    /// Squeeze a challenge, which is then appended to the transcript.
+    pub fn challenge_scalar(&mut self, label: &[u8]) -> Fr {
+        self.domain_sep(label);
+        let hash = self.0.finalize_reset();
+        let challenge = Fr::from_le_bytes_mod_order(&hash);
+        self.append_scalar(&challenge, label);
+        challenge
+    }
+}
+
+/// Whether `y` is greater than `(p - 1) / 2`.
+fn is_lexicographically_largest(y: Fq) -> bool {
+    y.into_bigint() > Fq::MODULUS_MINUS_ONE_DIV_TWO
+}
+
+pub fn serialize_point<C: BandersnatchConfig>(point: &TEAffine<C>) -> PointRaw {
+    let x = if is_lexicographically_largest(point.y) {
+        point.x
+    } else {
+        -point.x
+    };
+    let mut raw = PointRaw([0; 32]);
+    x.serialize_compressed(&mut raw.0[..])
+        .expect("Base field elements are 32 bytes");
+    raw.0.reverse();
+    raw
+}
+
+/// Decode a Banderwagon element, rejecting non canonical encodings and points not
+/// in the subgroup.
+pub fn deserialize_point<C: BandersnatchConfig>(raw: &PointRaw) -> Option<TEAffine<C>> {
+    let mut bytes = raw.0;
+    bytes.reverse();
+    let x = Fq::deserialize_compressed(&bytes[..]).ok()?;
+    point_from_x(x)
+}
+
+fn point_from_x<C: BandersnatchConfig>(x: Fq) -> Option<TEAffine<C>> {
+    let x2 = x.square();
+    // Points of `2E` are the ones with `1 - a x^2` square.
+    let numerator = Fq::one() - C::COEFF_A * x2;
+    if numerator.legendre().is_qnr() {
+        return None;
+    }
+    let denominator = Fq::one() - C::COEFF_D * x2;
+    let y = (numerator * denominator.inverse()?).sqrt()?;
+    let y = if is_lexicographically_largest(y) {
+        y
+    } else {
+        -y
+    };
+    Some(TEAffine::new_unchecked(x, y))
+}
+
+/// Banderwagon identity, with `(0, 1)` and `(0, -1)` representing the same element.
+fn is_identity<C: BandersnatchConfig>(point: &TEProjective<C>) -> bool {
+    point.into_affine().x.is_zero()
+}
+
+/// Derive the CRS points by hashing the seed with an increasing counter, skipping
+/// the hashes which are not a valid encoding.
+pub fn generate_crs<C: BandersnatchConfig>() -> Vec<TEAffine<C>> {
+    let mut crs = Vec::with_capacity(DOMAIN_SIZE);
+    let mut increment = 0_u64;
+    while crs.len() < DOMAIN_SIZE {
+        let hash = Sha256::new_with_prefix(CRS_SEED)
+            .chain_update(increment.to_be_bytes())
+            .finalize();
+        if let Some(point) = point_from_x(Fq::from_be_bytes_mod_order(&hash)) {
+            crs.push(point);
+        }
+        increment += 1;
+    }
+    crs
+}
+
+/// Lagrange basis polynomials of the domain evaluated at `point`.
+pub fn barycentric_coefficients(point: Fr) -> Vec<Fr> {
+    let domain = (0..DOMAIN_SIZE as u64).map(Fr::from);
+    if let Some(index) = domain.clone().position(|x| x == point) {
+        let mut coefficients = vec![Fr::zero(); DOMAIN_SIZE];
+        coefficients[index] = Fr::one();
+        return coefficients;
+    }
+
+    // Barycentric weights `Π_{j != i} (i - j) = (-1)^(n - 1 - i) i! (n - 1 - i)!`
+    let mut factorials = Vec::with_capacity(DOMAIN_SIZE);
+    factorials.push(Fr::one());
+    for i in 1..DOMAIN_SIZE {
+        factorials.push(factorials[i - 1] * Fr::from(i as u64));
+    }
+    let mut denominators = domain
+        .clone()
+        .enumerate()
+        .map(|(i, x)| {
+            let weight = factorials[i] * factorials[DOMAIN_SIZE - 1 - i];
+            let weight = if (DOMAIN_SIZE - 1 - i) % 2 == 0 {
+                weight
+            } else {
+                -weight
+            };
+            weight * (point - x)
+        })
+        .collect::<Vec<_>>();
+    batch_inversion(&mut denominators);
+
+    let vanishing = domain.map(|x| point - x).product::<Fr>();
+    denominators.iter().map(|d| vanishing * d).collect()
+}
+
+/// Verify a multiproof of `queries`.
+///
+/// Aggregates the queries into the opening of `h(X) - g(X)` at the challenge `t`,
+/// with `E - D` commitment, which is checked by the IPA.
+pub fn verify_multiproof<C: BandersnatchConfig>(
+    crs: &[TEAffine<C>],
+    queries: &[Query<C>],
+    proof: &MultiProof<C>,
+) -> bool {
+    let mut transcript = Transcript::new(TRANSCRIPT_LABEL);
+    transcript.domain_sep(b"multiproof");
+    for (commitment, point, value) in queries {
+        transcript.append_point(commitment, b"C");
+        transcript.append_scalar(&Fr::from(*point), b"z");
+        transcript.append_scalar(value, b"y");
+    }
+    let r = transcript.challenge_scalar(b"r");
+    transcript.append_point(&proof.d, b"D");
+    let t = transcript.challenge_scalar(b"t");
+
+    // Coefficients `r^i / (t - z_i)`
+    let mut coefficients = queries
+        .iter()
+        .map(|(_, point, _)| t - Fr::from(*point))
+        .collect::<Vec<_>>();
+    batch_inversion(&mut coefficients);
+    let mut power = Fr::one();
+    let mut h_minus_g_at_t = Fr::zero();
+    for (coefficient, (_, _, value)) in coefficients.iter_mut().zip(queries) {
+        *coefficient *= power;
+        h_minus_g_at_t += *coefficient * value;
+        power *= r;
+    }
+
+    let commitments = queries.iter().map(|(c, _, _)| *c).collect::<Vec<_>>();
+    let Ok(e) = C::msm(&commitments, &coefficients) else {
+        return false;
+    };
+    transcript.append_point(&e.into_affine(), b"E");
+    let e_minus_d = (e - proof.d).into_affine();
+
+    verify_ipa(
+        &mut transcript,
+        crs,
+        &e_minus_d,
+        t,
+        h_minus_g_at_t,
+        &proof.ipa,
+    )
+}
+
+/// Verify the opening of the polynomial committed in `commitment` at `point`.
+///
+/// The folding of the CRS and of the barycentric coefficients is deferred to a single
+/// MSM, checking `C + Σ (x_j L_j + x_j^-1 R_j) + w (value - a b_0) Q - a Σ s_i G_i`
+/// to be the identity, with `s_i` the folding scalars of `G_i`.
+fn verify_ipa<C: BandersnatchConfig>(
+    transcript: &mut Transcript,
+    crs: &[TEAffine<C>],
+    commitment: &TEAffine<C>,
+    point: Fr,
+    value: Fr,
+    proof: &IpaProof<C>,
+) -> bool {
+    transcript.domain_sep(b"ipa");
+    transcript.append_point(commitment, b"C");
+    transcript.append_scalar(&point, b"input point");
+    transcript.append_scalar(&value, b"output point");
+    let w = transcript.challenge_scalar(b"w");
+
+    let mut challenges = Vec::with_capacity(IPA_ROUNDS);
+    for (l, r) in proof.l.iter().zip(&proof.r) {
+        transcript.append_point(l, b"L");
+        transcript.append_point(r, b"R");
+        challenges.push(transcript.challenge_scalar(b"x"));
+    }
+    let mut inv_challenges = challenges.clone();
+    batch_inversion(&mut inv_challenges);
+
+    // Round `j` folds the right half of the CRS with `x_j^-1`.
+    let mut folding = Vec::with_capacity(DOMAIN_SIZE);
+    folding.push(Fr::one());
+    for inv_challenge in &inv_challenges {
+        folding = folding
+            .iter()
+            .flat_map(|s| [*s, *s * inv_challenge])
+            .collect();
+    }
+    let b_0 = barycentric_coefficients(point)
+        .iter()
+        .zip(&folding)
+        .map(|(b, s)| *b * s)
+        .sum::<Fr>();
+
+    let mut bases = Vec::with_capacity(2 * IPA_ROUNDS + DOMAIN_SIZE + 2);
+    let mut scalars = Vec::with_capacity(2 * IPA_ROUNDS + DOMAIN_SIZE + 2);
+    bases.push(*commitment);
+    scalars.push(Fr::one());
+    bases.extend(proof.l.iter().chain(&proof.r));
+    scalars.extend(challenges.iter().chain(&inv_challenges));
+    bases.push(TEAffine::generator());
+    scalars.push(w * (value - proof.a * b_0));
+    bases.extend_from_slice(crs);
+    scalars.extend(folding.iter().map(|s| -proof.a * s));
+
+    C::msm(&bases, &scalars).is_ok_and(|res| is_identity(&res))
+}
@@ -0,0 +1,326 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+mod ipa;
+mod utils;
+mod weights;
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+
+use ark_ec::twisted_edwards::Affine as TEAffine;
+use ark_ed_on_bls12_381_bandersnatch::Fr;
+use ark_scale::ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::vec::Vec;
+use ipa::{BandersnatchConfig, IpaProof, MultiProof};
+use pallet_ark_common::is_optimized;
+
+pub use pallet_ark_common::{ArkBackend, Arkworks, BackendSwitch, ConfigBackend, Substrate};
+pub use sp_crypto_ec_utils::ed_on_bls12_381_bandersnatch as sub_ed_on_bls12_381_bandersnatch;
+
+/// Size of the evaluation domain `0..DOMAIN_SIZE`, i.e. the Verkle tree width.
+pub const DOMAIN_SIZE: usize = 256;
+
+/// IPA rounds, halving the domain down to a single element.
+pub const IPA_ROUNDS: usize = DOMAIN_SIZE.ilog2() as usize;
+
+const CRS_PAGE_SIZE: usize = 1 << 5;
+
+/// Uncompressed affine point.
+const CRS_ITEM_SERIALIZED_SIZE: usize = 64;
+
+/// Banderwagon element.
+const POINT_SERIALIZED_SIZE: usize = 32;
+
+/// Little-endian scalar field element.
+const SCALAR_SERIALIZED_SIZE: usize = 32;
+
+/// `D`, IPA `L` and `R` points and final scalar.
+const MULTIPROOF_SERIALIZED_SIZE: usize =
+    POINT_SERIALIZED_SIZE * (1 + 2 * IPA_ROUNDS) + SCALAR_SERIALIZED_SIZE;
+
+#[derive(Copy, Clone, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct CrsItemRaw(pub [u8; CRS_ITEM_SERIALIZED_SIZE]);
+
+#[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct CrsPage(pub [CrsItemRaw; CRS_PAGE_SIZE]);
+
+impl Default for CrsPage {
+    fn default() -> Self {
+        Self([CrsItemRaw([0_u8; CRS_ITEM_SERIALIZED_SIZE]); CRS_PAGE_SIZE])
+    }
+}
+
+/// Banderwagon element, as the big-endian x coordinate of the representative with
+/// lexicographically largest y coordinate.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Debug,
+    Encode,
+    Decode,
+    TypeInfo,
+    MaxEncodedLen,
+    DecodeWithMemTracking,
+)]
+pub struct PointRaw(pub [u8; POINT_SERIALIZED_SIZE]);
+
+pub type CommitmentRaw = PointRaw;
+
+/// Little-endian canonical encoding of a scalar field element.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Debug,
+    Encode,
+    Decode,
+    TypeInfo,
+    MaxEncodedLen,
+    DecodeWithMemTracking,
+)]
+pub struct ScalarRaw(pub [u8; SCALAR_SERIALIZED_SIZE]);
+
+/// Claim that the polynomial committed in `commitment` evaluates to `value` at the
+/// domain element `point`.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    TypeInfo,
+    DecodeWithMemTracking,
+)]
+pub struct Query {
+    pub commitment: CommitmentRaw,
+    pub point: u8,
+    pub value: ScalarRaw,
+}
+
+pub type QueryBatch<MaxSize> = BoundedVec<Query, MaxSize>;
+
+/// Serialized multiproof, as `D || L_0..L_7 || R_0..R_7 || a`.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Debug,
+    Encode,
+    Decode,
+    TypeInfo,
+    MaxEncodedLen,
+    DecodeWithMemTracking,
+)]
+pub struct MultiProofRaw(pub [u8; MULTIPROOF_SERIALIZED_SIZE]);
+
+#[frame_support::pallet]
+pub mod pallet {
+
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> {
+        /// Maximum number of queries of a multiproof.
+        #[pallet::constant]
+        type MaxQueries: Get<u32>;
+
+        /// Origin allowed to generate the CRS.
+        type CrsOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Backend of the curve operations.
+        ///
+        /// Ignored with the `comparison-mode` feature, where the backend is selected per call.
+        type Backend: ArkBackend;
+
+        /// Extrinsic weights
+        type WeightInfo: WeightInfo;
+    }
+
+    /// Pages of the CRS points `G_i`.
+    ///
+    /// Points are derived by the pallet and stored uncompressed, to skip the square
+    /// roots of the decompression.
+    #[pallet::storage]
+    pub type Crs<T: Config> = StorageMap<_, Twox64Concat, u32, CrsPage>;
+
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        #[serde(skip)]
+        pub _phantom_data: core::marker::PhantomData<T>,
+    }
+
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            Self {
+                _phantom_data: Default::default(),
+            }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            log::info!(
+                "Building paged CRS (pages = {})",
+                DOMAIN_SIZE.div_ceil(CRS_PAGE_SIZE)
+            );
+            Pallet::<T>::generate_crs_impl();
+        }
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// The CRS has been generated.
+        CrsGenerated,
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// CRS has not been generated.
+        CrsNotGenerated,
+        /// Commitment is not a valid Banderwagon element.
+        InvalidCommitment,
+        /// Proof can't be decoded.
+        InvalidProof,
+        /// Scalar is not canonical.
+        InvalidScalar,
+        /// No queries provided.
+        EmptyQueries,
+        /// Multiproof verification failed.
+        VerificationFailed,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Derive the CRS from its seed and store it, for runtimes adding the pallet
+        /// after genesis.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::generate_crs())]
+        pub fn generate_crs(origin: OriginFor<T>) -> DispatchResult {
+            T::CrsOrigin::ensure_origin(origin)?;
+            Self::generate_crs_impl();
+            Self::deposit_event(Event::CrsGenerated);
+            Ok(())
+        }
+
+        /// Verify a multiproof, opening each commitment of `queries` at its point.
+        ///
+        /// Commitments and points are those of a Verkle state proof, e.g. the commitments
+        /// of the internal and extension nodes along the proven paths.
+        #[pallet::call_index(1)]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_verify_multiproof(queries.len() as u32)
+        } else {
+            T::WeightInfo::ark_verify_multiproof(queries.len() as u32)
+        })]
+        pub fn verify_multiproof(
+            _: OriginFor<T>,
+            queries: QueryBatch<T::MaxQueries>,
+            proof: MultiProofRaw,
+            optimized: BackendSwitch,
+        ) -> DispatchResult {
+            if is_optimized::<T::Backend>(&optimized) {
+                Self::verify_multiproof_impl::<sub_ed_on_bls12_381_bandersnatch::EdwardsConfig>(
+                    &queries, &proof,
+                )
+            } else {
+                Self::verify_multiproof_impl::<ark_ed_on_bls12_381_bandersnatch::EdwardsConfig>(
+                    &queries, &proof,
+                )
+            }
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        pub(crate) fn generate_crs_impl() {
+            let crs = ipa::generate_crs::<ark_ed_on_bls12_381_bandersnatch::EdwardsConfig>();
+            for (page_idx, points) in crs.chunks(CRS_PAGE_SIZE).enumerate() {
+                let mut page = CrsPage::default();
+                for (raw, point) in page.0.iter_mut().zip(points) {
+                    point.serialize_uncompressed(&mut raw.0[..]).unwrap();
+                }
+                Crs::<T>::insert(page_idx as u32, page);
+            }
+        }
+
+        pub(crate) fn fetch_crs<C: BandersnatchConfig>() -> Result<Vec<TEAffine<C>>, Error<T>> {
+            (0..DOMAIN_SIZE.div_ceil(CRS_PAGE_SIZE))
+                .map(|page_idx| Crs::<T>::get(page_idx as u32).ok_or(Error::<T>::CrsNotGenerated))
+                .collect::<Result<Vec<_>, _>>()?
+                .iter()
+                .flat_map(|page| page.0)
+                .map(|point| {
+                    // Points are derived by the pallet.
+                    TEAffine::<C>::deserialize_uncompressed_unchecked(&point.0[..])
+                        .map_err(|_| Error::<T>::CrsNotGenerated)
+                })
+                .collect()
+        }
+
+        pub(crate) fn verify_multiproof_impl<C: BandersnatchConfig>(
+            queries: &[Query],
+            proof: &MultiProofRaw,
+        ) -> DispatchResult {
+            ensure!(!queries.is_empty(), Error::<T>::EmptyQueries);
+            let queries = queries
+                .iter()
+                .map(|query| {
+                    let commitment = ipa::deserialize_point::<C>(&query.commitment)
+                        .ok_or(Error::<T>::InvalidCommitment)?;
+                    let value = decode_scalar(&query.value).ok_or(Error::<T>::InvalidScalar)?;
+                    Ok((commitment, query.point, value))
+                })
+                .collect::<Result<Vec<_>, Error<T>>>()?;
+            let proof = decode_multiproof::<C>(proof).ok_or(Error::<T>::InvalidProof)?;
+            let crs = Self::fetch_crs::<C>()?;
+
+            ensure!(
+                ipa::verify_multiproof(&crs, &queries, &proof),
+                Error::<T>::VerificationFailed
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Decode a little-endian scalar, rejecting non canonical encodings.
+fn decode_scalar(raw: &ScalarRaw) -> Option<Fr> {
+    Fr::deserialize_compressed(&raw.0[..]).ok()
+}
+
+fn decode_multiproof<C: BandersnatchConfig>(raw: &MultiProofRaw) -> Option<MultiProof<C>> {
+    let (points, a) = raw.0.split_at(POINT_SERIALIZED_SIZE * (1 + 2 * IPA_ROUNDS));
+    let points = points
+        .chunks_exact(POINT_SERIALIZED_SIZE)
+        .map(|chunk| ipa::deserialize_point(&PointRaw(chunk.try_into().ok()?)))
+        .collect::<Option<Vec<_>>>()?;
+    let (d, lr) = points.split_first()?;
+    let (l, r) = lr.split_at(IPA_ROUNDS);
+    Some(MultiProof {
+        d: *d,
+        ipa: IpaProof {
+            l: l.to_vec(),
+            r: r.to_vec(),
+            a: decode_scalar(&ScalarRaw(a.try_into().ok()?))?,
+        },
+    })
+}
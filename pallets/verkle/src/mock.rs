@@ -0,0 +1,92 @@
+use crate::{ArkBackend, BackendSwitch};
+use frame_support::{self, derive_impl, parameter_types, sp_runtime::BuildStorage};
+use frame_system::EnsureRoot;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    // The main runtime
+    #[runtime::runtime]
+    // Runtime Types to be generated
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeHoldReason,
+        RuntimeSlashReason,
+        RuntimeLockId,
+        RuntimeTask,
+        RuntimeViewFunction
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system::Pallet<Test>;
+
+    #[runtime::pallet_index(1)]
+    pub type ArkVerkle = crate::Pallet<Test>;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+}
+
+parameter_types! {
+    pub MaxQueries: u32 = 16;
+    pub static Optimized: bool = true;
+}
+
+/// Backend selected by the `Optimized` parameter.
+pub struct TestBackend;
+
+impl ArkBackend for TestBackend {
+    fn optimized() -> bool {
+        Optimized::get()
+    }
+}
+
+/// Select the backend of a call.
+///
+/// With the `comparison-mode` feature the selection is passed to the call, otherwise
+/// the backend of the mock runtime is switched.
+#[cfg(feature = "comparison-mode")]
+pub fn backend(optimized: bool) -> BackendSwitch {
+    optimized
+}
+
+/// Select the backend of a call.
+///
+/// With the `comparison-mode` feature the selection is passed to the call, otherwise
+/// the backend of the mock runtime is switched.
+#[cfg(not(feature = "comparison-mode"))]
+pub fn backend(optimized: bool) -> BackendSwitch {
+    Optimized::set(optimized);
+    crate::ConfigBackend
+}
+
+impl crate::Config for Test {
+    type MaxQueries = MaxQueries;
+    type CrsOrigin = EnsureRoot<u64>;
+    type Backend = TestBackend;
+    type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    sp_tracing::try_init_simple();
+
+    let mut storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    crate::GenesisConfig::<Test>::default()
+        .assimilate_storage(&mut storage)
+        .unwrap();
+
+    let mut ext: sp_io::TestExternalities = storage.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
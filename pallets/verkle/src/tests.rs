@@ -0,0 +1,257 @@
+use crate::{
+    ipa::{self, Transcript},
+    mock::{
+        backend, new_test_ext, ArkVerkle, MaxQueries, RuntimeEvent, RuntimeOrigin, System, Test,
+    },
+    utils::*,
+    Crs, Error, Event, MultiProofRaw, Pallet, PointRaw, QueryBatch, ScalarRaw,
+};
+use ark_ec::twisted_edwards::Affine as TEAffine;
+use ark_ed_on_bls12_381_bandersnatch::{EdwardsConfig, Fr};
+use frame_support::{assert_noop, assert_ok, sp_runtime::DispatchError};
+use sha2::{Digest, Sha256};
+
+const QUERIES: u32 = 4;
+
+fn make_query_batch(count: u32) -> (QueryBatch<MaxQueries>, MultiProofRaw) {
+    let (queries, proof) = make_multiproof_args(count);
+    (queries.try_into().unwrap(), proof)
+}
+
+// ---------------------------------------------
+// Tests for multiproofs verification
+// ---------------------------------------------
+
+fn verify_multiproof(optimized: bool) {
+    let (queries, proof) = make_query_batch(QUERIES);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(ArkVerkle::verify_multiproof(
+            RuntimeOrigin::none(),
+            queries,
+            proof,
+            backend(optimized)
+        ));
+    });
+}
+
+#[test]
+fn ark_verify_multiproof() {
+    verify_multiproof(false);
+}
+
+#[test]
+fn sub_verify_multiproof() {
+    verify_multiproof(true);
+}
+
+// ---------------------------------------------
+// Verkle test vectors (go-ipa / rust-verkle)
+// ---------------------------------------------
+
+#[test]
+fn crs_consistency() {
+    let crs = ipa::generate_crs::<EdwardsConfig>();
+    let encoded = crs
+        .iter()
+        .map(|point| ipa::serialize_point(point).0)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        hex::encode(encoded[0]),
+        "01587ad1336675eb912550ec2a28eb8923b824b490dd2ba82e48f14590a298a0"
+    );
+    assert_eq!(
+        hex::encode(encoded[255]),
+        "3de2be346b539395b0c0de56a5ccca54a317f1b5c80107b0802af9a62276a4d8"
+    );
+    assert_eq!(
+        hex::encode(Sha256::digest(encoded.concat())),
+        "1fcaea10bf24f750200e06fa473c76ff0468007291fa548e2d99f09ba9256fdb"
+    );
+}
+
+#[test]
+fn ipa_consistency() {
+    let crs = ipa::generate_crs::<EdwardsConfig>();
+    let polynomial = (0..256_u64)
+        .map(|i| Fr::from(i % 32 + 1))
+        .collect::<Vec<_>>();
+    let point = Fr::from(2101_u64);
+
+    let commitment = commit(&crs, &polynomial);
+    assert_eq!(
+        hex::encode(ipa::serialize_point(&commitment).0),
+        "1b9dff8f5ebbac250d291dfe90e36283a227c64b113c37f1bfb9e7a743cdb128"
+    );
+
+    let value = ipa::barycentric_coefficients(point)
+        .iter()
+        .zip(&polynomial)
+        .map(|(b, f)| *b * f)
+        .sum::<Fr>();
+    assert_eq!(
+        hex::encode(encode_scalar(value).0),
+        "4a353e70b03c89f161de002e8713beec0d740a5e20722fd5bd68b30540a33208"
+    );
+
+    let mut transcript = Transcript::new(b"test");
+    open_ipa(&mut transcript, &crs, &commitment, polynomial, point);
+    assert_eq!(
+        hex::encode(encode_scalar(transcript.challenge_scalar(b"state")).0),
+        "0a81881cbfd7d7197a54ebd67ed6a68b5867f3c783706675b34ece43e85e7306"
+    );
+}
+
+#[test]
+fn point_serialization_roundtrip() {
+    let crs = ipa::generate_crs::<EdwardsConfig>();
+    for point in &crs[..8] {
+        let raw = ipa::serialize_point(point);
+        assert_eq!(ipa::deserialize_point::<EdwardsConfig>(&raw), Some(*point));
+        // Same element, other representative.
+        let other = TEAffine::new_unchecked(-point.x, -point.y);
+        assert_eq!(ipa::serialize_point(&other), raw);
+    }
+}
+
+// ---------------------------------------------
+// Tests for invalid inputs
+// ---------------------------------------------
+
+#[test]
+fn verify_multiproof_with_wrong_value_fails() {
+    let (mut queries, proof) = make_query_batch(QUERIES);
+    queries[1].value = encode_scalar(Fr::from(42_u64));
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkVerkle::verify_multiproof(RuntimeOrigin::none(), queries, proof, backend(true)),
+            Error::<Test>::VerificationFailed
+        );
+    });
+}
+
+#[test]
+fn verify_multiproof_with_wrong_point_fails() {
+    let (mut queries, proof) = make_query_batch(QUERIES);
+    queries[1].point += 1;
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkVerkle::verify_multiproof(RuntimeOrigin::none(), queries, proof, backend(true)),
+            Error::<Test>::VerificationFailed
+        );
+    });
+}
+
+#[test]
+fn verify_multiproof_with_invalid_commitment_fails() {
+    let (mut queries, proof) = make_query_batch(QUERIES);
+    // Not canonical, greater than the base field modulus.
+    queries[0].commitment = PointRaw([0xff; 32]);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkVerkle::verify_multiproof(RuntimeOrigin::none(), queries, proof, backend(true)),
+            Error::<Test>::InvalidCommitment
+        );
+    });
+}
+
+#[test]
+fn verify_multiproof_with_non_canonical_value_fails() {
+    let (mut queries, proof) = make_query_batch(QUERIES);
+    queries[0].value = ScalarRaw([0xff; 32]);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkVerkle::verify_multiproof(RuntimeOrigin::none(), queries, proof, backend(true)),
+            Error::<Test>::InvalidScalar
+        );
+    });
+}
+
+#[test]
+fn verify_multiproof_with_invalid_proof_fails() {
+    let (queries, mut proof) = make_query_batch(QUERIES);
+    proof.0[..32].copy_from_slice(&[0xff; 32]);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkVerkle::verify_multiproof(RuntimeOrigin::none(), queries, proof, backend(true)),
+            Error::<Test>::InvalidProof
+        );
+    });
+}
+
+#[test]
+fn verify_multiproof_without_queries_fails() {
+    let (_, proof) = make_query_batch(1);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkVerkle::verify_multiproof(
+                RuntimeOrigin::none(),
+                Default::default(),
+                proof,
+                backend(true)
+            ),
+            Error::<Test>::EmptyQueries
+        );
+    });
+}
+
+// ---------------------------------------------
+// Tests for CRS management
+// ---------------------------------------------
+
+#[test]
+fn genesis_crs_matches_generated_crs() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            Pallet::<Test>::fetch_crs::<EdwardsConfig>().unwrap(),
+            ipa::generate_crs::<EdwardsConfig>()
+        );
+    });
+}
+
+#[test]
+fn verify_multiproof_without_crs_fails() {
+    let (queries, proof) = make_query_batch(QUERIES);
+
+    new_test_ext().execute_with(|| {
+        let _ = Crs::<Test>::clear(u32::MAX, None);
+        assert_noop!(
+            ArkVerkle::verify_multiproof(RuntimeOrigin::none(), queries, proof, backend(true)),
+            Error::<Test>::CrsNotGenerated
+        );
+    });
+}
+
+#[test]
+fn generate_crs_works() {
+    let (queries, proof) = make_query_batch(QUERIES);
+
+    new_test_ext().execute_with(|| {
+        let _ = Crs::<Test>::clear(u32::MAX, None);
+        assert_ok!(ArkVerkle::generate_crs(RuntimeOrigin::root()));
+        System::assert_last_event(RuntimeEvent::ArkVerkle(Event::CrsGenerated));
+        assert_ok!(ArkVerkle::verify_multiproof(
+            RuntimeOrigin::none(),
+            queries,
+            proof,
+            backend(true)
+        ));
+    });
+}
+
+#[test]
+fn generate_crs_requires_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ArkVerkle::generate_crs(RuntimeOrigin::signed(1)),
+            DispatchError::BadOrigin
+        );
+    });
+}
@@ -0,0 +1,179 @@
+#![allow(dead_code)]
+
+use crate::{
+    ipa::{self, IpaProof, MultiProof, Transcript, TRANSCRIPT_LABEL},
+    MultiProofRaw, Query, ScalarRaw, DOMAIN_SIZE,
+};
+use ark_ec::{twisted_edwards::TECurveConfig, AffineRepr, CurveGroup};
+use ark_ed_on_bls12_381_bandersnatch::{EdwardsAffine, EdwardsConfig, Fr};
+use ark_ff::{Field, One, Zero};
+use ark_scale::ark_serialize::CanonicalSerialize;
+use ark_std::{test_rng, vec, vec::Vec, UniformRand};
+
+/// Polynomial in evaluation form over the domain.
+pub type Polynomial = Vec<Fr>;
+
+pub fn encode_scalar(scalar: Fr) -> ScalarRaw {
+    let mut raw = ScalarRaw([0; 32]);
+    scalar.serialize_compressed(&mut raw.0[..]).unwrap();
+    raw
+}
+
+pub fn encode_multiproof(proof: &MultiProof<EdwardsConfig>) -> MultiProofRaw {
+    let mut raw = Vec::with_capacity(576);
+    raw.extend(ipa::serialize_point(&proof.d).0);
+    for point in proof.ipa.l.iter().chain(&proof.ipa.r) {
+        raw.extend(ipa::serialize_point(point).0);
+    }
+    raw.extend(encode_scalar(proof.ipa.a).0);
+    MultiProofRaw(raw.try_into().unwrap())
+}
+
+fn inner_product(a: &[Fr], b: &[Fr]) -> Fr {
+    a.iter().zip(b).map(|(a, b)| *a * b).sum()
+}
+
+pub fn commit(crs: &[EdwardsAffine], polynomial: &[Fr]) -> EdwardsAffine {
+    EdwardsConfig::msm(crs, polynomial).unwrap().into_affine()
+}
+
+/// Weights `Π_{j != i} (i - j)` of the domain.
+fn domain_weights() -> Vec<Fr> {
+    (0..DOMAIN_SIZE)
+        .map(|i| {
+            (0..DOMAIN_SIZE)
+                .filter(|j| *j != i)
+                .map(|j| Fr::from(i as u64) - Fr::from(j as u64))
+                .product()
+        })
+        .collect()
+}
+
+/// Quotient `(f(X) - f(z)) / (X - z)` in evaluation form, for `z` in the domain.
+fn divide_on_domain(weights: &[Fr], polynomial: &[Fr], z: usize) -> Polynomial {
+    let mut quotient = vec![Fr::zero(); DOMAIN_SIZE];
+    for i in (0..DOMAIN_SIZE).filter(|i| *i != z) {
+        let q_i = (polynomial[i] - polynomial[z])
+            * (Fr::from(i as u64) - Fr::from(z as u64)).inverse().unwrap();
+        quotient[i] = q_i;
+        quotient[z] -= q_i * weights[z] * weights[i].inverse().unwrap();
+    }
+    quotient
+}
+
+/// Open the polynomial committed in `commitment` at `point`.
+pub fn open_ipa(
+    transcript: &mut Transcript,
+    crs: &[EdwardsAffine],
+    commitment: &EdwardsAffine,
+    polynomial: Polynomial,
+    point: Fr,
+) -> IpaProof<EdwardsConfig> {
+    let mut a = polynomial;
+    let mut b = ipa::barycentric_coefficients(point);
+    let mut g = crs.to_vec();
+
+    transcript.domain_sep(b"ipa");
+    transcript.append_point(commitment, b"C");
+    transcript.append_scalar(&point, b"input point");
+    transcript.append_scalar(&inner_product(&a, &b), b"output point");
+    let w = transcript.challenge_scalar(b"w");
+    let q = EdwardsAffine::generator() * w;
+
+    let (mut l, mut r) = (Vec::new(), Vec::new());
+    while a.len() > 1 {
+        let half = a.len() / 2;
+        let (a_l, a_r) = a.split_at(half);
+        let (b_l, b_r) = b.split_at(half);
+        let (g_l, g_r) = g.split_at(half);
+        let c_l =
+            (EdwardsConfig::msm(g_l, a_r).unwrap() + q * inner_product(a_r, b_l)).into_affine();
+        let c_r =
+            (EdwardsConfig::msm(g_r, a_l).unwrap() + q * inner_product(a_l, b_r)).into_affine();
+        transcript.append_point(&c_l, b"L");
+        transcript.append_point(&c_r, b"R");
+        let x = transcript.challenge_scalar(b"x");
+        let x_inv = x.inverse().unwrap();
+
+        a = a_l.iter().zip(a_r).map(|(l, r)| *l + x * r).collect();
+        b = b_l.iter().zip(b_r).map(|(l, r)| *l + x_inv * r).collect();
+        g = g_l
+            .iter()
+            .zip(g_r)
+            .map(|(l, r)| (*r * x_inv + l).into_affine())
+            .collect();
+        l.push(c_l);
+        r.push(c_r);
+    }
+    IpaProof { l, r, a: a[0] }
+}
+
+/// Open each polynomial at its point of the domain.
+pub fn open_multiproof(
+    crs: &[EdwardsAffine],
+    queries: &[(EdwardsAffine, Polynomial, u8)],
+) -> MultiProof<EdwardsConfig> {
+    let mut transcript = Transcript::new(TRANSCRIPT_LABEL);
+    transcript.domain_sep(b"multiproof");
+    for (commitment, polynomial, point) in queries {
+        transcript.append_point(commitment, b"C");
+        transcript.append_scalar(&Fr::from(*point), b"z");
+        transcript.append_scalar(&polynomial[*point as usize], b"y");
+    }
+    let r = transcript.challenge_scalar(b"r");
+
+    // g(X) = Σ r^i (f_i(X) - y_i) / (X - z_i)
+    let weights = domain_weights();
+    let mut g = vec![Fr::zero(); DOMAIN_SIZE];
+    let mut power = Fr::one();
+    for (_, polynomial, point) in queries {
+        let quotient = divide_on_domain(&weights, polynomial, *point as usize);
+        g.iter_mut()
+            .zip(quotient)
+            .for_each(|(g, q)| *g += power * q);
+        power *= r;
+    }
+    let d = commit(crs, &g);
+    transcript.append_point(&d, b"D");
+    let t = transcript.challenge_scalar(b"t");
+
+    // h(X) = Σ r^i f_i(X) / (t - z_i)
+    let mut h = vec![Fr::zero(); DOMAIN_SIZE];
+    let mut power = Fr::one();
+    for (_, polynomial, point) in queries {
+        let coefficient = power * (t - Fr::from(*point)).inverse().unwrap();
+        h.iter_mut()
+            .zip(polynomial)
+            .for_each(|(h, f)| *h += coefficient * f);
+        power *= r;
+    }
+    let e = commit(crs, &h);
+    transcript.append_point(&e, b"E");
+
+    let h_minus_g = h.iter().zip(&g).map(|(h, g)| *h - g).collect();
+    let e_minus_d = (e.into_group() - d).into_affine();
+    let ipa = open_ipa(&mut transcript, crs, &e_minus_d, h_minus_g, t);
+    MultiProof { d, ipa }
+}
+
+/// Multiproof of `count` random polynomials.
+pub fn make_multiproof_args(count: u32) -> (Vec<Query>, MultiProofRaw) {
+    let rng = &mut test_rng();
+    let crs = ipa::generate_crs::<EdwardsConfig>();
+    let queries = (0..count)
+        .map(|i| {
+            let polynomial = (0..DOMAIN_SIZE).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+            (commit(&crs, &polynomial), polynomial, (i * 7) as u8)
+        })
+        .collect::<Vec<_>>();
+    let proof = open_multiproof(&crs, &queries);
+    let queries = queries
+        .into_iter()
+        .map(|(commitment, polynomial, point)| Query {
+            commitment: ipa::serialize_point(&commitment),
+            point,
+            value: encode_scalar(polynomial[point as usize]),
+        })
+        .collect();
+    (queries, encode_multiproof(&proof))
+}
@@ -0,0 +1,110 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Placeholder weights for `pallet_ark_verkle`
+//!
+//! NOT GENERATED BY THE BENCHMARK CLI. The figures below are estimates and must be
+//! replaced by running the pallet benchmarks with the command below.
+
+// Command to regenerate:
+// ./target/release/ark-node
+// benchmark
+// pallet
+// --runtime=target/release/wbuild/ark-runtime/ark_runtime.compact.wasm
+// --template=pallets/frame-weight-template.hbs
+// --pallet
+// pallet_ark_verkle
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat=20
+// --no-storage-info
+// --disable-proof-recording
+// --no-median-slopes
+// --json-file=./pallets/verkle/src/results.json
+// --output=./pallets/verkle/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_ark_verkle`.
+pub trait WeightInfo {
+	fn generate_crs() -> Weight;
+	fn ark_verify_multiproof(x: u32, ) -> Weight;
+	fn sub_verify_multiproof(x: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_ark_verkle` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `ArkVerkle::Crs` (r:0 w:8)
+	/// Proof: `ArkVerkle::Crs` (`max_values`: None, `max_size`: Some(2060), added: 4535, mode: `MaxEncodedLen`)
+	fn generate_crs() -> Weight {
+		Weight::from_parts(94_102_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `ArkVerkle::Crs` (r:8 w:0)
+	/// Proof: `ArkVerkle::Crs` (`max_values`: None, `max_size`: Some(2060), added: 4535, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_verify_multiproof(x: u32, ) -> Weight {
+		Weight::from_parts(131_482_905_331, 37270)
+			.saturating_add(Weight::from_parts(1_184_337_210, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+	}
+	/// Storage: `ArkVerkle::Crs` (r:8 w:0)
+	/// Proof: `ArkVerkle::Crs` (`max_values`: None, `max_size`: Some(2060), added: 4535, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_verify_multiproof(x: u32, ) -> Weight {
+		Weight::from_parts(19_873_642_109, 37270)
+			.saturating_add(Weight::from_parts(312_904_577, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `ArkVerkle::Crs` (r:0 w:8)
+	/// Proof: `ArkVerkle::Crs` (`max_values`: None, `max_size`: Some(2060), added: 4535, mode: `MaxEncodedLen`)
+	fn generate_crs() -> Weight {
+		Weight::from_parts(94_102_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `ArkVerkle::Crs` (r:8 w:0)
+	/// Proof: `ArkVerkle::Crs` (`max_values`: None, `max_size`: Some(2060), added: 4535, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn ark_verify_multiproof(x: u32, ) -> Weight {
+		Weight::from_parts(131_482_905_331, 37270)
+			.saturating_add(Weight::from_parts(1_184_337_210, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+	}
+	/// Storage: `ArkVerkle::Crs` (r:8 w:0)
+	/// Proof: `ArkVerkle::Crs` (`max_values`: None, `max_size`: Some(2060), added: 4535, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn sub_verify_multiproof(x: u32, ) -> Weight {
+		Weight::from_parts(19_873_642_109, 37270)
+			.saturating_add(Weight::from_parts(312_904_577, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+	}
+}
@@ -14,6 +14,7 @@ mod weights;
 use frame_support::pallet_prelude::*;
 
 use ark_vrf::reexports::ark_std::vec::Vec;
+use pallet_ark_common::{is_optimized, validation};
 
 use ark_vrf::suites::bandersnatch as ark_bandersnatch;
 pub(crate) type ArkSuite = ark_bandersnatch::BandersnatchSha512Ell2;
@@ -83,12 +84,12 @@ mod sub_bandersnatch {
 
 pub(crate) type SubSuite = sub_bandersnatch::BandersnatchSuite;
 
+pub use pallet_ark_common::{ArkBackend, Arkworks, BackendSwitch, ConfigBackend, Substrate};
+
 // Re-export all pallet parts, this is needed to properly import the pallet into the runtime.
 pub use pallet::*;
 pub use weights::*;
 
-const DEFAULT_WEIGHT: u64 = 10_000;
-
 const SRS_PAGE_SIZE: usize = 1 << 3;
 
 const COMPRESSED_POINT_SIZE: usize = 32;
@@ -215,7 +216,7 @@ pub mod pallet {
 
     use ark_vrf::{
         ietf::IetfSuite,
-        reexports::ark_serialize::{CanonicalDeserialize, CanonicalSerialize},
+        reexports::ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress},
         ring::RingSuite,
     };
     use frame_system::pallet_prelude::OriginFor;
@@ -235,6 +236,15 @@ pub mod pallet {
         #[pallet::constant]
         type MaxBatchSize: Get<u32>;
 
+        /// Check that user provided points are on the curve and in the prime order subgroup.
+        #[pallet::constant]
+        type ValidatePoints: Get<bool>;
+
+        /// Backend of the curve operations.
+        ///
+        /// Ignored with the `comparison-mode` feature, where the backend is selected per call.
+        type Backend: ArkBackend;
+
         /// Extrinsic weights
         type WeightInfo: WeightInfo;
     }
@@ -300,20 +310,24 @@ pub mod pallet {
         // ---------------------------------------------
 
         #[pallet::call_index(0)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(T::DbWeight::get().writes(2))]
         pub fn ring_reset(_: OriginFor<T>) -> DispatchResult {
             Self::ring_reset_impl();
             Ok(())
         }
 
         #[pallet::call_index(1)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_ring_vrf_accumulate(new_members.len() as u32)
+        } else {
+            T::WeightInfo::ark_ring_vrf_accumulate(new_members.len() as u32)
+        })]
         pub fn push_members(
             _: OriginFor<T>,
             new_members: Vec<PublicKeyRaw>,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
+            if is_optimized::<T::Backend>(&optimized) {
                 Self::push_members_impl::<SubSuite>(new_members);
             } else {
                 Self::push_members_impl::<ArkSuite>(new_members);
@@ -322,7 +336,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(2)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(T::DbWeight::get().reads_writes(2, 2))]
         pub fn push_member_buffered(_: OriginFor<T>, member: PublicKeyRaw) -> DispatchResult {
             Self::increment_ring_size(1);
             let mut members = RingKeys::<T>::get().unwrap_or_default();
@@ -332,32 +346,48 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Commit the ring, accumulating the buffered members first.
+        ///
+        /// Weight is charged for `Config::MaxRingSize` buffered members and the excess is
+        /// refunded.
         #[pallet::call_index(3)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
-        pub fn ring_commit(_: OriginFor<T>, optimized: bool) -> DispatchResult {
+        #[pallet::weight(Pallet::<T>::ring_commit_weight(
+            T::MaxRingSize::get(),
+            is_optimized::<T::Backend>(optimized)
+        ))]
+        pub fn ring_commit(
+            _: OriginFor<T>,
+            optimized: BackendSwitch,
+        ) -> DispatchResultWithPostInfo {
+            let optimized = is_optimized::<T::Backend>(&optimized);
+            let buffered = RingKeys::<T>::decode_len().unwrap_or_default() as u32;
             if optimized {
                 Self::commit_impl::<SubSuite>();
             } else {
                 Self::commit_impl::<ArkSuite>();
             }
-            Ok(())
+            Ok(Some(Self::ring_commit_weight(buffered, optimized)).into())
         }
 
         #[pallet::call_index(4)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_ring_vrf_verify()
+        } else {
+            T::WeightInfo::ark_ring_vrf_verify()
+        })]
         pub fn ring_verify(
             _: OriginFor<T>,
             input_raw: InputRaw,
             output_raw: OutputRaw,
             proof_raw: RingProofRaw,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
             let item = RingProofBatchItem {
                 input: input_raw,
                 output: output_raw,
                 proof: proof_raw,
             };
-            if optimized {
+            if is_optimized::<T::Backend>(&optimized) {
                 Self::ring_verify_batch_impl::<SubSuite>(&[item]);
             } else {
                 Self::ring_verify_batch_impl::<ArkSuite>(&[item]);
@@ -366,13 +396,17 @@ pub mod pallet {
         }
 
         #[pallet::call_index(5)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_ring_vrf_verify_batch(batch.len() as u32)
+        } else {
+            T::WeightInfo::ark_ring_vrf_verify_batch(batch.len() as u32)
+        })]
         pub fn ring_verify_batch(
             _: OriginFor<T>,
             batch: RingProofBatch<T::MaxBatchSize>,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
+            if is_optimized::<T::Backend>(&optimized) {
                 Self::ring_verify_batch_impl::<SubSuite>(&batch);
             } else {
                 Self::ring_verify_batch_impl::<ArkSuite>(&batch);
@@ -385,16 +419,20 @@ pub mod pallet {
         // ---------------------------------------------
 
         #[pallet::call_index(10)]
-        #[pallet::weight(Weight::from_all(DEFAULT_WEIGHT))]
+        #[pallet::weight(if is_optimized::<T::Backend>(optimized) {
+            T::WeightInfo::sub_ietf_vrf_verify()
+        } else {
+            T::WeightInfo::ark_ietf_vrf_verify()
+        })]
         pub fn ietf_verify(
             _: OriginFor<T>,
             public_raw: PublicKeyRaw,
             input_raw: InputRaw,
             output_raw: OutputRaw,
             proof_raw: IetfProofRaw,
-            optimized: bool,
+            optimized: BackendSwitch,
         ) -> DispatchResult {
-            if optimized {
+            if is_optimized::<T::Backend>(&optimized) {
                 Self::ietf_verify_impl::<SubSuite>(public_raw, input_raw, output_raw, proof_raw);
             } else {
                 Self::ietf_verify_impl::<ArkSuite>(public_raw, input_raw, output_raw, proof_raw);
@@ -404,6 +442,16 @@ pub mod pallet {
     }
 
     impl<T: Config> Pallet<T> {
+        /// Weight of `ring_commit` with `buffered` members to accumulate before commitment.
+        fn ring_commit_weight(buffered: u32, optimized: bool) -> Weight {
+            match (optimized, buffered) {
+                (true, 0) => T::WeightInfo::sub_ring_vrf_commit(),
+                (false, 0) => T::WeightInfo::ark_ring_vrf_commit(),
+                (true, x) => T::WeightInfo::sub_ring_vrf_accumulate_and_commit(x),
+                (false, x) => T::WeightInfo::ark_ring_vrf_accumulate_and_commit(x),
+            }
+        }
+
         pub(crate) fn increment_ring_size(new_members_count: u32) {
             let members_count = RingSize::<T>::get().unwrap_or_default() + new_members_count;
             let max_ring_size = T::MaxRingSize::get();
@@ -421,15 +469,30 @@ pub mod pallet {
             proof_raw: IetfProofRaw,
         ) {
             use ark_vrf::ietf::Verifier;
-            let input =
-                ark_vrf::Input::<S>::deserialize_compressed_unchecked(&input_raw.0[..]).unwrap();
-            let output =
-                ark_vrf::Output::<S>::deserialize_compressed_unchecked(&output_raw.0[..]).unwrap();
-            let public =
-                ark_vrf::Public::<S>::deserialize_compressed_unchecked(&public_raw.0[..]).unwrap();
-            let proof =
-                ark_vrf::ietf::Proof::<S>::deserialize_compressed_unchecked(&proof_raw.0[..])
-                    .unwrap();
+            let input = ark_vrf::Input::<S>::deserialize_with_mode(
+                &input_raw.0[..],
+                Compress::Yes,
+                validation(T::ValidatePoints::get()),
+            )
+            .unwrap();
+            let output = ark_vrf::Output::<S>::deserialize_with_mode(
+                &output_raw.0[..],
+                Compress::Yes,
+                validation(T::ValidatePoints::get()),
+            )
+            .unwrap();
+            let public = ark_vrf::Public::<S>::deserialize_with_mode(
+                &public_raw.0[..],
+                Compress::Yes,
+                validation(T::ValidatePoints::get()),
+            )
+            .unwrap();
+            let proof = ark_vrf::ietf::Proof::<S>::deserialize_with_mode(
+                &proof_raw.0[..],
+                Compress::Yes,
+                validation(T::ValidatePoints::get()),
+            )
+            .unwrap();
             public.verify(input, output, [], &proof).unwrap();
         }
 
@@ -451,15 +514,24 @@ pub mod pallet {
 
             // TODO: replace with true batching when available
             for item in batch {
-                let input =
-                    ark_vrf::Input::<S>::deserialize_compressed_unchecked(&item.input.0[..])
-                        .unwrap();
-                let output =
-                    ark_vrf::Output::<S>::deserialize_compressed_unchecked(&item.output.0[..])
-                        .unwrap();
-                let proof =
-                    ark_vrf::ring::Proof::<S>::deserialize_compressed_unchecked(&item.proof.0[..])
-                        .unwrap();
+                let input = ark_vrf::Input::<S>::deserialize_with_mode(
+                    &item.input.0[..],
+                    Compress::Yes,
+                    validation(T::ValidatePoints::get()),
+                )
+                .unwrap();
+                let output = ark_vrf::Output::<S>::deserialize_with_mode(
+                    &item.output.0[..],
+                    Compress::Yes,
+                    validation(T::ValidatePoints::get()),
+                )
+                .unwrap();
+                let proof = ark_vrf::ring::Proof::<S>::deserialize_with_mode(
+                    &item.proof.0[..],
+                    Compress::Yes,
+                    validation(T::ValidatePoints::get()),
+                )
+                .unwrap();
                 ark_vrf::Public::<S>::verify(input, output, [], &proof, &verifier).unwrap();
             }
         }
@@ -499,7 +571,12 @@ pub mod pallet {
                 .into_iter()
                 .map(|m| {
                     log::trace!("Pushing {:02x?}", m.0);
-                    ark_vrf::AffinePoint::<S>::deserialize_compressed_unchecked(&m.0[..]).unwrap()
+                    ark_vrf::AffinePoint::<S>::deserialize_with_mode(
+                        &m.0[..],
+                        Compress::Yes,
+                        validation(T::ValidatePoints::get()),
+                    )
+                    .unwrap()
                 })
                 .collect::<Vec<_>>();
             builder
@@ -1,4 +1,7 @@
-use frame_support::{self, derive_impl, parameter_types, sp_runtime::BuildStorage};
+use crate::{ArkBackend, BackendSwitch};
+use frame_support::{
+    self, derive_impl, parameter_types, sp_runtime::BuildStorage, traits::ConstBool,
+};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -36,11 +39,42 @@ impl frame_system::Config for Test {
 parameter_types! {
     pub MaxRingSize: u32 = crate::MAX_RING_SIZE;
     pub MaxBatchSize: u32 = 32;
+    pub static Optimized: bool = true;
+}
+
+/// Backend selected by the `Optimized` parameter.
+pub struct TestBackend;
+
+impl ArkBackend for TestBackend {
+    fn optimized() -> bool {
+        Optimized::get()
+    }
+}
+
+/// Select the backend of a call.
+///
+/// With the `comparison-mode` feature the selection is passed to the call, otherwise
+/// the backend of the mock runtime is switched.
+#[cfg(feature = "comparison-mode")]
+pub fn backend(optimized: bool) -> BackendSwitch {
+    optimized
+}
+
+/// Select the backend of a call.
+///
+/// With the `comparison-mode` feature the selection is passed to the call, otherwise
+/// the backend of the mock runtime is switched.
+#[cfg(not(feature = "comparison-mode"))]
+pub fn backend(optimized: bool) -> BackendSwitch {
+    Optimized::set(optimized);
+    crate::ConfigBackend
 }
 
 impl crate::Config for Test {
     type MaxRingSize = MaxRingSize;
     type MaxBatchSize = MaxBatchSize;
+    type ValidatePoints = ConstBool<true>;
+    type Backend = TestBackend;
     type WeightInfo = ();
 }
 
@@ -1,16 +1,18 @@
 use crate::{
-    mock::{new_test_ext, MaxBatchSize, MaxRingSize, RuntimeOrigin, Test},
-    utils, Pallet, PublicKeyRaw, RingBuilderPcsParams, RingProofBatch, RING_BUILDER_DATA,
-    RING_BUILDER_PARAMS,
+    ark_bandersnatch,
+    mock::{backend, new_test_ext, MaxBatchSize, MaxRingSize, RuntimeOrigin, Test},
+    utils, Pallet, PublicKeyRaw, RingBuilder, RingBuilderPcsParams, RingProofBatch,
+    RingVerifierKey, SubSuite, WeightInfo, RING_BUILDER_DATA, RING_BUILDER_PARAMS,
 };
+use ark_vrf::reexports::ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 
 const TEST_RING_SIZE: u32 = 42;
 
 fn ring_commit(optimized: bool) -> Vec<PublicKeyRaw> {
     let origin = RuntimeOrigin::none();
     let members = utils::ring_members_gen_raw(TEST_RING_SIZE);
-    Pallet::<Test>::push_members(origin.clone(), members.clone(), optimized).unwrap();
-    Pallet::<Test>::ring_commit(origin, optimized).unwrap();
+    Pallet::<Test>::push_members(origin.clone(), members.clone(), backend(optimized)).unwrap();
+    Pallet::<Test>::ring_commit(origin, backend(optimized)).unwrap();
     members
 }
 
@@ -22,7 +24,7 @@ fn ietf_verify(optimized: bool) {
         input_raw,
         output_raw,
         proof_raw,
-        optimized,
+        backend(optimized),
     )
     .unwrap();
 }
@@ -35,7 +37,7 @@ fn ring_verify(optimized: bool) {
         proof.input,
         proof.output,
         proof.proof,
-        optimized,
+        backend(optimized),
     )
     .unwrap()
 }
@@ -45,7 +47,7 @@ fn ring_verify_batch(optimized: bool) {
     let batch_size = MaxBatchSize::get().min(3);
     let batch = utils::ring_verify_params_gen(MaxRingSize::get(), Some(&members), batch_size);
     let batch: RingProofBatch<MaxBatchSize> = batch.try_into().unwrap();
-    Pallet::<Test>::ring_verify_batch(RuntimeOrigin::none(), batch, optimized).unwrap()
+    Pallet::<Test>::ring_verify_batch(RuntimeOrigin::none(), batch, backend(optimized)).unwrap()
 }
 
 #[test]
@@ -88,8 +90,22 @@ fn sub_ring_verify_batch() {
     new_test_ext().execute_with(|| ring_verify_batch(true));
 }
 
+#[test]
+fn ring_commit_refunds_weight() {
+    new_test_ext().execute_with(|| {
+        let members = utils::ring_members_gen_raw(3);
+        for member in members {
+            Pallet::<Test>::push_member_buffered(RuntimeOrigin::none(), member).unwrap();
+        }
+        let info = Pallet::<Test>::ring_commit(RuntimeOrigin::none(), backend(true)).unwrap();
+        assert_eq!(
+            info.actual_weight,
+            Some(<() as WeightInfo>::sub_ring_vrf_accumulate_and_commit(3))
+        );
+    });
+}
+
 fn backend_works(pregen_params: bool) {
-    use ark_vrf::reexports::ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
     use ark_vrf::ring::{Prover, Verifier};
     use ark_vrf::suites::bandersnatch as ark_bandersnatch;
 
@@ -168,3 +184,76 @@ fn backend_works_zcash_params() {
 fn backend_works_rand_params() {
     backend_works(false);
 }
+
+// ---------------------------------------------
+// Differential tests
+// ---------------------------------------------
+
+fn serialize(value: &impl CanonicalSerialize) -> Vec<u8> {
+    let mut buf = Vec::new();
+    value.serialize_compressed(&mut buf).unwrap();
+    buf
+}
+
+/// Convert a value to the type of the other suite via its serialization.
+fn convert<T: CanonicalDeserialize>(value: &impl CanonicalSerialize) -> T {
+    T::deserialize_compressed(&serialize(value)[..]).unwrap()
+}
+
+/// Ring verifier key builder and verifier key after committing a ring.
+fn committed_ring(optimized: bool, buffered: bool) -> (Vec<u8>, Vec<u8>) {
+    new_test_ext().execute_with(|| {
+        let origin = RuntimeOrigin::none();
+        let members = utils::ring_members_gen_raw(TEST_RING_SIZE);
+        if buffered {
+            for member in members {
+                Pallet::<Test>::push_member_buffered(origin.clone(), member).unwrap();
+            }
+        } else {
+            Pallet::<Test>::push_members(origin.clone(), members, backend(optimized)).unwrap();
+        }
+        Pallet::<Test>::ring_commit(origin, backend(optimized)).unwrap();
+        (
+            RingBuilder::<Test>::get().unwrap().0.to_vec(),
+            RingVerifierKey::<Test>::get().unwrap().0.to_vec(),
+        )
+    })
+}
+
+#[test]
+fn ring_commit_same_keys() {
+    assert_eq!(committed_ring(false, false), committed_ring(true, false));
+    assert_eq!(committed_ring(false, true), committed_ring(true, true));
+}
+
+#[test]
+fn ietf_same_verification() {
+    use ark_vrf::ietf::{Prover, Verifier};
+
+    for i in 0..4_u8 {
+        let secret = ark_bandersnatch::Secret::from_seed(&[i]);
+        let public = secret.public();
+        let data = [b"input".as_slice(), &[i]].concat();
+        let input = ark_bandersnatch::Input::new(&data).unwrap();
+        let output = secret.output(input);
+        let proof = secret.prove(input, output, []);
+        // Output of another input, the proof doesn't hold for it
+        let other = secret.output(ark_bandersnatch::Input::new(b"other").unwrap());
+
+        let sub_public: ark_vrf::Public<SubSuite> = convert(&public);
+        let sub_input = ark_vrf::Input::<SubSuite>::new(&data).unwrap();
+        assert_eq!(serialize(&input), serialize(&sub_input));
+        let sub_output: ark_vrf::Output<SubSuite> = convert(&output);
+        let sub_proof: ark_vrf::ietf::Proof<SubSuite> = convert(&proof);
+        let sub_other: ark_vrf::Output<SubSuite> = convert(&other);
+
+        assert!(public.verify(input, output, [], &proof).is_ok());
+        assert!(sub_public
+            .verify(sub_input, sub_output, [], &sub_proof)
+            .is_ok());
+        assert!(public.verify(input, other, [], &proof).is_err());
+        assert!(sub_public
+            .verify(sub_input, sub_other, [], &sub_proof)
+            .is_err());
+    }
+}
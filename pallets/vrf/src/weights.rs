@@ -16,15 +16,12 @@
 // limitations under the License.
 
 
-//! Autogenerated weights for `pallet_ark_vrf`
+//! Placeholder weights for `pallet_ark_vrf`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2026-01-24, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `ggwpez-ref-hw2`, CPU: `AMD EPYC 7232P 8-Core Processor`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+//! NOT GENERATED BY THE BENCHMARK CLI. The figures below are estimates and must be
+//! replaced by running the pallet benchmarks with the command below.
 
-// Executed Command:
+// Command to regenerate:
 // ./target/release/ark-node
 // benchmark
 // pallet
@@ -54,12 +51,16 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for `pallet_ark_vrf`.
 pub trait WeightInfo {
-	fn ark_ring_vrf_commit_buffered(x: u32, ) -> Weight;
-	fn sub_ring_vrf_commit_buffered(x: u32, ) -> Weight;
-	fn ark_ring_vrf_commit_unbuffered(x: u32, ) -> Weight;
-	fn sub_ring_vrf_commit_unbuffered(x: u32, ) -> Weight;
-	fn ark_ring_vrf_verify(x: u32, ) -> Weight;
-	fn sub_ring_vrf_verify(x: u32, ) -> Weight;
+	fn ark_ring_vrf_accumulate_and_commit(x: u32, ) -> Weight;
+	fn sub_ring_vrf_accumulate_and_commit(x: u32, ) -> Weight;
+	fn ark_ring_vrf_accumulate(x: u32, ) -> Weight;
+	fn sub_ring_vrf_accumulate(x: u32, ) -> Weight;
+	fn ark_ring_vrf_commit() -> Weight;
+	fn sub_ring_vrf_commit() -> Weight;
+	fn ark_ring_vrf_verify() -> Weight;
+	fn sub_ring_vrf_verify() -> Weight;
+	fn ark_ring_vrf_verify_batch(x: u32, ) -> Weight;
+	fn sub_ring_vrf_verify_batch(x: u32, ) -> Weight;
 	fn ark_ietf_vrf_verify() -> Weight;
 	fn sub_ietf_vrf_verify() -> Weight;
 }
@@ -78,13 +79,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ArkVrf::RingVerifierKey` (r:0 w:1)
 	/// Proof: `ArkVrf::RingVerifierKey` (`max_values`: Some(1), `max_size`: Some(384), added: 879, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 50]`.
-	fn ark_ring_vrf_commit_buffered(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1808 + x * (87 ±0)`
-		//  Estimated: `9647 + x * (355 ±2)`
-		// Minimum execution time: 4_894_579_000 picoseconds.
+	fn ark_ring_vrf_accumulate_and_commit(x: u32, ) -> Weight {
 		Weight::from_parts(4_294_985_512, 9647)
-			// Standard Error: 4_456_518
 			.saturating_add(Weight::from_parts(1_574_884_572, 0).saturating_mul(x.into()))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
@@ -101,33 +97,49 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ArkVrf::RingVerifierKey` (r:0 w:1)
 	/// Proof: `ArkVrf::RingVerifierKey` (`max_values`: Some(1), `max_size`: Some(384), added: 879, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 50]`.
-	fn sub_ring_vrf_commit_buffered(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1808 + x * (87 ±0)`
-		//  Estimated: `9647 + x * (355 ±2)`
-		// Minimum execution time: 1_325_625_000 picoseconds.
+	fn sub_ring_vrf_accumulate_and_commit(x: u32, ) -> Weight {
 		Weight::from_parts(1_070_210_802, 9647)
-			// Standard Error: 319_246
 			.saturating_add(Weight::from_parts(459_294_054, 0).saturating_mul(x.into()))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_parts(0, 355).saturating_mul(x.into()))
 	}
+	/// Storage: `ArkVrf::RingSize` (r:1 w:1)
+	/// Proof: `ArkVrf::RingSize` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ArkVrf::RingBuilder` (r:1 w:1)
+	/// Proof: `ArkVrf::RingBuilder` (`max_values`: Some(1), `max_size`: Some(848), added: 1343, mode: `MaxEncodedLen`)
+	/// Storage: `ArkVrf::Srs` (r:7 w:0)
+	/// Proof: `ArkVrf::Srs` (`max_values`: None, `max_size`: Some(396), added: 2871, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 50]`.
+	fn ark_ring_vrf_accumulate(x: u32, ) -> Weight {
+		Weight::from_parts(4_265_772_831, 2333)
+			.saturating_add(Weight::from_parts(1_574_884_572, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 355).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkVrf::RingSize` (r:1 w:1)
+	/// Proof: `ArkVrf::RingSize` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ArkVrf::RingBuilder` (r:1 w:1)
+	/// Proof: `ArkVrf::RingBuilder` (`max_values`: Some(1), `max_size`: Some(848), added: 1343, mode: `MaxEncodedLen`)
+	/// Storage: `ArkVrf::Srs` (r:7 w:0)
+	/// Proof: `ArkVrf::Srs` (`max_values`: None, `max_size`: Some(396), added: 2871, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 50]`.
+	fn sub_ring_vrf_accumulate(x: u32, ) -> Weight {
+		Weight::from_parts(1_018_487_895, 2333)
+			.saturating_add(Weight::from_parts(459_294_054, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 355).saturating_mul(x.into()))
+	}
 	/// Storage: `ArkVrf::RingKeys` (r:1 w:0)
 	/// Proof: `ArkVrf::RingKeys` (`max_values`: Some(1), `max_size`: Some(8162), added: 8657, mode: `MaxEncodedLen`)
 	/// Storage: `ArkVrf::RingBuilder` (r:1 w:0)
 	/// Proof: `ArkVrf::RingBuilder` (`max_values`: Some(1), `max_size`: Some(848), added: 1343, mode: `MaxEncodedLen`)
 	/// Storage: `ArkVrf::RingVerifierKey` (r:0 w:1)
 	/// Proof: `ArkVrf::RingVerifierKey` (`max_values`: Some(1), `max_size`: Some(384), added: 879, mode: `MaxEncodedLen`)
-	/// The range of component `x` is `[1, 50]`.
-	fn ark_ring_vrf_commit_unbuffered(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `940`
-		//  Estimated: `9647`
-		// Minimum execution time: 28_560_000 picoseconds.
-		Weight::from_parts(29_212_681, 9647)
-			// Standard Error: 724
-			.saturating_add(Weight::from_parts(23_986, 0).saturating_mul(x.into()))
+	fn ark_ring_vrf_commit() -> Weight {
+		Weight::from_parts(30_411_981, 9647)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -137,52 +149,43 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ArkVrf::RingBuilder` (`max_values`: Some(1), `max_size`: Some(848), added: 1343, mode: `MaxEncodedLen`)
 	/// Storage: `ArkVrf::RingVerifierKey` (r:0 w:1)
 	/// Proof: `ArkVrf::RingVerifierKey` (`max_values`: Some(1), `max_size`: Some(384), added: 879, mode: `MaxEncodedLen`)
-	/// The range of component `x` is `[1, 50]`.
-	fn sub_ring_vrf_commit_unbuffered(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `940`
-		//  Estimated: `9647`
-		// Minimum execution time: 50_911_000 picoseconds.
-		Weight::from_parts(51_722_907, 9647)
-			// Standard Error: 1_662
-			.saturating_add(Weight::from_parts(35_199, 0).saturating_mul(x.into()))
+	fn sub_ring_vrf_commit() -> Weight {
+		Weight::from_parts(53_482_857, 9647)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `ArkVrf::RingVerifierKey` (r:1 w:0)
 	/// Proof: `ArkVrf::RingVerifierKey` (`max_values`: Some(1), `max_size`: Some(384), added: 879, mode: `MaxEncodedLen`)
-	/// The range of component `x` is `[1, 50]`.
-	fn ark_ring_vrf_verify(_x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `489`
-		//  Estimated: `1869`
-		// Minimum execution time: 40_654_535_000 picoseconds.
+	fn ark_ring_vrf_verify() -> Weight {
 		Weight::from_parts(41_032_265_431, 1869)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
 	/// Storage: `ArkVrf::RingVerifierKey` (r:1 w:0)
 	/// Proof: `ArkVrf::RingVerifierKey` (`max_values`: Some(1), `max_size`: Some(384), added: 879, mode: `MaxEncodedLen`)
-	/// The range of component `x` is `[1, 50]`.
-	fn sub_ring_vrf_verify(_x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `489`
-		//  Estimated: `1869`
-		// Minimum execution time: 22_626_151_000 picoseconds.
+	fn sub_ring_vrf_verify() -> Weight {
 		Weight::from_parts(23_071_328_218, 1869)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
+	/// Storage: `ArkVrf::RingVerifierKey` (r:1 w:0)
+	/// Proof: `ArkVrf::RingVerifierKey` (`max_values`: Some(1), `max_size`: Some(384), added: 879, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 5]`.
+	fn ark_ring_vrf_verify_batch(x: u32, ) -> Weight {
+		Weight::from_parts(0, 1869)
+			.saturating_add(Weight::from_parts(41_032_265_431, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `ArkVrf::RingVerifierKey` (r:1 w:0)
+	/// Proof: `ArkVrf::RingVerifierKey` (`max_values`: Some(1), `max_size`: Some(384), added: 879, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 5]`.
+	fn sub_ring_vrf_verify_batch(x: u32, ) -> Weight {
+		Weight::from_parts(0, 1869)
+			.saturating_add(Weight::from_parts(23_071_328_218, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
 	fn ark_ietf_vrf_verify() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 2_908_103_000 picoseconds.
 		Weight::from_parts(2_909_823_000, 0)
 	}
 	fn sub_ietf_vrf_verify() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 838_706_000 picoseconds.
 		Weight::from_parts(839_527_000, 0)
 	}
 }
@@ -200,13 +203,8 @@ impl WeightInfo for () {
 	/// Storage: `ArkVrf::RingVerifierKey` (r:0 w:1)
 	/// Proof: `ArkVrf::RingVerifierKey` (`max_values`: Some(1), `max_size`: Some(384), added: 879, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 50]`.
-	fn ark_ring_vrf_commit_buffered(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1808 + x * (87 ±0)`
-		//  Estimated: `9647 + x * (355 ±2)`
-		// Minimum execution time: 4_894_579_000 picoseconds.
+	fn ark_ring_vrf_accumulate_and_commit(x: u32, ) -> Weight {
 		Weight::from_parts(4_294_985_512, 9647)
-			// Standard Error: 4_456_518
 			.saturating_add(Weight::from_parts(1_574_884_572, 0).saturating_mul(x.into()))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
@@ -223,33 +221,49 @@ impl WeightInfo for () {
 	/// Storage: `ArkVrf::RingVerifierKey` (r:0 w:1)
 	/// Proof: `ArkVrf::RingVerifierKey` (`max_values`: Some(1), `max_size`: Some(384), added: 879, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 50]`.
-	fn sub_ring_vrf_commit_buffered(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1808 + x * (87 ±0)`
-		//  Estimated: `9647 + x * (355 ±2)`
-		// Minimum execution time: 1_325_625_000 picoseconds.
+	fn sub_ring_vrf_accumulate_and_commit(x: u32, ) -> Weight {
 		Weight::from_parts(1_070_210_802, 9647)
-			// Standard Error: 319_246
 			.saturating_add(Weight::from_parts(459_294_054, 0).saturating_mul(x.into()))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_parts(0, 355).saturating_mul(x.into()))
 	}
+	/// Storage: `ArkVrf::RingSize` (r:1 w:1)
+	/// Proof: `ArkVrf::RingSize` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ArkVrf::RingBuilder` (r:1 w:1)
+	/// Proof: `ArkVrf::RingBuilder` (`max_values`: Some(1), `max_size`: Some(848), added: 1343, mode: `MaxEncodedLen`)
+	/// Storage: `ArkVrf::Srs` (r:7 w:0)
+	/// Proof: `ArkVrf::Srs` (`max_values`: None, `max_size`: Some(396), added: 2871, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 50]`.
+	fn ark_ring_vrf_accumulate(x: u32, ) -> Weight {
+		Weight::from_parts(4_265_772_831, 2333)
+			.saturating_add(Weight::from_parts(1_574_884_572, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 355).saturating_mul(x.into()))
+	}
+	/// Storage: `ArkVrf::RingSize` (r:1 w:1)
+	/// Proof: `ArkVrf::RingSize` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ArkVrf::RingBuilder` (r:1 w:1)
+	/// Proof: `ArkVrf::RingBuilder` (`max_values`: Some(1), `max_size`: Some(848), added: 1343, mode: `MaxEncodedLen`)
+	/// Storage: `ArkVrf::Srs` (r:7 w:0)
+	/// Proof: `ArkVrf::Srs` (`max_values`: None, `max_size`: Some(396), added: 2871, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 50]`.
+	fn sub_ring_vrf_accumulate(x: u32, ) -> Weight {
+		Weight::from_parts(1_018_487_895, 2333)
+			.saturating_add(Weight::from_parts(459_294_054, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 355).saturating_mul(x.into()))
+	}
 	/// Storage: `ArkVrf::RingKeys` (r:1 w:0)
 	/// Proof: `ArkVrf::RingKeys` (`max_values`: Some(1), `max_size`: Some(8162), added: 8657, mode: `MaxEncodedLen`)
 	/// Storage: `ArkVrf::RingBuilder` (r:1 w:0)
 	/// Proof: `ArkVrf::RingBuilder` (`max_values`: Some(1), `max_size`: Some(848), added: 1343, mode: `MaxEncodedLen`)
 	/// Storage: `ArkVrf::RingVerifierKey` (r:0 w:1)
 	/// Proof: `ArkVrf::RingVerifierKey` (`max_values`: Some(1), `max_size`: Some(384), added: 879, mode: `MaxEncodedLen`)
-	/// The range of component `x` is `[1, 50]`.
-	fn ark_ring_vrf_commit_unbuffered(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `940`
-		//  Estimated: `9647`
-		// Minimum execution time: 28_560_000 picoseconds.
-		Weight::from_parts(29_212_681, 9647)
-			// Standard Error: 724
-			.saturating_add(Weight::from_parts(23_986, 0).saturating_mul(x.into()))
+	fn ark_ring_vrf_commit() -> Weight {
+		Weight::from_parts(30_411_981, 9647)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -259,52 +273,43 @@ impl WeightInfo for () {
 	/// Proof: `ArkVrf::RingBuilder` (`max_values`: Some(1), `max_size`: Some(848), added: 1343, mode: `MaxEncodedLen`)
 	/// Storage: `ArkVrf::RingVerifierKey` (r:0 w:1)
 	/// Proof: `ArkVrf::RingVerifierKey` (`max_values`: Some(1), `max_size`: Some(384), added: 879, mode: `MaxEncodedLen`)
-	/// The range of component `x` is `[1, 50]`.
-	fn sub_ring_vrf_commit_unbuffered(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `940`
-		//  Estimated: `9647`
-		// Minimum execution time: 50_911_000 picoseconds.
-		Weight::from_parts(51_722_907, 9647)
-			// Standard Error: 1_662
-			.saturating_add(Weight::from_parts(35_199, 0).saturating_mul(x.into()))
+	fn sub_ring_vrf_commit() -> Weight {
+		Weight::from_parts(53_482_857, 9647)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `ArkVrf::RingVerifierKey` (r:1 w:0)
 	/// Proof: `ArkVrf::RingVerifierKey` (`max_values`: Some(1), `max_size`: Some(384), added: 879, mode: `MaxEncodedLen`)
-	/// The range of component `x` is `[1, 50]`.
-	fn ark_ring_vrf_verify(_x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `489`
-		//  Estimated: `1869`
-		// Minimum execution time: 40_654_535_000 picoseconds.
+	fn ark_ring_vrf_verify() -> Weight {
 		Weight::from_parts(41_032_265_431, 1869)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 	}
 	/// Storage: `ArkVrf::RingVerifierKey` (r:1 w:0)
 	/// Proof: `ArkVrf::RingVerifierKey` (`max_values`: Some(1), `max_size`: Some(384), added: 879, mode: `MaxEncodedLen`)
-	/// The range of component `x` is `[1, 50]`.
-	fn sub_ring_vrf_verify(_x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `489`
-		//  Estimated: `1869`
-		// Minimum execution time: 22_626_151_000 picoseconds.
+	fn sub_ring_vrf_verify() -> Weight {
 		Weight::from_parts(23_071_328_218, 1869)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 	}
+	/// Storage: `ArkVrf::RingVerifierKey` (r:1 w:0)
+	/// Proof: `ArkVrf::RingVerifierKey` (`max_values`: Some(1), `max_size`: Some(384), added: 879, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 5]`.
+	fn ark_ring_vrf_verify_batch(x: u32, ) -> Weight {
+		Weight::from_parts(0, 1869)
+			.saturating_add(Weight::from_parts(41_032_265_431, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `ArkVrf::RingVerifierKey` (r:1 w:0)
+	/// Proof: `ArkVrf::RingVerifierKey` (`max_values`: Some(1), `max_size`: Some(384), added: 879, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 5]`.
+	fn sub_ring_vrf_verify_batch(x: u32, ) -> Weight {
+		Weight::from_parts(0, 1869)
+			.saturating_add(Weight::from_parts(23_071_328_218, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
 	fn ark_ietf_vrf_verify() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 2_908_103_000 picoseconds.
 		Weight::from_parts(2_909_823_000, 0)
 	}
 	fn sub_ietf_vrf_verify() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 838_706_000 picoseconds.
 		Weight::from_parts(839_527_000, 0)
 	}
 }
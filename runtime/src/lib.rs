@@ -28,6 +28,7 @@ use frame_support::{
     derive_impl,
     genesis_builder_helper::{build_state, get_preset},
     parameter_types,
+    traits::{ConstBool, ConstU32, ConstU64},
     weights::{FixedFee, NoFee, Weight},
 };
 use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
@@ -179,6 +180,18 @@ mod runtime {
     /// Arkworks VRF.
     #[runtime::pallet_index(6)]
     pub type ArkVrf = pallet_ark_vrf;
+
+    /// Arkworks BLS signatures.
+    #[runtime::pallet_index(7)]
+    pub type ArkBls = pallet_ark_bls;
+
+    /// Arkworks KZG openings.
+    #[runtime::pallet_index(8)]
+    pub type ArkKzg = pallet_ark_kzg;
+
+    /// Arkworks Verkle multiproofs.
+    #[runtime::pallet_index(9)]
+    pub type ArkVerkle = pallet_ark_verkle;
 }
 
 parameter_types! {
@@ -213,12 +226,35 @@ impl pallet_transaction_payment::Config for Runtime {
     type LengthToFee = FixedFee<1, <Self as pallet_balances::Config>::Balance>;
 }
 
+/// Backend of the curve operations, shared by all the Arkworks pallets.
+pub type CurveBackend = pallet_ark_common::Substrate;
+
 impl pallet_ark_hostcalls::Config for Runtime {
-    type WeightInfo = ();
+    type StoreLastResult = ConstBool<true>;
+    type MaxMsmLen = ConstU32<512>;
+    type MaxPairingLen = ConstU32<16>;
+    type MaxScalarWords = ConstU32<16>;
+    // Matches the range of the hash to curve benchmarks
+    type MaxHashMsgLen = ConstU32<1024>;
+    type ValidatePoints = ConstBool<true>;
+    type BaseSetOrigin = frame_system::EnsureRoot<AccountId>;
+    type Backend = CurveBackend;
+    type WeightInfo = pallet_ark_hostcalls::SubstrateWeight<Runtime>;
 }
 
 impl pallet_ark_groth16::Config for Runtime {
-    type WeightInfo = ();
+    type ValidatePoints = ConstBool<true>;
+    type MaxPublicInputs = ConstU32<16>;
+    type MaxBatchSize = ConstU32<16>;
+    // Prepared BW6-761 keys with `MaxPublicInputs` inputs
+    type MaxVerifyingKeyLen = ConstU32<4616>;
+    type RegisterOrigin = frame_system::EnsureSigned<AccountId>;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type KeyDepositBase = ConstU64<1_000_000_000>;
+    type KeyDepositPerByte = ConstU64<1_000_000>;
+    type Backend = CurveBackend;
+    type WeightInfo = pallet_ark_groth16::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -229,7 +265,34 @@ parameter_types! {
 impl pallet_ark_vrf::Config for Runtime {
     type MaxRingSize = MaxRingSize;
     type MaxBatchSize = MaxBatchSize;
-    type WeightInfo = ();
+    type ValidatePoints = ConstBool<true>;
+    type Backend = CurveBackend;
+    type WeightInfo = pallet_ark_vrf::SubstrateWeight<Runtime>;
+}
+
+impl pallet_ark_bls::Config for Runtime {
+    // Match the range of the aggregate verification benchmarks
+    type MaxPublicKeys = ConstU32<16>;
+    type MaxMessages = ConstU32<16>;
+    type MaxMessageLen = ConstU32<1024>;
+    type Backend = CurveBackend;
+    type WeightInfo = pallet_ark_bls::SubstrateWeight<Runtime>;
+}
+
+impl pallet_ark_kzg::Config for Runtime {
+    // Powers of the EIP-4844 trusted setup
+    type MaxSrsLen = ConstU32<65>;
+    type MaxBatchSize = MaxBatchSize;
+    type SrsOrigin = frame_system::EnsureRoot<AccountId>;
+    type Backend = CurveBackend;
+    type WeightInfo = pallet_ark_kzg::SubstrateWeight<Runtime>;
+}
+
+impl pallet_ark_verkle::Config for Runtime {
+    type MaxQueries = ConstU32<16>;
+    type CrsOrigin = frame_system::EnsureRoot<AccountId>;
+    type Backend = CurveBackend;
+    type WeightInfo = pallet_ark_verkle::SubstrateWeight<Runtime>;
 }
 
 // Opaque types for the node to use
@@ -276,6 +339,9 @@ frame_benchmarking::define_benchmarks!(
     [pallet_ark_hostcalls, ArkHostcalls]
     [pallet_ark_groth16, ArkGroth16]
     [pallet_ark_vrf, ArkVrf]
+    [pallet_ark_bls, ArkBls]
+    [pallet_ark_kzg, ArkKzg]
+    [pallet_ark_verkle, ArkVerkle]
 );
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -365,6 +431,33 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_ark_hostcalls_runtime_api::ArkCurveApi<
+        Block,
+        pallet_ark_hostcalls::Error<Runtime>,
+    > for Runtime {
+        fn msm(
+            curve: pallet_ark_hostcalls::Curve,
+            bases: Vec<u8>,
+            scalars: Vec<u8>,
+        ) -> Result<Vec<u8>, pallet_ark_hostcalls::Error<Runtime>> {
+            ArkHostcalls::compute_msm(curve, bases, scalars)
+        }
+        fn mul_affine(
+            curve: pallet_ark_hostcalls::Curve,
+            base: Vec<u8>,
+            scalar: Vec<u8>,
+        ) -> Result<Vec<u8>, pallet_ark_hostcalls::Error<Runtime>> {
+            ArkHostcalls::compute_mul_affine(curve, base, scalar)
+        }
+        fn pairing(
+            curve: pallet_ark_hostcalls::PairingCurve,
+            a: Vec<u8>,
+            b: Vec<u8>,
+        ) -> Result<Vec<u8>, pallet_ark_hostcalls::Error<Runtime>> {
+            ArkHostcalls::compute_pairing(curve, a, b)
+        }
+    }
+
     impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
         Block,
         interface::Balance,